@@ -3,12 +3,82 @@
 use async_trait::async_trait;
 use jsonwebtoken::jwk::{AlgorithmParameters, Jwk, JwkSet};
 use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, TokenData, Validation};
-use serde::Deserialize;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::time::{Duration, Instant};
+use thiserror::Error as ThisError;
 
-use crate::error::{Auth0Result, Error};
-use crate::utils::URL_REGEX;
-use crate::Auth0Client;
+use crate::authorization::dpop::DpopKey;
+use crate::authorization::jwks::{JwksCache, JwksSource};
+use crate::authorization::mtls::Confirmation;
+use crate::authorization::token_set::TokenSet;
+use crate::error::{Auth0OAuthError, Auth0Result, Error};
+use crate::utils::check_extra_params;
+use crate::{Auth0Client, GrantType};
+
+pub mod authorize;
+pub mod callback;
+pub mod ciba;
+pub mod client_assertion;
+pub mod database;
+pub mod device_flow;
+pub mod discovery;
+pub mod dpop;
+pub mod id_token;
+pub mod jwks;
+pub mod logout;
+pub mod mfa;
+pub mod mtls;
+pub mod passwordless;
+pub mod registration;
+pub mod saml;
+pub mod social;
+pub mod state;
+pub mod token_exchange;
+pub mod token_set;
+pub mod userinfo;
+pub mod validation;
+
+/// The path of the token endpoint, the only one DPoP proofs are sent to.
+const TOKEN_PATH: &str = "/oauth/token";
+
+/// The number of seconds added to the polling interval when Auth0 asks us to slow down.
+const SLOW_DOWN_INCREMENT: u64 = 5;
+
+/// The parameters of token requests, which extra parameters can not override.
+pub(crate) const RESERVED_TOKEN_PARAMS: &[&str] = &[
+    "grant_type",
+    "client_id",
+    "client_secret",
+    "client_assertion",
+    "client_assertion_type",
+    "audience",
+    "scope",
+    "organization",
+    "username",
+    "password",
+    "realm",
+    "refresh_token",
+    "code",
+    "code_verifier",
+    "redirect_uri",
+    "device_code",
+    "otp",
+    "mfa_token",
+    "oob_code",
+    "binding_code",
+    "recovery_code",
+    "subject_token",
+    "subject_token_type",
+    "requested_token_type",
+    "actor_token",
+    "actor_token_type",
+    "auth_req_id",
+];
 
 /// Trait for authenticating an Auth0 client.
 #[async_trait]
@@ -29,7 +99,75 @@ pub trait Authenticatable {
     /// ```
     async fn authenticate(&mut self) -> Auth0Result<String>;
 
-    /// Authenticates the a user from its password.
+    /// Authenticates the client from its configuration, requesting only the given scopes.
+    ///
+    /// The extra parameters are sent as is to the token endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn new_client() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Authenticatable;
+    /// let mut client =
+    ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
+    ///
+    /// client
+    ///     .authenticate_with_scope("read:users", Default::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn authenticate_with_scope(
+        &mut self,
+        scope: &str,
+        extra_params: HashMap<&str, String>,
+    ) -> Auth0Result<String>;
+
+    /// Authenticates the client from its configuration for another API than its audience.
+    ///
+    /// Tokens are tracked per audience and can be retrieved through
+    /// [`Authenticatable::access_token_for`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn new_client() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Authenticatable;
+    /// let mut client =
+    ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
+    ///
+    /// let orders_token = client.authenticate_for("https://orders.example.com").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn authenticate_for(&mut self, audience: &str) -> Auth0Result<String>;
+
+    /// Authenticates the a user from its password and returns the tokens issued for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn new_client() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Authenticatable;
+    /// let mut client =
+    ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
+    ///
+    /// let tokens = client
+    ///     .authenticate_user("user@email.com".to_string(), "password".to_string())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn authenticate_user(
+        &mut self,
+        username: String,
+        password: String,
+    ) -> Auth0Result<AccessTokenResponse>;
+
+    /// Authenticates the a user from its password against a specific connection (realm).
+    ///
+    /// It always uses the `password-realm` grant, whatever the grant type of the client is,
+    /// and returns the tokens issued for the user.
     ///
     /// # Example
     ///
@@ -39,11 +177,22 @@ pub trait Authenticatable {
     /// let mut client =
     ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
     ///
-    /// client.authenticate_user("user@email.com".to_string(), "password".to_string()).await?;
+    /// let tokens = client
+    ///     .authenticate_user_with_realm(
+    ///         "user@email.com".to_string(),
+    ///         "password".to_string(),
+    ///         "Username-Password-Authentication".to_string(),
+    ///     )
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    async fn authenticate_user(&mut self, username: String, password: String) -> Auth0Result<()>;
+    async fn authenticate_user_with_realm(
+        &mut self,
+        username: String,
+        password: String,
+        realm: String,
+    ) -> Auth0Result<AccessTokenResponse>;
 
     /// Calls an authentication request with body
     async fn authenticate_with_body(
@@ -51,52 +200,141 @@ pub trait Authenticatable {
         body: HashMap<&str, String>,
     ) -> Auth0Result<AccessTokenResponse>;
 
+    /// Gets a new access token from a refresh token using the `refresh_token` grant.
+    ///
+    /// Both the new access token and the rotated refresh token (if any) are stored on the client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn new_client() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Authenticatable;
+    /// let mut client =
+    ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
+    ///
+    /// client.refresh_access_token("refresh_token").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn refresh_access_token(&mut self, refresh_token: &str) -> Auth0Result<String>;
+
+    /// Revokes a refresh token so it can no longer be used to get new access tokens.
+    ///
+    /// If it is the refresh token stored on the client, it is removed from it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn new_client() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Authenticatable;
+    /// let mut client =
+    ///     auth0_client::Auth0Client::new("client_id", "client_secret", "domain", "audience");
+    ///
+    /// client.revoke_refresh_token("refresh_token").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn revoke_refresh_token(&mut self, refresh_token: &str) -> Auth0Result<()>;
+
     /// Returns the access token if autenticated or `None` if it is not.
     fn access_token(&self) -> Option<String>;
+
+    /// Returns the refresh token if one has been received or `None` if it has not.
+    fn refresh_token(&self) -> Option<String>;
+
+    /// Returns the access token issued for the given audience or `None` if there is none.
+    fn access_token_for(&self, audience: &str) -> Option<String>;
 }
 
-/// The token type we use to authenticate.
-#[derive(Deserialize)]
-enum TokenType {
+/// The type of the access tokens issued by Auth0.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub enum TokenType {
+    #[default]
     Bearer,
+    /// A token bound to a DPoP key, which must be sent along with a DPoP proof.
+    #[serde(rename = "DPoP")]
+    Dpop,
+    /// Any other token type, e.g. `N_A` for tokens not meant to be used as access tokens.
+    #[serde(other)]
+    Other,
 }
 
 /// The response we get when we authenticate.
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct AccessTokenResponse {
     pub access_token: String,
+    #[serde(default)]
+    pub token_type: TokenType,
+    /// The number of seconds the access token is valid for.
+    pub expires_in: Option<u64>,
+    pub scope: Option<String>,
+    pub id_token: Option<String>,
+    pub refresh_token: Option<String>,
+    /// The thumbprint of the DPoP key the tokens are bound to, if any.
+    #[serde(skip)]
+    pub jkt: Option<String>,
 }
 
 #[async_trait]
 impl Authenticatable for Auth0Client {
     async fn authenticate(&mut self) -> Auth0Result<String> {
-        let url = URL_REGEX
-            .replace_all(&format!("{}/oauth/token", self.domain), "$1")
-            .to_string();
+        let url = self.endpoint_url(TOKEN_PATH);
 
         tracing::debug!("Starting authentication at {url}...");
 
+        let body = self.client_body()?;
+        let response = self.authenticate_with_body(body).await?;
+
+        self.store_access_token(&response);
+        Ok(response.access_token)
+    }
+
+    async fn authenticate_for(&mut self, audience: &str) -> Auth0Result<String> {
         let body = {
-            let mut body = std::collections::HashMap::new();
+            let mut body = self.client_body()?;
 
-            body.insert("grant_type", self.grant_type.to_string());
-            body.insert("client_id", self.client_id.clone());
-            body.insert("client_secret", self.client_secret.clone());
-            body.insert("audience", self.audience.clone());
+            body.insert("audience", audience.to_owned());
             body
         };
 
         let response = self.authenticate_with_body(body).await?;
 
-        self.access_token = Some(response.access_token.clone());
+        if audience == self.audience {
+            self.store_access_token(&response);
+        }
+        self.audience_tokens
+            .insert(audience.to_owned(), response.access_token.clone());
+        Ok(response.access_token)
+    }
+
+    async fn authenticate_with_scope(
+        &mut self,
+        scope: &str,
+        extra_params: HashMap<&str, String>,
+    ) -> Auth0Result<String> {
+        check_extra_params(extra_params.keys().copied(), RESERVED_TOKEN_PARAMS)?;
+
+        let body = {
+            let mut body = extra_params;
+
+            body.extend(self.client_body()?);
+            body.insert("scope", scope.to_owned());
+            body
+        };
+
+        let response = self.authenticate_with_body(body).await?;
+
+        self.store_access_token(&response);
         Ok(response.access_token)
     }
 
-    async fn authenticate_user(&mut self, username: String, password: String) -> Auth0Result<()> {
-        let url = URL_REGEX
-            .replace_all(&format!("{}/oauth/token", self.domain), "$1")
-            .to_string();
+    async fn authenticate_user(
+        &mut self,
+        username: String,
+        password: String,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let url = self.endpoint_url(TOKEN_PATH);
 
         tracing::debug!("Starting authentication at {url}...");
 
@@ -105,110 +343,494 @@ impl Authenticatable for Auth0Client {
 
             body.insert("grant_type", self.grant_type.to_string());
             body.insert("client_id", self.client_id.clone());
-            body.insert("client_secret", self.client_secret.clone());
+            body.extend(self.client_authentication()?);
             body.insert("audience", self.audience.clone());
             body.insert("username", username);
             body.insert("password", password);
+            if let Some(organization) = &self.organization {
+                body.insert("organization", organization.clone());
+            }
             body
         };
 
-        self.authenticate_with_body(body).await?;
+        self.authenticate_with_body(body).await
+    }
 
-        Ok(())
+    async fn authenticate_user_with_realm(
+        &mut self,
+        username: String,
+        password: String,
+        realm: String,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::PasswordRealm.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("audience", self.audience.clone());
+            body.insert("username", username);
+            body.insert("password", password);
+            body.insert("realm", realm);
+            if let Some(organization) = &self.organization {
+                body.insert("organization", organization.clone());
+            }
+            body
+        };
+
+        self.authenticate_with_body(body).await
     }
 
     async fn authenticate_with_body(
         &mut self,
         body: HashMap<&str, String>,
     ) -> Auth0Result<AccessTokenResponse> {
-        let url = URL_REGEX
-            .replace_all(&format!("{}/oauth/token", self.domain), "$1")
-            .to_string();
+        let mut response: AccessTokenResponse = self.token_request(body).await?;
 
-        tracing::debug!("Starting authentication at {url}...");
+        self.bind_to_dpop_key(&mut response);
+        Ok(response)
+    }
 
-        let response = self.http_client.post(&url).json(&body).send().await?;
-        let status = response.status();
-        let resp_body = response.text().await?;
+    async fn refresh_access_token(&mut self, refresh_token: &str) -> Auth0Result<String> {
+        let body = {
+            let mut body = HashMap::new();
 
-        tracing::debug!("Response from Auth0 ({}): {resp_body}", status.as_u16());
+            body.insert("grant_type", GrantType::RefreshToken.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("refresh_token", refresh_token.to_owned());
+            body
+        };
+
+        let response = self.authenticate_with_body(body).await?;
+
+        self.rotate_token_set(TokenSet {
+            access_token: Some(response.access_token.clone()),
+            // Auth0 only sends a new refresh token back when rotation is enabled.
+            refresh_token: Some(
+                response
+                    .refresh_token
+                    .unwrap_or_else(|| refresh_token.to_owned()),
+            ),
+        })?;
+        self.access_token_jkt = response.jkt.clone();
+        Ok(response.access_token)
+    }
+
+    async fn revoke_refresh_token(&mut self, refresh_token: &str) -> Auth0Result<()> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("token", refresh_token.to_owned());
+            body
+        };
+
+        self.send_authentication_request(Method::POST, "/oauth/revoke", None, Some(body))
+            .await?;
 
-        Ok(serde_json::from_str::<AccessTokenResponse>(&resp_body)?)
+        if self.refresh_token.as_deref() == Some(refresh_token) {
+            self.refresh_token = None;
+        }
+        Ok(())
     }
 
     fn access_token(&self) -> Option<String> {
         self.access_token.clone()
     }
+
+    fn refresh_token(&self) -> Option<String> {
+        self.refresh_token.clone()
+    }
+
+    fn access_token_for(&self, audience: &str) -> Option<String> {
+        if audience == self.audience && self.access_token.is_some() {
+            return self.access_token.clone();
+        }
+        self.audience_tokens.get(audience).cloned()
+    }
 }
 
-/// Fetches the jwks from the given URI.
-async fn fetch_jwks(url: &str) -> Auth0Result<JwkSet> {
-    let url = URL_REGEX.replace_all(url, "$1").to_string();
-    let res = reqwest::get(url).await?;
-    let val = res.json::<JwkSet>().await?;
+impl Auth0Client {
+    /// Builds the token request body authenticating the client from its configuration.
+    fn client_body(&self) -> Auth0Result<HashMap<&'static str, String>> {
+        let mut body = HashMap::new();
+
+        body.insert("grant_type", self.grant_type.to_string());
+        body.insert("client_id", self.client_id.clone());
+        body.extend(self.client_authentication()?);
+        body.insert("audience", self.audience.clone());
+        if let Some(organization) = &self.organization {
+            body.insert("organization", organization.clone());
+        }
+        Ok(body)
+    }
+
+    /// Sends a request to the token endpoint, along with the extra token parameters of the client.
+    ///
+    /// The fields of `body` take precedence over the extra token parameters.
+    pub(crate) async fn token_request<B, R>(&self, body: B) -> Auth0Result<R>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        let mut params = self
+            .extra_token_params
+            .iter()
+            .map(|(name, value)| (name.clone(), serde_json::Value::from(value.as_str())))
+            .collect::<serde_json::Map<_, _>>();
+
+        if let serde_json::Value::Object(body) = serde_json::to_value(body)? {
+            params.extend(body);
+        }
+
+        self.authentication_request(Method::POST, TOKEN_PATH, None, Some(params))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    /// Stores the access token of the response on the client, along with the key it is bound to.
+    fn store_access_token(&mut self, response: &AccessTokenResponse) {
+        self.access_token = Some(response.access_token.clone());
+        self.access_token_jkt = response.jkt.clone();
+    }
+
+    /// Sets the thumbprint of the DPoP key of the client on the tokens bound to it.
+    pub(crate) fn bind_to_dpop_key(&self, response: &mut AccessTokenResponse) {
+        if response.token_type == TokenType::Dpop {
+            response.jkt = self.dpop_key.as_ref().map(DpopKey::thumbprint);
+        }
+    }
+
+    /// Make a request towards the Auth0 authentication API. It uses the `domain` field as the base URL.
+    ///
+    /// Errors returned by the authentication API are mapped to [`AuthenticationError`].
+    ///
+    /// # Parameters
+    ///
+    /// * `method`: The HTTP method to use.
+    /// * `path`: The path to use for the request.
+    /// * `token`: The bearer token to send with the request, if any.
+    /// * `body`: The body to send with the request.
+    pub(crate) async fn authentication_request<B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<B>,
+    ) -> Auth0Result<Option<R>>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        match self
+            .send_authentication_request(method, path, token, body)
+            .await?
+        {
+            Some((_, resp_body)) => Ok(Some(serde_json::from_str::<R>(&resp_body)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Same as [`Auth0Client::authentication_request`] but returns the content type
+    /// and the raw body of the response instead of deserializing it.
+    pub(crate) async fn send_authentication_request<B>(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<B>,
+    ) -> Auth0Result<Option<(String, String)>>
+    where
+        B: Serialize,
+    {
+        let url = self.endpoint_url(path);
+        let body = body.map(|body| serde_json::to_value(body)).transpose()?;
+        let mut dpop_nonce = None;
+
+        tracing::debug!("Starting {method} authentication request at {url}...");
+
+        loop {
+            let mut req = match method {
+                Method::GET => self.http_client.get(&url),
+                Method::POST => self.http_client.post(&url),
+                Method::DELETE => self.http_client.delete(&url),
+                _ => return Err(Error::Unimplemented),
+            };
+
+            if let Some(token) = token {
+                req = req.header("Authorization", format!("Bearer {token}"));
+            }
+
+            if let (Some(key), TOKEN_PATH) = (&self.dpop_key, path) {
+                req = req.header(
+                    "DPoP",
+                    key.proof(method.as_str(), &url, None, dpop_nonce.as_deref())?,
+                );
+            }
+
+            if let Some(body) = &body {
+                req = req.json(body)
+            }
+
+            match Self::send_prepared_authentication_request(req).await {
+                // Auth0 may require a nonce it sends back in the first response.
+                Err(Error::Authentication(AuthenticationError::UseDpopNonce(nonce)))
+                    if dpop_nonce.is_none() =>
+                {
+                    tracing::debug!("Retrying with the DPoP nonce sent by Auth0...");
+                    dpop_nonce = Some(nonce);
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Same as [`Auth0Client::authentication_request`] but POSTs the body form-encoded,
+    /// as required by some endpoints of the authentication API.
+    pub(crate) async fn authentication_form_request<B, R>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Auth0Result<R>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        let url = self.endpoint_url(path);
+
+        tracing::debug!("Starting POST authentication request at {url}...");
+
+        let req = self.http_client.post(&url).form(body);
+
+        match Self::send_prepared_authentication_request(req).await? {
+            Some((_, resp_body)) => Ok(serde_json::from_str::<R>(&resp_body)?),
+            None => Err(Error::InvalidResponseBody),
+        }
+    }
+
+    /// Sends a request towards the authentication API and maps its response.
+    async fn send_prepared_authentication_request(
+        req: RequestBuilder,
+    ) -> Auth0Result<Option<(String, String)>> {
+        let response = req.send().await?;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_owned();
+        let dpop_nonce = response
+            .headers()
+            .get("DPoP-Nonce")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let resp_body = response.text().await?;
+
+        tracing::debug!("Response from Auth0 ({}): {resp_body}", status.as_u16());
+
+        if status.is_success() {
+            if status == StatusCode::NO_CONTENT || resp_body.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some((content_type, resp_body)))
+            }
+        } else {
+            match (status, serde_json::from_str::<Auth0OAuthError>(&resp_body)) {
+                (StatusCode::TOO_MANY_REQUESTS, _) => Err(Error::TooManyRequests),
+                (_, Ok(mut oauth_error)) => match (oauth_error.error.as_str(), dpop_nonce) {
+                    ("use_dpop_nonce", Some(nonce)) => {
+                        Err(AuthenticationError::UseDpopNonce(nonce).into())
+                    }
+                    ("mfa_required", _) if oauth_error.mfa_token.is_some() => {
+                        Err(Error::MfaRequired {
+                            mfa_token: oauth_error.mfa_token.take().unwrap_or_default(),
+                        })
+                    }
+                    _ => Err(AuthenticationError::from(oauth_error).into()),
+                },
+                (StatusCode::UNAUTHORIZED, Err(_)) => Err(Error::Unauthorized),
+                (_, Err(err)) => Err(err.into()),
+            }
+        }
+    }
+
+    /// Polls the token endpoint with the given body until the request is either approved or rejected.
+    ///
+    /// `authorization_pending` responses are retried every `interval` seconds and
+    /// `slow_down` responses increase the interval as required by the specification.
+    /// Polling stops with [`AuthenticationError::ExpiredToken`] once the next attempt would
+    /// be later than `expires_in` seconds from now, if given.
+    pub(crate) async fn poll_token(
+        &mut self,
+        body: HashMap<&'static str, String>,
+        mut interval: u64,
+        expires_in: Option<u64>,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let deadline =
+            expires_in.map(|expires_in| Instant::now() + Duration::from_secs(expires_in));
+
+        loop {
+            if let Some(deadline) = deadline {
+                if Instant::now() + Duration::from_secs(interval) >= deadline {
+                    return Err(AuthenticationError::ExpiredToken.into());
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+
+            match self.authenticate_with_body(body.clone()).await {
+                Err(Error::Authentication(AuthenticationError::AuthorizationPending)) => {
+                    tracing::debug!("Authorization is still pending...");
+                }
+                Err(Error::Authentication(AuthenticationError::SlowDown)) => {
+                    interval += SLOW_DOWN_INCREMENT;
+                    tracing::debug!("Slowing down polling to {interval}s...");
+                }
+                res => return res,
+            }
+        }
+    }
+}
 
-    Ok(val)
+/// An error representing the possible errors that can occur when interacting with the Auth0 authentication API.
+#[derive(Debug, ThisError)]
+pub enum AuthenticationError {
+    #[error("Authorization pending")]
+    AuthorizationPending,
+    #[error("Slow down")]
+    SlowDown,
+    #[error("Expired token")]
+    ExpiredToken,
+    #[error("Access denied: {0}")]
+    AccessDenied(String),
+    #[error("Invalid grant: {0}")]
+    InvalidGrant(String),
+    #[error("DPoP nonce required: {0}")]
+    UseDpopNonce(String),
+    #[error("Unknown authentication error: {0}")]
+    Unknown(String),
 }
 
-/// Fetches the jwks from the given URI if needed.
-async fn fetch_jwks_if_needed(jwks: Option<&JwkSet>, authority: &str) -> Auth0Result<JwkSet> {
-    match jwks {
-        Some(jwks) => Ok(jwks.clone()),
-        None => fetch_jwks(&format!("{authority}/.well-known/jwks.json")).await,
+impl From<Auth0OAuthError> for AuthenticationError {
+    fn from(oauth_error: Auth0OAuthError) -> Self {
+        let description = oauth_error
+            .error_description
+            .unwrap_or_else(|| oauth_error.error.clone());
+
+        match oauth_error.error.as_str() {
+            "authorization_pending" => Self::AuthorizationPending,
+            "slow_down" => Self::SlowDown,
+            "expired_token" => Self::ExpiredToken,
+            "access_denied" => Self::AccessDenied(description),
+            "invalid_grant" => Self::InvalidGrant(description),
+            _ => Self::Unknown(description),
+        }
     }
 }
 
-/// Attempts to find the key in the jwks.
-/// If it fails, it fetches the jwks again and tries again.
-async fn get_jwk(kid: &str, jwks: JwkSet, authority: &str) -> Auth0Result<(Jwk, JwkSet)> {
-    match jwks.find(kid) {
-        Some(jwk) => Ok((jwk.clone(), jwks)),
+/// Attempts to find the key in the given jwks, or in the cached jwks of the authority if there is none.
+/// If the cached jwks misses it, it is refreshed and searched again, as the keys may have been
+/// rotated. A given jwks is used as is.
+async fn get_jwk(
+    kid: &str,
+    jwks: Option<&JwkSet>,
+    source: &JwksSource<'_>,
+) -> Auth0Result<(Jwk, JwkSet)> {
+    let jwks = match jwks {
+        Some(jwks) => jwks.clone(),
         None => {
-            let jwks = fetch_jwks(authority).await?;
+            let jwks = JwksCache::global().get_from(source).await?;
 
-            Ok((jwks.find(kid).ok_or(Error::JwtMissingKid)?.clone(), jwks))
+            match jwks.find(kid) {
+                Some(_) => jwks,
+                None => JwksCache::global().refresh_from(source).await?,
+            }
         }
-    }
+    };
+
+    Ok((jwks.find(kid).ok_or(Error::JwtMissingKid)?.clone(), jwks))
 }
 
-/// Validates a JWT token and returns its decoded payload.
+/// Validates a JWT token and decodes its payload into `C`.
+///
+/// Use [`Claims`] to only read the claims the crate relies on, or your own type to
+/// decode the rest of the payload.
+///
+/// RSA (`RS*` and `PS*`), elliptic curve (`ES256` and `ES384`) and `EdDSA` keys are
+/// supported, the algorithm of the token having to be one of the `validation` algorithms.
 ///
 /// # Arguments
 ///
 /// * `token` - The JWT token to validate.
 /// * `authority` - The authority to retreive the jwks from.
 /// * `validations` - The validations to perform on the token.
+/// * `jwks` - The jwks to use instead of the ones cached by [`JwksCache::global`], if any.
+///   It is never refreshed, so tokens signed by a key missing from it are rejected.
 ///
 /// # Example
 /// ```
 /// # async fn validate_jwt() -> auth0_client::error::Auth0Result<()> {
-/// # use alcoholic_jwt::Validation;
-/// # use auth0_client::authorization::valid_jwt;
-/// valid_jwt(
+/// # use jsonwebtoken::{Algorithm, Validation};
+/// # use auth0_client::authorization::{valid_jwt, Claims};
+/// #[derive(serde::Deserialize)]
+/// struct MyClaims {
+///     sub: String,
+///     tenant: String,
+/// }
+///
+/// valid_jwt::<Claims>(
+///     "...jwt_token...",
+///     "authority_to_retreive_jwks_from",
+///     Validation::new(Algorithm::RS256),
+///     None,
+/// ).await?;
+///
+/// let (token, _) = valid_jwt::<MyClaims>(
 ///     "...jwt_token...",
 ///     "authority_to_retreive_jwks_from",
-///     vec![Validation::SubjectPresent, Validation::NotExpired],
+///     Validation::new(Algorithm::RS256),
 ///     None,
 /// ).await?;
+/// println!("{} belongs to {}", token.claims.sub, token.claims.tenant);
 /// # Ok(())
 /// # }
-pub async fn valid_jwt(
+/// ```
+pub async fn valid_jwt<C: DeserializeOwned>(
     token: &str,
     authority: &str,
     validation: Validation,
     jwks: Option<&JwkSet>,
-) -> Auth0Result<(TokenData<Claims>, JwkSet)> {
+) -> Auth0Result<(TokenData<C>, JwkSet)> {
+    valid_jwt_from(token, &JwksSource::of(authority), validation, jwks).await
+}
+
+/// Same as [`valid_jwt`], the jwks being fetched from the given source if none is given.
+pub(crate) async fn valid_jwt_from<C: DeserializeOwned>(
+    token: &str,
+    source: &JwksSource<'_>,
+    validation: Validation,
+    jwks: Option<&JwkSet>,
+) -> Auth0Result<(TokenData<C>, JwkSet)> {
     let header = decode_header(token)?;
     let kid: String = header.kid.ok_or(Error::JwtMissingKid)?;
-    let jwks = fetch_jwks_if_needed(jwks, authority).await?;
-    let jwk = get_jwk(&kid, jwks, authority).await?;
-    // let jwt = validate(token, &jwk.0, validations)?;
+    let jwk = get_jwk(&kid, jwks, source).await?;
 
     let jwt = match jwk.0.algorithm {
         AlgorithmParameters::RSA(ref rsa) => {
             let key =
                 DecodingKey::from_rsa_components(&rsa.n, &rsa.e).map_err(|_| Error::InvalidJwk)?;
-            decode::<Claims>(token, &key, &validation)?
+            decode::<C>(token, &key, &validation)?
+        }
+        AlgorithmParameters::EllipticCurve(ref ec) => {
+            let key =
+                DecodingKey::from_ec_components(&ec.x, &ec.y).map_err(|_| Error::InvalidJwk)?;
+            decode::<C>(token, &key, &validation)?
+        }
+        AlgorithmParameters::OctetKeyPair(ref okp) => {
+            let key = DecodingKey::from_ed_components(&okp.x).map_err(|_| Error::InvalidJwk)?;
+            decode::<C>(token, &key, &validation)?
         }
         _ => return Err(Error::InvalidJwk),
     };
@@ -216,10 +838,213 @@ pub async fn valid_jwt(
     Ok((jwt, jwk.1))
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Claims {}
+/// Validates a JWT token signed with a shared secret (`HS256`) and decodes its payload into `C`.
+///
+/// Only meant for legacy applications signing their tokens with the client secret:
+/// `HS256` is the only accepted algorithm, whatever the `validation` algorithms are.
+///
+/// # Arguments
+///
+/// * `token` - The JWT token to validate.
+/// * `secret` - The secret the token is signed with, usually the client secret.
+/// * `validations` - The validations to perform on the token.
+///
+/// # Example
+/// ```
+/// # fn validate_jwt() -> auth0_client::error::Auth0Result<()> {
+/// # use jsonwebtoken::{Algorithm, Validation};
+/// # use auth0_client::authorization::{valid_jwt_hs256, Claims};
+/// let token = valid_jwt_hs256::<Claims>(
+///     "...jwt_token...",
+///     "client_secret",
+///     Validation::new(Algorithm::HS256),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn valid_jwt_hs256<C: DeserializeOwned>(
+    token: &str,
+    secret: &str,
+    mut validation: Validation,
+) -> Auth0Result<TokenData<C>> {
+    validation.algorithms = vec![Algorithm::HS256];
 
-#[cfg(test)]
+    let key = DecodingKey::from_secret(secret.as_bytes());
+
+    Ok(decode::<C>(token, &key, &validation)?)
+}
+
+/// The registered claims of Auth0 access tokens.
+#[derive(Debug, Deserialize)]
+pub struct Claims {
+    pub sub: Option<String>,
+    pub iss: Option<String>,
+    #[serde(default)]
+    pub aud: OneOrMany<String>,
+    pub exp: Option<i64>,
+    pub iat: Option<i64>,
+    /// The client ID of the application the token was issued to.
+    pub azp: Option<String>,
+    /// The space-separated scopes granted to the token.
+    pub scope: Option<String>,
+    /// The permissions of the user, when RBAC is enabled on the API.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// The grant type the token was issued with, e.g. `client-credentials`.
+    pub gty: Option<String>,
+    /// The confirmation claim of certificate-bound access tokens.
+    pub cnf: Option<Confirmation>,
+    /// The ID of the organization the token was issued for, if any.
+    pub org_id: Option<String>,
+    /// The name of the organization the token was issued for, when enabled on the tenant.
+    pub org_name: Option<String>,
+    /// Every other claim of the token, such as namespaced custom claims.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl Claims {
+    /// Returns the scopes granted to the token, from the space-separated `scope` claim.
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.as_deref().unwrap_or_default().split_whitespace()
+    }
+
+    /// Returns whether the token was granted the given scope.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().any(|granted| granted == scope)
+    }
+
+    /// Returns whether the `permissions` claim of the token contains the given permission.
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|granted| granted == permission)
+    }
+
+    /// Checks that the token was granted every given scope.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn protected(token: &str) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::Claims;
+    /// # use auth0_client::authorization::validation::Auth0Validation;
+    /// let validation = Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com");
+    /// let (token, _) = validation.validate::<Claims>(token, None).await?;
+    ///
+    /// // Fails with `Error::InsufficientScope` listing the missing scopes.
+    /// token.claims.require_scopes(&["read:users", "update:users"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require_scopes(&self, scopes: &[&str]) -> Auth0Result<()> {
+        insufficient_scope(scopes.iter().filter(|scope| !self.has_scope(scope)))
+    }
+
+    /// Deserializes the custom claims under a namespace, with the namespace stripped from their names.
+    ///
+    /// # Example
+    /// ```
+    /// # fn roles(claims: auth0_client::authorization::Claims) -> auth0_client::error::Auth0Result<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct MyClaims {
+    ///     // From the `https://myapp.example/roles` claim
+    ///     roles: Vec<String>,
+    /// }
+    ///
+    /// let custom: MyClaims = claims.namespaced("https://myapp.example")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn namespaced<T: DeserializeOwned>(&self, namespace: &str) -> Auth0Result<T> {
+        namespaced_claims(&self.other, namespace)
+    }
+
+    /// Checks that the `permissions` claim of the token contains every given permission.
+    pub fn require_permissions(&self, permissions: &[&str]) -> Auth0Result<()> {
+        insufficient_scope(
+            permissions
+                .iter()
+                .filter(|permission| !self.has_permission(permission)),
+        )
+    }
+}
+
+/// Deserializes the claims whose name starts with the namespace, stripping it from their names.
+pub(crate) fn namespaced_claims<T: DeserializeOwned>(
+    claims: &HashMap<String, serde_json::Value>,
+    namespace: &str,
+) -> Auth0Result<T> {
+    let namespace = format!("{}/", namespace.trim_end_matches('/'));
+    let claims = claims
+        .iter()
+        .filter_map(|(name, value)| {
+            name.strip_prefix(&namespace)
+                .map(|name| (name.to_owned(), value.clone()))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    Ok(serde_json::from_value(serde_json::Value::Object(claims))?)
+}
+
+/// Returns an [`Error::InsufficientScope`] if any scope or permission is missing.
+fn insufficient_scope<'a>(missing: impl Iterator<Item = &'a &'a str>) -> Auth0Result<()> {
+    let missing: Vec<String> = missing.map(|scope| scope.to_string()).collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InsufficientScope { missing })
+    }
+}
+
+/// A claim which is either a single value or an array of values, such as `aud`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OneOrMany<T>(pub Vec<T>);
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        Self(vec![])
+    }
+}
+
+impl<T> Deref for OneOrMany<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(values: OneOrMany<T>) -> Self {
+        values.0
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneOrMany<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<T> {
+            One(T),
+            Many(Vec<T>),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::One(value) => Self(vec![value]),
+            Repr::Many(values) => Self(values),
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for OneOrMany<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.as_slice() {
+            [value] => value.serialize(serializer),
+            values => values.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use mockito::{mock, Mock};
     use serde_json::json;
@@ -255,6 +1080,356 @@ mod tests {
             client.authenticate().await.unwrap();
             assert_eq!(client.access_token, Some("access_token".to_owned()));
         }
+
+        #[tokio::test]
+        async fn send_the_organization() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "grant_type": "client_credentials",
+                    "organization": "org_W2pqeQg6pL6Ar3sk"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "org_access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+            client.organization(Some("org_W2pqeQg6pL6Ar3sk"));
+
+            let resp = client.authenticate().await.unwrap();
+
+            assert_eq!(resp, "org_access_token");
+        }
+    }
+
+    mod authenticate_for {
+        use super::*;
+
+        #[tokio::test]
+        async fn track_tokens_per_audience() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "audience": "https://orders.example.com"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "orders_access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .authenticate_for("https://orders.example.com")
+                .await
+                .unwrap();
+
+            assert_eq!(resp, "orders_access_token");
+            assert_eq!(client.access_token, None);
+            assert_eq!(
+                client.access_token_for("https://orders.example.com"),
+                Some("orders_access_token".to_owned())
+            );
+            assert_eq!(client.access_token_for("https://audience.com"), None);
+        }
+    }
+
+    mod authenticate_with_scope {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_extra_token_params() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "scope": "read:orders",
+                    "ext-tenant": "acme"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "extra_access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+            client
+                .extra_token_params(HashMap::from([(
+                    "ext-tenant".to_owned(),
+                    "acme".to_owned(),
+                )]))
+                .unwrap();
+
+            let resp = client
+                .authenticate_with_scope("read:orders", HashMap::new())
+                .await
+                .unwrap();
+
+            assert_eq!(resp, "extra_access_token");
+        }
+
+        #[tokio::test]
+        async fn reject_reserved_extra_params() {
+            let mut client = new_client();
+
+            let extra_params = HashMap::from([("client_id", "another_client_id".to_owned())]);
+            let resp = client
+                .authenticate_with_scope("read:users", extra_params)
+                .await;
+
+            match resp {
+                Err(Error::ReservedParameter(name)) => assert_eq!(name, "client_id"),
+                _ => panic!("Expected ReservedParameter variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn send_the_scope_and_extra_params() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "grant_type": "client_credentials",
+                    "audience": "https://audience.com",
+                    "scope": "read:users",
+                    "custom": "value"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "scoped_access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let extra_params = HashMap::from([("custom", "value".to_owned())]);
+            let resp = client
+                .authenticate_with_scope("read:users", extra_params)
+                .await
+                .unwrap();
+
+            assert_eq!(resp, "scoped_access_token");
+            assert_eq!(client.access_token, Some("scoped_access_token".to_owned()));
+        }
+    }
+
+    mod authenticate_with_body {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_full_token_response() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "access_token": "access_token",
+                        "refresh_token": "refresh_token",
+                        "id_token": "id_token",
+                        "token_type": "Bearer",
+                        "expires_in": 86400,
+                        "scope": "openid offline_access"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.authenticate_with_body(HashMap::new()).await.unwrap();
+
+            assert_eq!(resp.token_type, TokenType::Bearer);
+            assert_eq!(resp.expires_in, Some(86400));
+            assert_eq!(resp.scope.as_deref(), Some("openid offline_access"));
+            assert_eq!(resp.id_token.as_deref(), Some("id_token"));
+            assert_eq!(resp.refresh_token.as_deref(), Some("refresh_token"));
+        }
+
+        #[tokio::test]
+        async fn map_authentication_api_errors() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(403)
+                .with_body(
+                    json!({
+                        "error": "invalid_grant",
+                        "error_description": "Wrong email or password."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.authenticate().await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::InvalidGrant(msg))) => {
+                    assert_eq!(msg, "Wrong email or password.")
+                }
+                _ => panic!("Expected InvalidGrant variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod authenticate_user_with_realm {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_realm() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "grant_type": "http://auth0.com/oauth/grant-type/password-realm",
+                    "username": "test@example.com",
+                    "password": "password",
+                    "realm": "ldap"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            client
+                .authenticate_user_with_realm(
+                    "test@example.com".to_owned(),
+                    "password".to_owned(),
+                    "ldap".to_owned(),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    mod authenticate_user {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_user_tokens() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "username": "test@example.com",
+                    "password": "password"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "access_token": "user_access_token",
+                        "refresh_token": "refresh_token",
+                        "id_token": "id_token"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+            client.grant_type(GrantType::Password);
+
+            let resp = client
+                .authenticate_user("test@example.com".to_owned(), "password".to_owned())
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "user_access_token");
+            assert_eq!(resp.id_token.as_deref(), Some("id_token"));
+            assert_eq!(resp.refresh_token.as_deref(), Some("refresh_token"));
+            assert_eq!(client.access_token, None);
+        }
+
+        #[tokio::test]
+        async fn return_the_mfa_token_when_mfa_is_required() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "username": "mfa@example.com"
+                })))
+                .with_status(403)
+                .with_body(
+                    json!({
+                        "error": "mfa_required",
+                        "error_description": "Multifactor authentication required",
+                        "mfa_token": "Fe26...Ha"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+            client.grant_type(GrantType::Password);
+
+            let resp = client
+                .authenticate_user("mfa@example.com".to_owned(), "password".to_owned())
+                .await;
+
+            match resp {
+                Err(Error::MfaRequired { mfa_token }) => assert_eq!(mfa_token, "Fe26...Ha"),
+                _ => panic!("Expected MfaRequired variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod refresh_access_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn save_the_rotated_tokens_to_the_client() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(mockito::Matcher::PartialJson(json!({
+                    "grant_type": "refresh_token",
+                    "refresh_token": "refresh_token"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "access_token": "new_access_token",
+                        "refresh_token": "new_refresh_token",
+                        "token_type": "Bearer"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let access_token = client.refresh_access_token("refresh_token").await.unwrap();
+
+            assert_eq!(access_token, "new_access_token");
+            assert_eq!(client.access_token, Some("new_access_token".to_owned()));
+            assert_eq!(client.refresh_token, Some("new_refresh_token".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn keep_the_refresh_token_without_rotation() {
+            let _m = auth_mock();
+            let mut client = new_client();
+
+            client.refresh_access_token("refresh_token").await.unwrap();
+
+            assert_eq!(client.access_token, Some("access_token".to_owned()));
+            assert_eq!(client.refresh_token(), Some("refresh_token".to_owned()));
+        }
+    }
+
+    mod revoke_refresh_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn remove_the_revoked_token_from_the_client() {
+            let _m = mock("POST", "/oauth/revoke")
+                .match_body(mockito::Matcher::Json(json!({
+                    "client_id": "client_id",
+                    "client_secret": "client_secret",
+                    "token": "refresh_token"
+                })))
+                .with_status(200)
+                .create();
+            let mut client = new_client();
+            client.refresh_token = Some("refresh_token".to_owned());
+
+            client.revoke_refresh_token("refresh_token").await.unwrap();
+
+            assert_eq!(client.refresh_token, None);
+        }
+
+        #[tokio::test]
+        async fn map_authentication_api_errors() {
+            let _m = mock("POST", "/oauth/revoke")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "error": "invalid_request",
+                        "error_description": "Missing required parameter: token"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.revoke_refresh_token("").await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::Unknown(msg))) => {
+                    assert_eq!(msg, "Missing required parameter: token")
+                }
+                _ => panic!("Expected Unknown variant, got: {resp:?}"),
+            }
+        }
     }
 
     mod access_token {
@@ -278,28 +1453,137 @@ mod tests {
         }
     }
 
+    mod one_or_many {
+        use super::*;
+
+        #[test]
+        fn deserialize_a_single_value() {
+            let aud: OneOrMany<String> =
+                serde_json::from_value(json!("https://audience.com")).unwrap();
+
+            assert_eq!(*aud, vec!["https://audience.com"]);
+            assert_eq!(
+                serde_json::to_value(&aud).unwrap(),
+                json!("https://audience.com")
+            );
+        }
+
+        #[test]
+        fn deserialize_an_array() {
+            let aud: OneOrMany<String> = serde_json::from_value(json!([
+                "https://audience.com",
+                "https://domain.com/userinfo"
+            ]))
+            .unwrap();
+
+            assert_eq!(
+                *aud,
+                vec!["https://audience.com", "https://domain.com/userinfo"]
+            );
+            assert!(aud.contains(&"https://domain.com/userinfo".to_owned()));
+        }
+    }
+
+    mod claims {
+        use super::*;
+
+        fn claims() -> Claims {
+            serde_json::from_value(json!({
+                "scope": "openid read:users  update:users",
+                "permissions": ["orders:read", "orders:write"],
+                "https://myapp.example/roles": ["admin"],
+                "https://myapp.example/tenant": "acme",
+                "https://other.example/roles": ["viewer"]
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn check_scopes_and_permissions() {
+            let claims = claims();
+
+            assert!(claims.has_scope("read:users"));
+            assert!(!claims.has_scope("read"));
+            assert!(claims.has_permission("orders:write"));
+            assert!(!claims.has_permission("read:users"));
+        }
+
+        #[test]
+        fn deserialize_namespaced_claims() {
+            #[derive(Debug, Deserialize, PartialEq)]
+            struct MyClaims {
+                roles: Vec<String>,
+                tenant: String,
+                plan: Option<String>,
+            }
+
+            let custom: MyClaims = claims().namespaced("https://myapp.example/").unwrap();
+
+            assert_eq!(
+                custom,
+                MyClaims {
+                    roles: vec!["admin".to_owned()],
+                    tenant: "acme".to_owned(),
+                    plan: None,
+                }
+            );
+            assert!(claims()
+                .namespaced::<MyClaims>("https://other.example")
+                .is_err());
+        }
+
+        #[test]
+        fn list_the_missing_scopes() {
+            let claims = claims();
+
+            assert!(claims
+                .require_scopes(&["read:users", "update:users"])
+                .is_ok());
+            match claims.require_scopes(&["read:users", "delete:users", "create:users"]) {
+                Err(Error::InsufficientScope { missing }) => {
+                    assert_eq!(missing, vec!["delete:users", "create:users"])
+                }
+                res => panic!("Expected InsufficientScope variant, got: {res:?}"),
+            }
+            match claims.require_permissions(&["orders:delete"]) {
+                Err(Error::InsufficientScope { missing }) => {
+                    assert_eq!(missing, vec!["orders:delete"])
+                }
+                res => panic!("Expected InsufficientScope variant, got: {res:?}"),
+            }
+        }
+    }
+
     mod jwt_validation {
         use super::*;
 
+        /// The authority of the sample jwks, apart from the one other tests cache for the server.
+        fn authority() -> String {
+            format!("{}/jwt_validation", mockito::server_url())
+        }
+
         fn jwks_mock() -> Mock {
             let jwks_response = std::fs::read_to_string("tests/data/jwks.json").unwrap();
 
-            mock("GET", "/.well-known/jwks.json")
+            mock("GET", "/jwt_validation/.well-known/jwks.json")
                 .with_status(200)
                 .with_body(jwks_response)
                 .create()
         }
 
-        mod fetch_jwks {
+        mod get_jwk {
             use super::*;
 
             #[tokio::test]
             async fn works_with_sample_response() {
                 let _m = jwks_mock();
 
-                fetch_jwks(&format!("{}/.well-known/jwks.json", mockito::server_url()))
-                    .await
-                    .unwrap();
+                let (jwk, _) =
+                    get_jwk("Jux39TkUhnGQ7QSkBRDv4", None, &JwksSource::of(&authority()))
+                        .await
+                        .unwrap();
+
+                assert_eq!(jwk.common.key_id.as_deref(), Some("Jux39TkUhnGQ7QSkBRDv4"));
             }
         }
 
@@ -320,15 +1604,132 @@ mod tests {
                 validation.validate_aud = false;
                 validation.required_spec_claims =
                     HashSet::from_iter([String::from("sub")].into_iter());
-                valid_jwt(&valid_token, &mockito::server_url(), validation, None)
+                let (token, _) = valid_jwt::<Claims>(&valid_token, &authority(), validation, None)
                     .await
                     .unwrap();
+
+                assert!(token.claims.sub.is_some());
+            }
+
+            #[tokio::test]
+            async fn validate_tokens_signed_with_other_algorithms() {
+                use jsonwebtoken::{encode, EncodingKey, Header};
+
+                let rsa_jwks = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+                let other_jwks = std::fs::read_to_string("tests/data/ec_okp_jwks.json").unwrap();
+                let mut jwks: JwkSet = serde_json::from_str(&rsa_jwks).unwrap();
+                jwks.keys
+                    .extend(serde_json::from_str::<JwkSet>(&other_jwks).unwrap().keys);
+                let rsa_pem = std::fs::read("tests/data/rsa_private_key.pem").unwrap();
+                let ec_pem = std::fs::read("tests/data/ec_private_key.pem").unwrap();
+                let ed_pem = std::fs::read("tests/data/ed25519_private_key.pem").unwrap();
+                let keys = [
+                    (
+                        Algorithm::PS256,
+                        "test_kid",
+                        EncodingKey::from_rsa_pem(&rsa_pem),
+                    ),
+                    (
+                        Algorithm::ES256,
+                        "test_ec_kid",
+                        EncodingKey::from_ec_pem(&ec_pem),
+                    ),
+                    (
+                        Algorithm::EdDSA,
+                        "test_okp_kid",
+                        EncodingKey::from_ed_pem(&ed_pem),
+                    ),
+                ];
+
+                for (algorithm, kid, key) in keys {
+                    let mut header = Header::new(algorithm);
+                    header.kid = Some(kid.to_owned());
+                    let claims =
+                        json!({ "sub": "auth0|63bfd5cdbd7f1c642dd83768", "exp": 9999999999u64 });
+                    let token = encode(&header, &claims, &key.unwrap()).unwrap();
+                    let mut validation = Validation::new(algorithm);
+                    validation.validate_aud = false;
+
+                    let (token, _) =
+                        valid_jwt::<Claims>(&token, "authority", validation, Some(&jwks))
+                            .await
+                            .unwrap();
+
+                    assert_eq!(
+                        token.claims.sub.as_deref(),
+                        Some("auth0|63bfd5cdbd7f1c642dd83768")
+                    );
+                }
+            }
+
+            #[tokio::test]
+            async fn decode_the_registered_claims() {
+                let jwks = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+                let jwks: JwkSet = serde_json::from_str(&jwks).unwrap();
+                let token = crate::utils::sign_test_jwt(&json!({
+                    "iss": "https://domain.com/",
+                    "sub": "client_id@clients",
+                    "aud": ["https://audience.com", "https://domain.com/userinfo"],
+                    "iat": 1673537441,
+                    "exp": 9999999999u64,
+                    "azp": "client_id",
+                    "scope": "read:users update:users",
+                    "permissions": ["read:users", "update:users"],
+                    "gty": "client-credentials"
+                }));
+                let mut validation = Validation::new(Algorithm::RS256);
+                validation.set_audience(&["https://audience.com"]);
+
+                let (token, _) = valid_jwt::<Claims>(&token, "authority", validation, Some(&jwks))
+                    .await
+                    .unwrap();
+                let claims = token.claims;
+
+                assert_eq!(claims.sub.as_deref(), Some("client_id@clients"));
+                assert_eq!(claims.iss.as_deref(), Some("https://domain.com/"));
+                assert_eq!(
+                    *claims.aud,
+                    vec!["https://audience.com", "https://domain.com/userinfo"]
+                );
+                assert_eq!(claims.exp, Some(9999999999));
+                assert_eq!(claims.iat, Some(1673537441));
+                assert_eq!(claims.azp.as_deref(), Some("client_id"));
+                assert_eq!(claims.scope.as_deref(), Some("read:users update:users"));
+                assert_eq!(claims.permissions, vec!["read:users", "update:users"]);
+                assert_eq!(claims.gty.as_deref(), Some("client-credentials"));
+            }
+
+            #[tokio::test]
+            async fn decode_custom_claims() {
+                #[derive(Deserialize)]
+                struct CustomClaims {
+                    sub: String,
+                    tenant: String,
+                }
+
+                let jwks = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+                let jwks: JwkSet = serde_json::from_str(&jwks).unwrap();
+                let token = crate::utils::sign_test_jwt(&json!({
+                    "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                    "tenant": "acme",
+                    "exp": 9999999999u64
+                }));
+                let mut validation = Validation::new(Algorithm::RS256);
+                validation.validate_aud = false;
+
+                let (token, _) =
+                    valid_jwt::<CustomClaims>(&token, "authority", validation, Some(&jwks))
+                        .await
+                        .unwrap();
+
+                assert_eq!(token.claims.sub, "auth0|63bfd5cdbd7f1c642dd83768");
+                assert_eq!(token.claims.tenant, "acme");
             }
 
             #[tokio::test]
             async fn errored_with_missing_kid() {
                 let jwks_response = std::fs::read_to_string("tests/data/jwks_no_key.json").unwrap();
-                let _m = mock("GET", "/.well-known/jwks.json")
+                let _m = mock("GET", "/no_key/.well-known/jwks.json")
                     .with_status(200)
                     .with_body(jwks_response)
                     .create();
@@ -338,7 +1739,8 @@ mod tests {
                 validation.validate_aud = false;
                 validation.required_spec_claims =
                     HashSet::from_iter([String::from("sub")].into_iter());
-                let res = valid_jwt(&valid_token, &mockito::server_url(), validation, None).await;
+                let authority = format!("{}/no_key", mockito::server_url());
+                let res = valid_jwt::<Claims>(&valid_token, &authority, validation, None).await;
 
                 match res {
                     Err(Error::JwtMissingKid) => (),
@@ -347,7 +1749,28 @@ mod tests {
                 }
             }
 
-            
+            #[tokio::test]
+            async fn errored_with_a_kid_missing_from_the_given_jwks() {
+                let m = mock("GET", "/given_jwks/.well-known/jwks.json")
+                    .expect(0)
+                    .create();
+                let jwks = std::fs::read_to_string("tests/data/jwks_no_key.json").unwrap();
+                let jwks: JwkSet = serde_json::from_str(&jwks).unwrap();
+                let valid_token = std::fs::read_to_string("tests/data/valid_jwt.txt").unwrap();
+                let mut validation = Validation::new(Algorithm::RS256);
+                validation.validate_exp = false;
+                validation.validate_aud = false;
+                let authority = format!("{}/given_jwks", mockito::server_url());
+                let res =
+                    valid_jwt::<Claims>(&valid_token, &authority, validation, Some(&jwks)).await;
+
+                match res {
+                    Err(Error::JwtMissingKid) => (),
+                    _ => panic!("Expected JwtMissingKid variant, got: {res:?}"),
+                }
+                m.assert();
+            }
+
             #[tokio::test]
             async fn errored_with_invalid_jwt() {
                 let _m = jwks_mock();
@@ -355,24 +1778,85 @@ mod tests {
                 let mut validation = Validation::new(Algorithm::RS256);
                 validation.required_spec_claims =
                     HashSet::from_iter([String::from("sub")].into_iter());
-                let res = valid_jwt(
-                    &invalid_token,
-                    &mockito::server_url(),
-                    validation,
-                    None,
-                )
-                .await;
+                let res = valid_jwt::<Claims>(&invalid_token, &authority(), validation, None).await;
 
                 match res {
                     Err(Error::InvalidJwt(err)) => {
                         if *err.kind() != ErrorKind::InvalidSignature {
                             panic!("Expected ErrorKind::InvalidSignature but got {err:?}")
                         }
-                    },
+                    }
                     Err(err) => panic!("Expected JWTError(InvalidSignature) but got {err:?}"),
                     _ => panic!("Expected JWTError but got a valid JWT"),
                 }
             }
         }
+
+        mod valid_jwt_hs256 {
+            use super::*;
+            use jsonwebtoken::errors::ErrorKind;
+            use jsonwebtoken::{encode, EncodingKey, Header};
+
+            fn hs256_token(secret: &str) -> String {
+                let claims =
+                    json!({ "sub": "auth0|63bfd5cdbd7f1c642dd83768", "exp": 9999999999u64 });
+
+                encode(
+                    &Header::new(Algorithm::HS256),
+                    &claims,
+                    &EncodingKey::from_secret(secret.as_bytes()),
+                )
+                .unwrap()
+            }
+
+            #[test]
+            fn validate_tokens_signed_with_the_secret() {
+                let token = valid_jwt_hs256::<Claims>(
+                    &hs256_token("client_secret"),
+                    "client_secret",
+                    Validation::new(Algorithm::HS256),
+                )
+                .unwrap();
+
+                assert_eq!(
+                    token.claims.sub.as_deref(),
+                    Some("auth0|63bfd5cdbd7f1c642dd83768")
+                );
+            }
+
+            #[test]
+            fn errored_with_another_secret() {
+                let res = valid_jwt_hs256::<Claims>(
+                    &hs256_token("another_secret"),
+                    "client_secret",
+                    Validation::new(Algorithm::HS256),
+                );
+
+                match res {
+                    Err(Error::InvalidJwt(err)) => {
+                        assert_eq!(*err.kind(), ErrorKind::InvalidSignature)
+                    }
+                    _ => panic!("Expected InvalidJwt variant, got: {res:?}"),
+                }
+            }
+
+            #[test]
+            fn only_accept_hs256() {
+                let hs512 = encode(
+                    &Header::new(Algorithm::HS512),
+                    &json!({ "sub": "sub", "exp": 9999999999u64 }),
+                    &EncodingKey::from_secret(b"client_secret"),
+                )
+                .unwrap();
+
+                let res = valid_jwt_hs256::<Claims>(
+                    &hs512,
+                    "client_secret",
+                    Validation::new(Algorithm::HS512),
+                );
+
+                assert!(res.is_err());
+            }
+        }
     }
 }
@@ -0,0 +1,796 @@
+//! Types, traits and functions relative to the authorization endpoint.
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::digest::{digest, SHA256};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use crate::authorization::client_assertion::ClientAssertionKey;
+use crate::authorization::state::AuthorizationTransaction;
+use crate::error::Auth0Result;
+use crate::utils::{check_extra_params, URL_REGEX};
+use crate::Auth0Client;
+
+/// The number of seconds a signed request object is valid for.
+const REQUEST_OBJECT_LIFETIME: i64 = 300;
+
+/// The parameters set by the builder itself, which extra parameters can not override.
+const RESERVED_PARAMS: &[&str] = &[
+    "response_type",
+    "client_id",
+    "redirect_uri",
+    "scope",
+    "audience",
+    "state",
+    "nonce",
+    "code_challenge",
+    "code_challenge_method",
+    "connection",
+    "organization",
+    "invitation",
+    "prompt",
+    "max_age",
+    "acr_values",
+    "login_hint",
+    "screen_hint",
+    "ui_locales",
+    "request",
+    "request_uri",
+];
+
+/// How the authorization server prompts the user for reauthentication and consent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prompt {
+    /// Fails instead of displaying any page, for silent authentication.
+    None,
+    /// Forces the user to log in even if they have a session.
+    Login,
+    /// Asks the user for consent even if they already gave it.
+    Consent,
+    /// Asks the user to select one of their accounts.
+    SelectAccount,
+}
+
+impl Display for Prompt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Prompt::None => write!(f, "none"),
+            Prompt::Login => write!(f, "login"),
+            Prompt::Consent => write!(f, "consent"),
+            Prompt::SelectAccount => write!(f, "select_account"),
+        }
+    }
+}
+
+/// The Universal Login screen the user lands on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenHint {
+    Login,
+    Signup,
+}
+
+impl Display for ScreenHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScreenHint::Login => write!(f, "login"),
+            ScreenHint::Signup => write!(f, "signup"),
+        }
+    }
+}
+
+/// A builder for the URLs users are redirected to in order to log in through Auth0.
+///
+/// # Example
+///
+/// ```
+/// # fn authorize_url() -> auth0_client::error::Auth0Result<()> {
+/// let client =
+///     auth0_client::Auth0Client::new("client_id", "client_secret", "https://domain.com", "audience");
+///
+/// let url = client
+///     .authorize_url()
+///     .redirect_uri("https://app.example.com/callback")
+///     .scope("openid profile")
+///     .build()?;
+///
+/// assert_eq!(
+///     url,
+///     "https://domain.com/authorize?response_type=code&client_id=client_id&redirect_uri=https%3A%2F%2Fapp.example.com%2Fcallback&scope=openid%20profile"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuthorizeUrlBuilder {
+    domain: String,
+    endpoint: Option<String>,
+    response_type: String,
+    client_id: Option<String>,
+    redirect_uri: Option<String>,
+    scope: Option<String>,
+    audience: Option<String>,
+    state: Option<String>,
+    nonce: Option<String>,
+    code_challenge: Option<String>,
+    connection: Option<String>,
+    organization: Option<String>,
+    invitation: Option<String>,
+    prompt: Option<Prompt>,
+    max_age: Option<u64>,
+    acr_values: Option<Vec<String>>,
+    login_hint: Option<String>,
+    screen_hint: Option<ScreenHint>,
+    ui_locales: Option<Vec<String>>,
+    extra_params: BTreeMap<String, String>,
+    request_uri: Option<String>,
+    request_object_key: Option<ClientAssertionKey>,
+}
+
+impl AuthorizeUrlBuilder {
+    /// Creates a new builder for the given tenant domain, requesting an authorization code.
+    pub fn new(domain: &str) -> Self {
+        Self {
+            domain: domain.to_owned(),
+            endpoint: None,
+            response_type: "code".to_owned(),
+            client_id: None,
+            redirect_uri: None,
+            scope: None,
+            audience: None,
+            state: None,
+            nonce: None,
+            code_challenge: None,
+            connection: None,
+            organization: None,
+            invitation: None,
+            prompt: None,
+            max_age: None,
+            acr_values: None,
+            login_hint: None,
+            screen_hint: None,
+            ui_locales: None,
+            extra_params: BTreeMap::new(),
+            request_uri: None,
+            request_object_key: None,
+        }
+    }
+
+    /// Sets the response type to request. Defaults to `code`.
+    pub fn response_type(mut self, response_type: &str) -> Self {
+        self.response_type = response_type.to_owned();
+        self
+    }
+
+    /// Sets the client ID of the application the user logs in to.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        self.client_id = Some(client_id.to_owned());
+        self
+    }
+
+    /// Sets the URL the user is redirected to after logging in.
+    pub fn redirect_uri(mut self, redirect_uri: &str) -> Self {
+        self.redirect_uri = Some(redirect_uri.to_owned());
+        self
+    }
+
+    /// Sets the scopes to request.
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_owned());
+        self
+    }
+
+    /// Sets the API the access token is requested for.
+    pub fn audience(mut self, audience: &str) -> Self {
+        self.audience = Some(audience.to_owned());
+        self
+    }
+
+    /// Sets the opaque value sent back to the redirect URI along with the response.
+    pub fn state(mut self, state: &str) -> Self {
+        self.state = Some(state.to_owned());
+        self
+    }
+
+    /// Sets the value the ID token must contain, preventing replays.
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_owned());
+        self
+    }
+
+    /// Protects the authorization code with PKCE (RFC 7636), sending the `S256` challenge of
+    /// the given verifier.
+    ///
+    /// The verifier, e.g. one of [`generate_code_verifier`](crate::authorization::state::generate_code_verifier),
+    /// must then be sent along with the authorization code when exchanging it.
+    pub fn pkce(mut self, code_verifier: &str) -> Self {
+        self.code_challenge =
+            Some(URL_SAFE_NO_PAD.encode(digest(&SHA256, code_verifier.as_bytes())));
+        self
+    }
+
+    /// Sets the state, nonce, redirect URI and PKCE challenge of the given transaction.
+    pub fn transaction(mut self, transaction: &AuthorizationTransaction) -> Self {
+        self.state = Some(transaction.state.clone());
+        self.nonce = Some(transaction.nonce.clone());
+        if let Some(redirect_uri) = &transaction.redirect_uri {
+            self.redirect_uri = Some(redirect_uri.clone());
+        }
+        match &transaction.code_verifier {
+            Some(code_verifier) => self.pkce(code_verifier),
+            None => self,
+        }
+    }
+
+    /// Sets the connection the user logs in with, skipping the Universal Login page.
+    pub fn connection(mut self, connection: &str) -> Self {
+        self.connection = Some(connection.to_owned());
+        self
+    }
+
+    /// Sets the organization (ID or name) the user logs in to.
+    pub fn organization(mut self, organization: &str) -> Self {
+        self.organization = Some(organization.to_owned());
+        self
+    }
+
+    /// Sets the ticket of the organization invitation the user accepts.
+    ///
+    /// It is the `invitation` query parameter of the link sent to the invited user,
+    /// which must be used along with the matching [`AuthorizeUrlBuilder::organization`].
+    pub fn invitation(mut self, invitation: &str) -> Self {
+        self.invitation = Some(invitation.to_owned());
+        self
+    }
+
+    /// Sets how the user is prompted for reauthentication and consent.
+    pub fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+
+    /// Sets the number of seconds since the last login after which the user must log in again.
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets the authentication context class references requested, in order of preference.
+    pub fn acr_values(mut self, acr_values: &[&str]) -> Self {
+        self.acr_values = Some(acr_values.iter().map(|v| v.to_string()).collect());
+        self
+    }
+
+    /// Sets the identifier the login page is prefilled with, e.g. an email address.
+    pub fn login_hint(mut self, login_hint: &str) -> Self {
+        self.login_hint = Some(login_hint.to_owned());
+        self
+    }
+
+    /// Sets the Universal Login screen the user lands on.
+    pub fn screen_hint(mut self, screen_hint: ScreenHint) -> Self {
+        self.screen_hint = Some(screen_hint);
+        self
+    }
+
+    /// Sets the languages the login page is displayed in, in order of preference.
+    pub fn ui_locales(mut self, ui_locales: &[&str]) -> Self {
+        self.ui_locales = Some(ui_locales.iter().map(|l| l.to_string()).collect());
+        self
+    }
+
+    /// Adds parameters which are not supported by the builder, e.g. the `ext-*` parameters read by Actions.
+    ///
+    /// Building the URL fails with [`Error::ReservedParameter`](crate::error::Error::ReservedParameter)
+    /// if one of them is a parameter set by the builder.
+    pub fn extra_params(mut self, extra_params: HashMap<String, String>) -> Self {
+        self.extra_params.extend(extra_params);
+        self
+    }
+
+    /// Sets the `request_uri` returned by a pushed authorization request.
+    ///
+    /// The built URL then only carries the client ID and the request URI, every other
+    /// parameter having already been pushed to Auth0.
+    pub fn request_uri(mut self, request_uri: &str) -> Self {
+        self.request_uri = Some(request_uri.to_owned());
+        self
+    }
+
+    /// Sends the parameters as a request object (RFC 9101) signed with the given key
+    /// instead of plain query parameters.
+    ///
+    /// The matching public key must be registered on the application.
+    pub fn sign_request(mut self, key: ClientAssertionKey) -> Self {
+        self.request_object_key = Some(key);
+        self
+    }
+
+    /// Returns the parameters of the authorization request, in the order they are sent.
+    ///
+    /// When a request object key is set, they are wrapped into a signed `request` parameter.
+    pub(crate) fn params(&self) -> Auth0Result<Vec<(String, String)>> {
+        check_extra_params(
+            self.extra_params.keys().map(String::as_str),
+            RESERVED_PARAMS,
+        )?;
+
+        let params = self
+            .plain_params()
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value))
+            .chain(self.extra_params.clone())
+            .collect::<Vec<_>>();
+
+        let key = match &self.request_object_key {
+            Some(key) => key,
+            None => return Ok(params),
+        };
+
+        let mut claims = params
+            .into_iter()
+            .map(|(name, value)| (name, serde_json::Value::from(value)))
+            .collect::<serde_json::Map<_, _>>();
+        let iat = chrono::Utc::now().timestamp();
+
+        if let Some(client_id) = &self.client_id {
+            claims.insert("iss".to_owned(), client_id.clone().into());
+        }
+        claims.insert(
+            "aud".to_owned(),
+            format!("{}/", self.domain.trim_end_matches('/')).into(),
+        );
+        claims.insert("iat".to_owned(), iat.into());
+        claims.insert("exp".to_owned(), (iat + REQUEST_OBJECT_LIFETIME).into());
+
+        let mut params = vec![];
+
+        if let Some(client_id) = &self.client_id {
+            params.push(("client_id".to_owned(), client_id.clone()));
+        }
+        params.push(("request".to_owned(), key.sign(&claims)?));
+        Ok(params)
+    }
+
+    /// Returns the parameters of the authorization request as plain query parameters.
+    fn plain_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("response_type", self.response_type.clone())];
+
+        if let Some(client_id) = &self.client_id {
+            params.push(("client_id", client_id.clone()));
+        }
+        if let Some(redirect_uri) = &self.redirect_uri {
+            params.push(("redirect_uri", redirect_uri.clone()));
+        }
+        if let Some(scope) = &self.scope {
+            params.push(("scope", scope.clone()));
+        }
+        if let Some(audience) = &self.audience {
+            params.push(("audience", audience.clone()));
+        }
+        if let Some(state) = &self.state {
+            params.push(("state", state.clone()));
+        }
+        if let Some(nonce) = &self.nonce {
+            params.push(("nonce", nonce.clone()));
+        }
+        if let Some(code_challenge) = &self.code_challenge {
+            params.push(("code_challenge", code_challenge.clone()));
+            params.push(("code_challenge_method", "S256".to_owned()));
+        }
+        if let Some(connection) = &self.connection {
+            params.push(("connection", connection.clone()));
+        }
+        if let Some(organization) = &self.organization {
+            params.push(("organization", organization.clone()));
+        }
+        if let Some(invitation) = &self.invitation {
+            params.push(("invitation", invitation.clone()));
+        }
+        if let Some(prompt) = self.prompt {
+            params.push(("prompt", prompt.to_string()));
+        }
+        if let Some(max_age) = self.max_age {
+            params.push(("max_age", max_age.to_string()));
+        }
+        if let Some(acr_values) = &self.acr_values {
+            params.push(("acr_values", acr_values.join(" ")));
+        }
+        if let Some(login_hint) = &self.login_hint {
+            params.push(("login_hint", login_hint.clone()));
+        }
+        if let Some(screen_hint) = self.screen_hint {
+            params.push(("screen_hint", screen_hint.to_string()));
+        }
+        if let Some(ui_locales) = &self.ui_locales {
+            params.push(("ui_locales", ui_locales.join(" ")));
+        }
+        params
+    }
+
+    /// Builds the authorization URL.
+    pub fn build(&self) -> Auth0Result<String> {
+        let params = match &self.request_uri {
+            Some(request_uri) => {
+                let mut params = vec![];
+
+                if let Some(client_id) = &self.client_id {
+                    params.push(("client_id".to_owned(), client_id.clone()));
+                }
+                params.push(("request_uri".to_owned(), request_uri.clone()));
+                params
+            }
+            None => self.params()?,
+        };
+
+        let url = match &self.endpoint {
+            Some(endpoint) => endpoint.clone(),
+            None => URL_REGEX
+                .replace_all(&format!("{}/authorize", self.domain), "$1")
+                .to_string(),
+        };
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{key}={}", urlencoding::encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        Ok(format!("{url}?{query}"))
+    }
+}
+
+impl Auth0Client {
+    /// Returns an [`AuthorizeUrlBuilder`] for the client domain with the client ID already set.
+    ///
+    /// It uses the authorization endpoint of the discovery document if it has been loaded.
+    pub fn authorize_url(&self) -> AuthorizeUrlBuilder {
+        let mut builder = AuthorizeUrlBuilder::new(&self.domain).client_id(&self.client_id);
+
+        builder.endpoint = Some(self.endpoint_url("/authorize"));
+        builder.organization = self.organization.clone();
+        builder
+    }
+}
+
+/// Trait for pushing authorization requests (RFC 9126) to Auth0.
+#[async_trait]
+pub trait PushedAuthorization {
+    /// Pushes the parameters of an authorization request to `/oauth/par`.
+    ///
+    /// The returned `request_uri` is then given to [`AuthorizeUrlBuilder::request_uri`]
+    /// to build the URL the user is redirected to.
+    ///
+    /// # Arguments
+    /// * `request` - The authorization request to push.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn par(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::authorize::PushedAuthorization;
+    /// let request = client
+    ///     .authorize_url()
+    ///     .redirect_uri("https://app.example.com/callback")
+    ///     .scope("openid profile");
+    ///
+    /// let pushed = client.push_authorization_request(&request).await?;
+    /// let url = request.request_uri(&pushed.request_uri).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn push_authorization_request(
+        &mut self,
+        request: &AuthorizeUrlBuilder,
+    ) -> Auth0Result<PushedAuthorizationResponse>;
+}
+
+/// The response we get when we push an authorization request.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PushedAuthorizationResponse {
+    pub request_uri: String,
+    pub expires_in: u64,
+}
+
+#[async_trait]
+impl PushedAuthorization for Auth0Client {
+    async fn push_authorization_request(
+        &mut self,
+        request: &AuthorizeUrlBuilder,
+    ) -> Auth0Result<PushedAuthorizationResponse> {
+        let mut body = request.clone().client_id(&self.client_id).params()?;
+
+        body.extend(
+            self.client_authentication()?
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value)),
+        );
+
+        self.authentication_form_request("/oauth/par", &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod build {
+        use super::*;
+
+        #[test]
+        fn include_the_set_parameters() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com/")
+                .client_id("client_id")
+                .redirect_uri("https://app.example.com/callback")
+                .audience("https://audience.com")
+                .state("state")
+                .connection("github")
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&client_id=client_id&redirect_uri=https%3A%2F%2Fapp.example.com%2Fcallback&audience=https%3A%2F%2Faudience.com&state=state&connection=github"
+            );
+        }
+
+        #[test]
+        fn include_the_transaction() {
+            let transaction = AuthorizationTransaction {
+                state: "state".to_owned(),
+                nonce: "nonce".to_owned(),
+                redirect_uri: Some("https://app.example.com/callback".to_owned()),
+                code_verifier: None,
+            };
+
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .transaction(&transaction)
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&redirect_uri=https%3A%2F%2Fapp.example.com%2Fcallback&state=state&nonce=nonce"
+            );
+
+            let transaction = AuthorizationTransaction {
+                code_verifier: Some("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_owned()),
+                ..transaction
+            };
+
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .transaction(&transaction)
+                .build()
+                .unwrap();
+
+            assert!(url.ends_with(
+                "&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"
+            ));
+        }
+
+        #[test]
+        fn include_the_pkce_challenge() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .state("state")
+                .pkce("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk")
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&state=state&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"
+            );
+        }
+
+        #[test]
+        fn accept_organization_invitations() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .organization("org_W2pqeQg6pL6Ar3sk")
+                .invitation("tf7ZM0CXkI")
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&organization=org_W2pqeQg6pL6Ar3sk&invitation=tf7ZM0CXkI"
+            );
+        }
+
+        #[test]
+        fn include_the_oidc_request_parameters() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .prompt(Prompt::Login)
+                .max_age(3600)
+                .acr_values(&["http://schemas.openid.net/pape/policies/2007/06/multi-factor"])
+                .login_hint("user@example.com")
+                .screen_hint(ScreenHint::Signup)
+                .ui_locales(&["fr-CA", "en"])
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&prompt=login&max_age=3600&acr_values=http%3A%2F%2Fschemas.openid.net%2Fpape%2Fpolicies%2F2007%2F06%2Fmulti-factor&login_hint=user%40example.com&screen_hint=signup&ui_locales=fr-CA%20en"
+            );
+        }
+
+        #[test]
+        fn include_the_extra_parameters() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .extra_params(HashMap::from([
+                    ("ext-theme".to_owned(), "dark".to_owned()),
+                    ("ext-campaign".to_owned(), "spring sale".to_owned()),
+                ]))
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?response_type=code&ext-campaign=spring%20sale&ext-theme=dark"
+            );
+        }
+
+        #[test]
+        fn reject_extra_parameters_overriding_reserved_ones() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .extra_params(HashMap::from([(
+                    "redirect_uri".to_owned(),
+                    "https://evil.example.com/".to_owned(),
+                )]))
+                .build();
+
+            match url {
+                Err(Error::ReservedParameter(name)) => assert_eq!(name, "redirect_uri"),
+                _ => panic!("Expected ReservedParameter variant, got: {url:?}"),
+            }
+        }
+
+        #[test]
+        fn use_the_organization_of_the_client() {
+            let mut client = new_client();
+            client.organization(Some("acme"));
+
+            let url = client.authorize_url().build().unwrap();
+
+            assert!(url.ends_with("&organization=acme"));
+        }
+
+        #[test]
+        fn only_send_the_request_uri_of_pushed_requests() {
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .client_id("client_id")
+                .scope("openid")
+                .request_uri("urn:ietf:params:oauth:request_uri:6esc_11ACC5bwc014ltc14eY22c")
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/authorize?client_id=client_id&request_uri=urn%3Aietf%3Aparams%3Aoauth%3Arequest_uri%3A6esc_11ACC5bwc014ltc14eY22c"
+            );
+        }
+    }
+
+    mod sign_request {
+        use super::*;
+        use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+
+        #[test]
+        fn send_the_parameters_as_a_signed_request_object() {
+            let pem = std::fs::read("tests/data/rsa_private_key.pem").unwrap();
+            let key = ClientAssertionKey::from_rsa_pem(&pem, Some("test_kid")).unwrap();
+
+            let url = AuthorizeUrlBuilder::new("https://domain.com")
+                .client_id("client_id")
+                .redirect_uri("https://app.example.com/callback")
+                .scope("openid")
+                .pkce("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk")
+                .sign_request(key)
+                .build()
+                .unwrap();
+
+            let request = url
+                .strip_prefix("https://domain.com/authorize?client_id=client_id&request=")
+                .unwrap();
+            let public_key = std::fs::read("tests/data/rsa_public_key.pem").unwrap();
+            let mut validation = Validation::new(Algorithm::RS256);
+            validation.set_audience(&["https://domain.com/"]);
+            validation.set_issuer(&["client_id"]);
+            let token = decode::<serde_json::Value>(
+                request,
+                &DecodingKey::from_rsa_pem(&public_key).unwrap(),
+                &validation,
+            )
+            .unwrap();
+
+            assert_eq!(token.claims["response_type"], "code");
+            assert_eq!(
+                token.claims["redirect_uri"],
+                "https://app.example.com/callback"
+            );
+            assert_eq!(token.claims["scope"], "openid");
+            assert_eq!(
+                token.claims["code_challenge"],
+                "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+            );
+            assert_eq!(token.claims["code_challenge_method"], "S256");
+        }
+    }
+
+    mod push_authorization_request {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oauth/par")
+                .match_header("content-type", "application/x-www-form-urlencoded")
+                .match_body(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("response_type".to_owned(), "code".to_owned()),
+                    Matcher::UrlEncoded("client_id".to_owned(), "client_id".to_owned()),
+                    Matcher::UrlEncoded("client_secret".to_owned(), "client_secret".to_owned()),
+                    Matcher::UrlEncoded(
+                        "redirect_uri".to_owned(),
+                        "https://app.example.com/callback".to_owned(),
+                    ),
+                ]))
+                .with_status(201)
+                .with_body(
+                    json!({
+                        "request_uri": "urn:ietf:params:oauth:request_uri:6esc_11ACC5bwc014ltc14eY22c",
+                        "expires_in": 30
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let request = client
+                .authorize_url()
+                .redirect_uri("https://app.example.com/callback");
+            let resp = client.push_authorization_request(&request).await.unwrap();
+
+            assert_eq!(
+                resp.request_uri,
+                "urn:ietf:params:oauth:request_uri:6esc_11ACC5bwc014ltc14eY22c"
+            );
+            assert_eq!(resp.expires_in, 30);
+        }
+
+        #[tokio::test]
+        async fn map_authentication_api_errors() {
+            let _m = mock("POST", "/oauth/par")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "error": "invalid_request",
+                        "error_description": "Missing required parameter: redirect_uri"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .push_authorization_request(&client.authorize_url())
+                .await;
+
+            match resp {
+                Err(Error::Authentication(_)) => (),
+                _ => panic!("Expected Authentication variant, got: {resp:?}"),
+            }
+        }
+    }
+}
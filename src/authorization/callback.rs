@@ -0,0 +1,273 @@
+//! Types, traits and functions relative to the callback of the authorization code flow.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use crate::authorization::state::{AuthorizationTransaction, StateStore};
+use crate::authorization::{AccessTokenResponse, Authenticatable, AuthenticationError};
+use crate::error::{Auth0OAuthError, Auth0Result, Error};
+use crate::{Auth0Client, GrantType};
+
+/// Trait for completing the authorization code flow.
+#[async_trait]
+pub trait AuthorizationCode {
+    /// Exchanges an authorization code for tokens.
+    ///
+    /// # Arguments
+    /// * `code` - The authorization code sent back to the redirect URI.
+    /// * `redirect_uri` - The redirect URI of the authorization request, if one was sent.
+    /// * `code_verifier` - The PKCE verifier of the authorization request, if it sent a challenge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn exchange(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::callback::AuthorizationCode;
+    /// let tokens = client
+    ///     .exchange_authorization_code("code", Some("https://app.example.com/callback"), None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn exchange_authorization_code(
+        &mut self,
+        code: &str,
+        redirect_uri: Option<&str>,
+        code_verifier: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse>;
+
+    /// Handles the redirect of the user back to the application.
+    ///
+    /// It parses the query string, verifies the state against the store, surfaces
+    /// error redirects as [`AuthenticationError`] and exchanges the code for tokens, along
+    /// with the PKCE verifier of the transaction.
+    ///
+    /// # Arguments
+    /// * `query` - The query string of the callback URL, with or without the leading `?`.
+    /// * `store` - The store the transaction was saved to before redirecting the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn callback(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::callback::AuthorizationCode;
+    /// # use auth0_client::authorization::state::InMemoryStateStore;
+    /// # let store = InMemoryStateStore::default();
+    /// let callback = client.handle_callback("?code=code&state=state", &store).await?;
+    ///
+    /// println!("Logged in with nonce {}", callback.transaction.nonce);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn handle_callback(
+        &mut self,
+        query: &str,
+        store: &dyn StateStore,
+    ) -> Auth0Result<CallbackResponse>;
+}
+
+/// The result of a successful callback.
+#[derive(Debug, Clone)]
+pub struct CallbackResponse {
+    pub tokens: AccessTokenResponse,
+    /// The verified transaction, whose nonce the ID token must contain.
+    pub transaction: AuthorizationTransaction,
+}
+
+#[async_trait]
+impl AuthorizationCode for Auth0Client {
+    async fn exchange_authorization_code(
+        &mut self,
+        code: &str,
+        redirect_uri: Option<&str>,
+        code_verifier: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::AuthorizationCode.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("code", code.to_owned());
+            if let Some(redirect_uri) = redirect_uri {
+                body.insert("redirect_uri", redirect_uri.to_owned());
+            }
+            if let Some(code_verifier) = code_verifier {
+                body.insert("code_verifier", code_verifier.to_owned());
+            }
+            body
+        };
+
+        self.authenticate_with_body(body).await
+    }
+
+    async fn handle_callback(
+        &mut self,
+        query: &str,
+        store: &dyn StateStore,
+    ) -> Auth0Result<CallbackResponse> {
+        let mut params = parse_query(query);
+
+        let state = params
+            .remove("state")
+            .ok_or_else(|| Error::InvalidCallback("Missing state".to_owned()))?;
+        let transaction = store.verify(&state).await?;
+
+        if let Some(error) = params.remove("error") {
+            let oauth_error = Auth0OAuthError {
+                error,
+                error_description: params.remove("error_description"),
+                mfa_token: None,
+            };
+
+            return Err(AuthenticationError::from(oauth_error).into());
+        }
+
+        let code = params
+            .remove("code")
+            .ok_or_else(|| Error::InvalidCallback("Missing code".to_owned()))?;
+        let tokens = self
+            .exchange_authorization_code(
+                &code,
+                transaction.redirect_uri.as_deref(),
+                transaction.code_verifier.as_deref(),
+            )
+            .await?;
+
+        Ok(CallbackResponse {
+            tokens,
+            transaction,
+        })
+    }
+}
+
+/// Parses a query string into its decoded parameters.
+fn parse_query(query: &str) -> HashMap<String, String> {
+    let decode = |value: &str| {
+        let value = value.replace('+', " ");
+
+        urlencoding::decode(&value)
+            .map(|v| v.into_owned())
+            .unwrap_or(value)
+    };
+
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (decode(key), decode(value)),
+            None => (decode(pair), String::new()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::state::InMemoryStateStore;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    async fn store_with_transaction() -> InMemoryStateStore {
+        let store = InMemoryStateStore::default();
+
+        store
+            .save(AuthorizationTransaction {
+                state: "state".to_owned(),
+                nonce: "nonce".to_owned(),
+                redirect_uri: Some("https://app.example.com/callback".to_owned()),
+                code_verifier: Some("code_verifier".to_owned()),
+            })
+            .await
+            .unwrap();
+        store
+    }
+
+    mod handle_callback {
+        use super::*;
+
+        #[tokio::test]
+        async fn exchange_the_code() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "authorization_code",
+                    "code": "code",
+                    "redirect_uri": "https://app.example.com/callback",
+                    "code_verifier": "code_verifier"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "id_token": "id_token" }).to_string(),
+                )
+                .create();
+            let mut client = new_client();
+            let store = store_with_transaction().await;
+
+            let resp = client
+                .handle_callback("?code=code&state=state", &store)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.tokens.id_token.as_deref(), Some("id_token"));
+            assert_eq!(resp.transaction.nonce, "nonce");
+        }
+
+        #[tokio::test]
+        async fn surface_error_redirects() {
+            let mut client = new_client();
+            let store = store_with_transaction().await;
+
+            let resp = client
+                .handle_callback(
+                    "error=access_denied&error_description=User%20did%20not%20authorize+the+request&state=state",
+                    &store,
+                )
+                .await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::AccessDenied(msg))) => {
+                    assert_eq!(msg, "User did not authorize the request")
+                }
+                _ => panic!("Expected AccessDenied variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn reject_unknown_states() {
+            let mut client = new_client();
+            let store = store_with_transaction().await;
+
+            let resp = client
+                .handle_callback("code=code&state=forged_state", &store)
+                .await;
+
+            match resp {
+                Err(Error::InvalidState(_)) => (),
+                _ => panic!("Expected InvalidState variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn reject_callbacks_without_state() {
+            let mut client = new_client();
+            let store = store_with_transaction().await;
+
+            let resp = client.handle_callback("code=code", &store).await;
+
+            match resp {
+                Err(Error::InvalidCallback(_)) => (),
+                _ => panic!("Expected InvalidCallback variant, got: {resp:?}"),
+            }
+        }
+    }
+}
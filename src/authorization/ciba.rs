@@ -0,0 +1,280 @@
+//! Types, traits and functions relative to the client-initiated backchannel authentication flow (CIBA).
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::authorization::AccessTokenResponse;
+use crate::error::Auth0Result;
+use crate::{Auth0Client, GrantType};
+
+/// Trait for authenticating users through the client-initiated backchannel authentication flow.
+#[async_trait]
+pub trait BackchannelAuthentication {
+    /// Starts a backchannel authentication request for a user.
+    ///
+    /// The user is asked to approve the request on their enrolled device, where the
+    /// binding message is displayed so they can match it with the one you show them.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the user to authenticate and the request details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn start_ciba(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::ciba::{BackchannelAuthentication, BackchannelAuthenticationPayload};
+    /// let payload = BackchannelAuthenticationPayload::new(
+    ///     "auth0|63bfd5cdbd7f1c642dd83768",
+    ///     "openid",
+    ///     "Call 123-456",
+    /// );
+    ///
+    /// let request = client.start_backchannel_authentication(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn start_backchannel_authentication(
+        &mut self,
+        payload: &BackchannelAuthenticationPayload,
+    ) -> Auth0Result<BackchannelAuthenticationResponse>;
+
+    /// Polls the token endpoint until the user approves or denies the backchannel authentication request.
+    ///
+    /// `authorization_pending` responses are retried every `interval` seconds and
+    /// `slow_down` responses increase the interval as required by the specification.
+    /// It fails with [`AuthenticationError::ExpiredToken`](crate::authorization::AuthenticationError::ExpiredToken)
+    /// once the request expired.
+    ///
+    /// # Arguments
+    /// * `request` - The response returned by [`BackchannelAuthentication::start_backchannel_authentication`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn poll_ciba(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::ciba::{BackchannelAuthentication, BackchannelAuthenticationPayload};
+    /// # let payload = BackchannelAuthenticationPayload::new("auth0|63bfd5cdbd7f1c642dd83768", "openid", "Call 123-456");
+    /// let request = client.start_backchannel_authentication(&payload).await?;
+    /// let tokens = client.poll_backchannel_token(&request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn poll_backchannel_token(
+        &mut self,
+        request: &BackchannelAuthenticationResponse,
+    ) -> Auth0Result<AccessTokenResponse>;
+}
+
+/// A struct containing the payload for starting a backchannel authentication request.
+#[derive(Debug, Clone)]
+pub struct BackchannelAuthenticationPayload {
+    /// The ID of the user to authenticate.
+    pub user_id: String,
+    pub scope: String,
+    /// The message displayed to the user on their device, e.g. a reference of the call.
+    pub binding_message: String,
+    pub audience: Option<String>,
+    /// The number of seconds the request is valid for.
+    pub requested_expiry: Option<u64>,
+}
+
+/// The response we get when we start a backchannel authentication request.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BackchannelAuthenticationResponse {
+    pub auth_req_id: String,
+    pub expires_in: u64,
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+/// The polling interval to use when Auth0 does not specify one.
+fn default_interval() -> u64 {
+    5
+}
+
+#[async_trait]
+impl BackchannelAuthentication for Auth0Client {
+    async fn start_backchannel_authentication(
+        &mut self,
+        payload: &BackchannelAuthenticationPayload,
+    ) -> Auth0Result<BackchannelAuthenticationResponse> {
+        let login_hint = json!({
+            "format": "iss_sub",
+            "iss": format!("{}/", self.domain.trim_end_matches('/')),
+            "sub": payload.user_id,
+        });
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("login_hint", login_hint.to_string());
+            body.insert("scope", payload.scope.clone());
+            body.insert("binding_message", payload.binding_message.clone());
+            if let Some(audience) = &payload.audience {
+                body.insert("audience", audience.clone());
+            }
+            if let Some(requested_expiry) = payload.requested_expiry {
+                body.insert("requested_expiry", requested_expiry.to_string());
+            }
+            body
+        };
+
+        self.authentication_form_request("/bc-authorize", &body)
+            .await
+    }
+
+    async fn poll_backchannel_token(
+        &mut self,
+        request: &BackchannelAuthenticationResponse,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::Ciba.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("auth_req_id", request.auth_req_id.clone());
+            body
+        };
+
+        self.poll_token(body, request.interval, Some(request.expires_in))
+            .await
+    }
+}
+
+impl BackchannelAuthenticationPayload {
+    /// Returns a payload for starting a backchannel authentication request with only the mandatory fields set.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to authenticate.
+    /// * `scope` - The scopes to request, which must include `openid`.
+    /// * `binding_message` - The message displayed to the user on their device.
+    pub fn new(user_id: &str, scope: &str, binding_message: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            scope: scope.to_owned(),
+            binding_message: binding_message.to_owned(),
+            audience: None,
+            requested_expiry: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::AuthenticationError;
+    use crate::error::Error;
+    use mockito::{mock, Matcher};
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    fn request() -> BackchannelAuthenticationResponse {
+        BackchannelAuthenticationResponse {
+            auth_req_id: "auth_req_id".to_owned(),
+            expires_in: 300,
+            interval: 0,
+        }
+    }
+
+    mod start_backchannel_authentication {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let login_hint = json!({
+                "format": "iss_sub",
+                "iss": format!("{}/", mockito::server_url()),
+                "sub": "auth0|63bfd5cdbd7f1c642dd83768"
+            });
+            let _m = mock("POST", "/bc-authorize")
+                .match_body(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("client_id".to_owned(), "client_id".to_owned()),
+                    Matcher::UrlEncoded("login_hint".to_owned(), login_hint.to_string()),
+                    Matcher::UrlEncoded("binding_message".to_owned(), "Call 123-456".to_owned()),
+                    Matcher::UrlEncoded("requested_expiry".to_owned(), "300".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "auth_req_id": "eyJh...",
+                        "expires_in": 300,
+                        "interval": 5
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload = BackchannelAuthenticationPayload::new(
+                "auth0|63bfd5cdbd7f1c642dd83768",
+                "openid",
+                "Call 123-456",
+            );
+            payload.requested_expiry = Some(300);
+            let resp = client
+                .start_backchannel_authentication(&payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.auth_req_id, "eyJh...");
+            assert_eq!(resp.interval, 5);
+        }
+    }
+
+    mod poll_backchannel_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn retry_while_authorization_is_pending() {
+            let _pending = mock("POST", "/oauth/token")
+                .with_status(400)
+                .with_body(json!({ "error": "authorization_pending" }).to_string())
+                .expect(1)
+                .create();
+            let _success = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "urn:openid:params:grant-type:ciba",
+                    "auth_req_id": "auth_req_id"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.poll_backchannel_token(&request()).await.unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+
+        #[tokio::test]
+        async fn stop_when_access_is_denied() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(403)
+                .with_body(
+                    json!({ "error": "access_denied", "error_description": "The user rejected the request" })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.poll_backchannel_token(&request()).await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::AccessDenied(_))) => (),
+                _ => panic!("Expected AccessDenied variant"),
+            }
+        }
+    }
+}
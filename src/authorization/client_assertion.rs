@@ -0,0 +1,233 @@
+//! Types, traits and functions relative to the `private_key_jwt` client authentication (RFC 7523).
+
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::error::Auth0Result;
+use crate::utils::random_string;
+use crate::Auth0Client;
+
+/// The assertion type sent along with a signed client assertion.
+pub const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// The number of seconds a client assertion is valid for.
+const CLIENT_ASSERTION_LIFETIME: i64 = 60;
+
+/// A private key used to sign client assertions instead of sending the client secret.
+///
+/// # Example
+///
+/// ```
+/// # fn set_key(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::client_assertion::ClientAssertionKey;
+/// let pem = std::fs::read("tests/data/rsa_private_key.pem").unwrap();
+///
+/// client.set_client_assertion_key(ClientAssertionKey::from_rsa_pem(&pem, Some("kid"))?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ClientAssertionKey {
+    key: EncodingKey,
+    algorithm: Algorithm,
+    kid: Option<String>,
+}
+
+impl std::fmt::Debug for ClientAssertionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientAssertionKey")
+            .field("algorithm", &self.algorithm)
+            .field("kid", &self.kid)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The claims of a client assertion.
+#[derive(Serialize)]
+struct ClientAssertionClaims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: String,
+    iat: i64,
+    exp: i64,
+    jti: String,
+}
+
+impl ClientAssertionKey {
+    /// Returns a key signing client assertions with `RS256` from a PEM encoded RSA private key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pem` - The PEM encoded private key.
+    /// * `kid` - The id of the matching public key registered on the application, if any.
+    pub fn from_rsa_pem(pem: &[u8], kid: Option<&str>) -> Auth0Result<Self> {
+        Ok(Self {
+            key: EncodingKey::from_rsa_pem(pem)?,
+            algorithm: Algorithm::RS256,
+            kid: kid.map(str::to_owned),
+        })
+    }
+
+    /// Returns a key signing client assertions with `ES256` from a PEM encoded (PKCS#8)
+    /// EC P-256 private key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pem` - The PEM encoded private key.
+    /// * `kid` - The id of the matching public key registered on the application, if any.
+    pub fn from_ec_pem(pem: &[u8], kid: Option<&str>) -> Auth0Result<Self> {
+        Ok(Self {
+            key: EncodingKey::from_ec_pem(pem)?,
+            algorithm: Algorithm::ES256,
+            kid: kid.map(str::to_owned),
+        })
+    }
+
+    /// Signs the given claims with the key.
+    pub(crate) fn sign<C: Serialize>(&self, claims: &C) -> Auth0Result<String> {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.kid.clone();
+
+        Ok(encode(&header, claims, &self.key)?)
+    }
+}
+
+impl Auth0Client {
+    /// Sets the private key used to authenticate the client.
+    ///
+    /// Once set, a signed `client_assertion` is sent to the authentication API
+    /// instead of the client secret.
+    pub fn set_client_assertion_key(&mut self, key: ClientAssertionKey) {
+        self.client_assertion_key = Some(key);
+    }
+
+    /// Returns the parameters authenticating the client against the authentication API.
+    ///
+    /// It is either the client secret, a freshly signed client assertion or nothing at all
+    /// when the client authenticates with its TLS identity.
+    pub(crate) fn client_authentication(&self) -> Auth0Result<HashMap<&'static str, String>> {
+        let mut params = HashMap::new();
+
+        match &self.client_assertion_key {
+            Some(key) => {
+                params.insert("client_assertion", self.client_assertion(key)?);
+                params.insert("client_assertion_type", CLIENT_ASSERTION_TYPE.to_owned());
+            }
+            None if self.tls_client_auth => (),
+            None => {
+                params.insert("client_secret", self.client_secret.clone());
+            }
+        }
+        Ok(params)
+    }
+
+    /// Signs a single use client assertion for the tenant with the given key.
+    fn client_assertion(&self, key: &ClientAssertionKey) -> Auth0Result<String> {
+        let iat = chrono::Utc::now().timestamp();
+        let claims = ClientAssertionClaims {
+            iss: &self.client_id,
+            sub: &self.client_id,
+            aud: format!("{}/", self.domain.trim_end_matches('/')),
+            iat,
+            exp: iat + CLIENT_ASSERTION_LIFETIME,
+            jti: random_string(16)?,
+        };
+
+        key.sign(&claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::Authenticatable;
+    use jsonwebtoken::{decode, DecodingKey, Validation};
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        let mut client = Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        );
+        let pem = std::fs::read("tests/data/rsa_private_key.pem").unwrap();
+
+        client.set_client_assertion_key(
+            ClientAssertionKey::from_rsa_pem(&pem, Some("test_kid")).unwrap(),
+        );
+        client
+    }
+
+    mod client_authentication {
+        use super::*;
+
+        #[test]
+        fn send_the_client_secret_without_key() {
+            let client = Auth0Client::new(
+                "client_id",
+                "client_secret",
+                "https://domain.com",
+                "https://audience.com",
+            );
+
+            let params = client.client_authentication().unwrap();
+
+            assert_eq!(params["client_secret"], "client_secret");
+            assert!(!params.contains_key("client_assertion"));
+        }
+
+        #[test]
+        fn sign_a_client_assertion_with_key() {
+            let client = new_client();
+
+            let params = client.client_authentication().unwrap();
+
+            assert!(!params.contains_key("client_secret"));
+            assert_eq!(params["client_assertion_type"], CLIENT_ASSERTION_TYPE);
+
+            let header = jsonwebtoken::decode_header(&params["client_assertion"]).unwrap();
+            assert_eq!(header.kid.as_deref(), Some("test_kid"));
+
+            let public_key = std::fs::read("tests/data/rsa_public_key.pem").unwrap();
+            let mut validation = Validation::new(Algorithm::RS256);
+            validation.set_audience(&[format!("{}/", mockito::server_url())]);
+            validation.set_issuer(&["client_id"]);
+            let token = decode::<serde_json::Value>(
+                &params["client_assertion"],
+                &DecodingKey::from_rsa_pem(&public_key).unwrap(),
+                &validation,
+            )
+            .unwrap();
+
+            assert_eq!(token.claims["sub"], "client_id");
+            assert!(token.claims["jti"].is_string());
+        }
+    }
+
+    mod authenticate {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_a_client_assertion() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::AllOf(vec![
+                    Matcher::PartialJson(json!({
+                        "client_id": "client_id",
+                        "client_assertion_type": CLIENT_ASSERTION_TYPE
+                    })),
+                    Matcher::Regex("\"client_assertion\":\"ey".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.authenticate().await.unwrap();
+
+            assert_eq!(resp, "access_token");
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Types, traits and functions relative to database connections.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Auth0Result, Error};
+use crate::Auth0Client;
+
+/// Trait for managing users of a database connection through the authentication API.
+#[async_trait]
+pub trait DatabaseConnection {
+    /// Creates a new user in a database connection.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to sign the user up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn signup(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::database::{DatabaseConnection, SignupPayload};
+    /// let mut payload = SignupPayload::new(
+    ///     "Username-Password-Authentication",
+    ///     "test@example.com",
+    ///     "password123456789!",
+    /// );
+    /// payload.given_name = Some("John".to_owned());
+    ///
+    /// let user = client.signup(&payload).await?;
+    /// println!("Created user {}", user.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn signup(&mut self, payload: &SignupPayload) -> Auth0Result<SignupResponse>;
+
+    /// Sends a password reset email to a user of a database connection.
+    ///
+    /// # Arguments
+    /// * `email` - The email of the user.
+    /// * `connection` - The database connection of the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn change_password(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::database::DatabaseConnection;
+    /// client
+    ///     .request_password_change("test@example.com", "Username-Password-Authentication")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn request_password_change(&mut self, email: &str, connection: &str) -> Auth0Result<()>;
+}
+
+/// A struct containing the payload for signing a user up.
+#[derive(Serialize)]
+pub struct SignupPayload {
+    pub connection: String,
+    pub email: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<serde_json::Value>,
+}
+
+/// The response we get when we sign a user up.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SignupResponse {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub email: String,
+    pub email_verified: bool,
+}
+
+/// The body sent to the signup endpoint.
+#[derive(Serialize)]
+struct SignupBody<'a> {
+    client_id: &'a str,
+    #[serde(flatten)]
+    payload: &'a SignupPayload,
+}
+
+#[async_trait]
+impl DatabaseConnection for Auth0Client {
+    async fn signup(&mut self, payload: &SignupPayload) -> Auth0Result<SignupResponse> {
+        let body = SignupBody {
+            client_id: &self.client_id,
+            payload,
+        };
+
+        self.authentication_request(Method::POST, "/dbconnections/signup", None, Some(body))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn request_password_change(&mut self, email: &str, connection: &str) -> Auth0Result<()> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.insert("email", email.to_owned());
+            body.insert("connection", connection.to_owned());
+            body
+        };
+
+        // The response is a plain text message meant to be displayed to the user.
+        self.send_authentication_request(
+            Method::POST,
+            "/dbconnections/change_password",
+            None,
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl SignupPayload {
+    /// Returns a payload for signing a user up with only the mandatory fields set.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - The database connection to create the user in.
+    /// * `email` - The email of the user.
+    /// * `password` - The password of the user.
+    pub fn new(connection: &str, email: &str, password: &str) -> Self {
+        Self {
+            connection: connection.to_owned(),
+            email: email.to_owned(),
+            password: password.to_owned(),
+            username: None,
+            given_name: None,
+            family_name: None,
+            name: None,
+            nickname: None,
+            picture: None,
+            user_metadata: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::AuthenticationError;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod signup {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/dbconnections/signup")
+                .match_body(Matcher::Json(json!({
+                    "client_id": "client_id",
+                    "connection": "Username-Password-Authentication",
+                    "email": "test@example.com",
+                    "password": "password123456789!",
+                    "given_name": "John",
+                    "user_metadata": { "plan": "free" }
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "_id": "63bfd5cdbd7f1c642dd83768",
+                        "email_verified": false,
+                        "email": "test@example.com"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload = SignupPayload::new(
+                "Username-Password-Authentication",
+                "test@example.com",
+                "password123456789!",
+            );
+            payload.given_name = Some("John".to_owned());
+            payload.user_metadata = Some(json!({ "plan": "free" }));
+
+            let resp = client.signup(&payload).await.unwrap();
+
+            assert_eq!(resp.id, "63bfd5cdbd7f1c642dd83768");
+            assert!(!resp.email_verified);
+        }
+
+        #[tokio::test]
+        async fn map_authentication_api_errors() {
+            let _m = mock("POST", "/dbconnections/signup")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "name": "BadRequestError",
+                        "code": "invalid_signup",
+                        "description": "Invalid sign up",
+                        "statusCode": 400
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = SignupPayload::new(
+                "Username-Password-Authentication",
+                "test@example.com",
+                "password123456789!",
+            );
+
+            match client.signup(&payload).await {
+                Err(Error::Authentication(AuthenticationError::Unknown(msg))) => {
+                    assert_eq!(msg, "Invalid sign up")
+                }
+                _ => panic!("Expected Unknown variant"),
+            }
+        }
+
+        #[tokio::test]
+        async fn keep_password_policy_details() {
+            let _m = mock("POST", "/dbconnections/signup")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "name": "PasswordStrengthError",
+                        "code": "invalid_password",
+                        "description": { "rules": [{ "code": "lengthAtLeast", "verified": false }] },
+                        "statusCode": 400
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload =
+                SignupPayload::new("Username-Password-Authentication", "test@example.com", "1");
+
+            match client.signup(&payload).await {
+                Err(Error::Authentication(AuthenticationError::Unknown(msg))) => {
+                    assert!(msg.contains("lengthAtLeast"))
+                }
+                _ => panic!("Expected Unknown variant"),
+            }
+        }
+    }
+
+    mod request_password_change {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/dbconnections/change_password")
+                .match_body(Matcher::Json(json!({
+                    "client_id": "client_id",
+                    "email": "test@example.com",
+                    "connection": "Username-Password-Authentication"
+                })))
+                .with_status(200)
+                .with_body("We've just sent you an email to reset your password.")
+                .create();
+            let mut client = new_client();
+
+            client
+                .request_password_change("test@example.com", "Username-Password-Authentication")
+                .await
+                .unwrap();
+        }
+    }
+}
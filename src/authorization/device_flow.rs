@@ -0,0 +1,233 @@
+//! Types, traits and functions relative to the device authorization flow.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::authorization::AccessTokenResponse;
+use crate::error::{Auth0Result, Error};
+use crate::{Auth0Client, GrantType};
+
+/// Trait for authenticating through the device authorization flow.
+#[async_trait]
+pub trait DeviceAuthorization {
+    /// Starts the device authorization flow.
+    ///
+    /// The returned `user_code` and `verification_uri` must be displayed to the user
+    /// so they can approve the request on another device.
+    ///
+    /// # Arguments
+    /// * `scope` - The scopes to request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn start_device_flow(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::device_flow::DeviceAuthorization;
+    /// let device_code = client.start_device_flow("openid profile offline_access").await?;
+    ///
+    /// println!("Go to {} and enter {}", device_code.verification_uri, device_code.user_code);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn start_device_flow(&mut self, scope: &str) -> Auth0Result<DeviceCodeResponse>;
+
+    /// Polls the token endpoint until the user approves or denies the device authorization request.
+    ///
+    /// `authorization_pending` responses are retried every `interval` seconds and
+    /// `slow_down` responses increase the interval as required by the specification.
+    /// It fails with [`AuthenticationError::ExpiredToken`](crate::authorization::AuthenticationError::ExpiredToken)
+    /// once the device code expired.
+    ///
+    /// # Arguments
+    /// * `device_code` - The response returned by [`DeviceAuthorization::start_device_flow`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn poll_device_token(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::device_flow::DeviceAuthorization;
+    /// let device_code = client.start_device_flow("openid profile").await?;
+    /// let tokens = client.poll_device_token(&device_code).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn poll_device_token(
+        &mut self,
+        device_code: &DeviceCodeResponse,
+    ) -> Auth0Result<AccessTokenResponse>;
+}
+
+/// The response we get when we start the device authorization flow.
+#[derive(Debug, Deserialize, Clone)]
+pub struct DeviceCodeResponse {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+/// The polling interval to use when Auth0 does not specify one.
+fn default_interval() -> u64 {
+    5
+}
+
+#[async_trait]
+impl DeviceAuthorization for Auth0Client {
+    async fn start_device_flow(&mut self, scope: &str) -> Auth0Result<DeviceCodeResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.insert("scope", scope.to_owned());
+            body.insert("audience", self.audience.clone());
+            body
+        };
+
+        self.authentication_request(Method::POST, "/oauth/device/code", None, Some(body))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn poll_device_token(
+        &mut self,
+        device_code: &DeviceCodeResponse,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::DeviceCode.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.insert("device_code", device_code.device_code.clone());
+            body
+        };
+
+        self.poll_token(body, device_code.interval, Some(device_code.expires_in))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::AuthenticationError;
+    use mockito::{mock, Matcher, Mock};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    fn device_code() -> DeviceCodeResponse {
+        DeviceCodeResponse {
+            device_code: "device_code".to_owned(),
+            user_code: "ABCD-EFGH".to_owned(),
+            verification_uri: "https://domain.com/activate".to_owned(),
+            verification_uri_complete: None,
+            expires_in: 900,
+            interval: 0,
+        }
+    }
+
+    fn token_error_mock(error: &str) -> Mock {
+        mock("POST", "/oauth/token")
+            .with_status(403)
+            .with_body(json!({ "error": error, "error_description": error }).to_string())
+            .expect(1)
+            .create()
+    }
+
+    mod start_device_flow {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oauth/device/code")
+                .match_body(Matcher::PartialJson(json!({
+                    "client_id": "client_id",
+                    "scope": "openid profile"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "device_code": "Ag_EE...ko1p",
+                        "user_code": "QTZL-MCBW",
+                        "verification_uri": "https://accounts.acmetest.org/activate",
+                        "verification_uri_complete": "https://accounts.acmetest.org/activate?user_code=QTZL-MCBW",
+                        "expires_in": 900,
+                        "interval": 5
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.start_device_flow("openid profile").await.unwrap();
+
+            assert_eq!(resp.user_code, "QTZL-MCBW");
+            assert_eq!(resp.interval, 5);
+        }
+    }
+
+    mod poll_device_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn retry_while_authorization_is_pending() {
+            let _pending = token_error_mock("authorization_pending");
+            let _success = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "urn:ietf:params:oauth:grant-type:device_code",
+                    "device_code": "device_code"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.poll_device_token(&device_code()).await.unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+
+        #[tokio::test]
+        async fn stop_when_access_is_denied() {
+            let _m = token_error_mock("access_denied");
+            let mut client = new_client();
+
+            let resp = client.poll_device_token(&device_code()).await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::AccessDenied(_))) => (),
+                _ => panic!("Expected AccessDenied variant"),
+            }
+        }
+
+        #[tokio::test]
+        async fn stop_once_the_device_code_expired() {
+            let m = mock("POST", "/oauth/token").expect(0).create();
+            let mut client = new_client();
+
+            let device_code = DeviceCodeResponse {
+                expires_in: 0,
+                ..device_code()
+            };
+            let resp = client.poll_device_token(&device_code).await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::ExpiredToken)) => (),
+                _ => panic!("Expected ExpiredToken variant, got: {resp:?}"),
+            }
+            m.assert();
+        }
+    }
+}
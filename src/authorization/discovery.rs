@@ -0,0 +1,334 @@
+//! Types and functions relative to the OIDC discovery document.
+
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::authorization::jwks::{max_age, JwksSource};
+use crate::authorization::validation::authority;
+use crate::error::{Auth0Result, Error};
+use crate::utils::URL_REGEX;
+use crate::Auth0Client;
+
+/// The default number of seconds a discovery document is cached for when the response has no `max-age`.
+const DEFAULT_OPENID_CONFIGURATION_TTL: u64 = 600;
+
+lazy_static! {
+    /// The discovery documents fetched for each domain, along with when they expire.
+    static ref OPENID_CONFIGURATIONS: Mutex<HashMap<String, (OpenIdConfiguration, Instant)>> =
+        Mutex::new(HashMap::new());
+}
+
+/// The OIDC discovery document of a tenant.
+#[derive(Debug, Deserialize, Clone)]
+pub struct OpenIdConfiguration {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+    pub userinfo_endpoint: Option<String>,
+    pub revocation_endpoint: Option<String>,
+    pub device_authorization_endpoint: Option<String>,
+    pub end_session_endpoint: Option<String>,
+    pub registration_endpoint: Option<String>,
+    pub pushed_authorization_request_endpoint: Option<String>,
+    pub backchannel_authentication_endpoint: Option<String>,
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    /// Every other field of the document.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// Downloads the OIDC discovery document of the given tenant.
+///
+/// Documents are cached per domain for the `max-age` of their response, or 10 minutes.
+///
+/// # Arguments
+///
+/// * `domain` - The domain of the tenant, e.g. `https://tenant.eu.auth0.com`.
+///
+/// # Example
+/// ```
+/// # async fn discover() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::discovery::fetch_openid_configuration;
+/// let configuration = fetch_openid_configuration("https://tenant.eu.auth0.com").await?;
+///
+/// println!("Tokens are issued at {}", configuration.token_endpoint);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn fetch_openid_configuration(domain: &str) -> Auth0Result<OpenIdConfiguration> {
+    fetch_cached_openid_configuration(&reqwest::Client::new(), domain).await
+}
+
+/// Returns the cached discovery document of the domain, downloading it with `http_client`
+/// if it is not cached or has expired.
+async fn fetch_cached_openid_configuration(
+    http_client: &reqwest::Client,
+    domain: &str,
+) -> Auth0Result<OpenIdConfiguration> {
+    let key = domain.trim_end_matches('/');
+    let cached = lock_openid_configurations()?
+        .get(key)
+        .filter(|(_, expires_at)| *expires_at > Instant::now())
+        .map(|(configuration, _)| configuration.clone());
+
+    if let Some(configuration) = cached {
+        return Ok(configuration);
+    }
+
+    let url = URL_REGEX
+        .replace_all(&format!("{domain}/.well-known/openid-configuration"), "$1")
+        .to_string();
+
+    tracing::debug!("Fetching OIDC discovery document at {url}...");
+
+    let response = http_client.get(url).send().await?;
+
+    if !response.status().is_success() {
+        return Err(Error::InvalidResponseBody);
+    }
+    let ttl = max_age(response.headers())
+        .unwrap_or(Duration::from_secs(DEFAULT_OPENID_CONFIGURATION_TTL));
+    let configuration = response.json::<OpenIdConfiguration>().await?;
+
+    lock_openid_configurations()?.insert(
+        key.to_owned(),
+        (configuration.clone(), Instant::now() + ttl),
+    );
+    Ok(configuration)
+}
+
+fn lock_openid_configurations(
+) -> Auth0Result<std::sync::MutexGuard<'static, HashMap<String, (OpenIdConfiguration, Instant)>>> {
+    OPENID_CONFIGURATIONS
+        .lock()
+        .map_err(|_| Error::Unknown("OIDC discovery cache lock poisoned".to_owned()))
+}
+
+impl Auth0Client {
+    /// Downloads the OIDC discovery document of the client domain and caches it on the client.
+    ///
+    /// The document is downloaded with the HTTP client of the client, e.g. presenting its
+    /// TLS identity, and shares the cache of [`fetch_openid_configuration`].
+    ///
+    /// Once loaded, the endpoints of the document are used instead of the default Auth0 paths.
+    pub async fn load_openid_configuration(&mut self) -> Auth0Result<&OpenIdConfiguration> {
+        let configuration =
+            fetch_cached_openid_configuration(&self.http_client, &self.domain).await?;
+
+        Ok(self.openid_configuration.insert(configuration))
+    }
+
+    /// Returns the cached OIDC discovery document, if it has been loaded.
+    pub fn openid_configuration(&self) -> Option<&OpenIdConfiguration> {
+        self.openid_configuration.as_ref()
+    }
+
+    /// Returns the URL of an authentication API endpoint.
+    ///
+    /// The URL of the discovery document is used when it is loaded and knows the endpoint.
+    pub(crate) fn endpoint_url(&self, path: &str) -> String {
+        let discovered = self.openid_configuration.as_ref().and_then(|c| match path {
+            "/authorize" => Some(&c.authorization_endpoint),
+            "/oauth/token" => Some(&c.token_endpoint),
+            "/oauth/revoke" => c.revocation_endpoint.as_ref(),
+            "/oauth/device/code" => c.device_authorization_endpoint.as_ref(),
+            "/oauth/par" => c.pushed_authorization_request_endpoint.as_ref(),
+            "/bc-authorize" => c.backchannel_authentication_endpoint.as_ref(),
+            "/oidc/register" => c.registration_endpoint.as_ref(),
+            "/userinfo" => c.userinfo_endpoint.as_ref(),
+            _ => None,
+        });
+
+        match discovered {
+            Some(url) => url.clone(),
+            None => URL_REGEX
+                .replace_all(&format!("{}/{path}", self.domain), "$1")
+                .to_string(),
+        }
+    }
+
+    /// Returns where the jwks of the tenant is fetched from with the HTTP client of the client:
+    /// the `jwks_uri` of the discovery document once it is loaded, `/.well-known/jwks.json` otherwise.
+    pub(crate) fn jwks_source(&self) -> JwksSource<'_> {
+        let authority = authority(&self.domain);
+
+        match &self.openid_configuration {
+            Some(configuration) => {
+                JwksSource::new(&authority, &configuration.jwks_uri, &self.http_client)
+            }
+            None => JwksSource::new(
+                &authority,
+                &format!("{authority}/.well-known/jwks.json"),
+                &self.http_client,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::Authenticatable;
+    use mockito::{mock, Mock};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    fn discovery_mock() -> Mock {
+        let domain = mockito::server_url();
+
+        mock("GET", "/.well-known/openid-configuration")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "issuer": format!("{domain}/"),
+                    "authorization_endpoint": format!("{domain}/custom/authorize"),
+                    "token_endpoint": format!("{domain}/custom/token"),
+                    "jwks_uri": format!("{domain}/custom/jwks.json"),
+                    "revocation_endpoint": format!("{domain}/custom/revoke"),
+                    "scopes_supported": ["openid", "profile"],
+                    "request_uri_parameter_supported": false
+                })
+                .to_string(),
+            )
+            .create()
+    }
+
+    mod fetch_openid_configuration {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = discovery_mock();
+
+            let resp = fetch_openid_configuration(&mockito::server_url())
+                .await
+                .unwrap();
+
+            assert_eq!(resp.issuer, format!("{}/", mockito::server_url()));
+            assert_eq!(resp.scopes_supported, vec!["openid", "profile"]);
+            assert_eq!(resp.other["request_uri_parameter_supported"], json!(false));
+        }
+
+        #[tokio::test]
+        async fn cache_the_document_per_domain() {
+            let domain = format!("{}/cached-discovery", mockito::server_url());
+            let m = mock("GET", "/cached-discovery/.well-known/openid-configuration")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "issuer": format!("{domain}/"),
+                        "authorization_endpoint": format!("{domain}/authorize"),
+                        "token_endpoint": format!("{domain}/oauth/token"),
+                        "jwks_uri": format!("{domain}/.well-known/jwks.json")
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create();
+
+            fetch_openid_configuration(&domain).await.unwrap();
+            let resp = fetch_openid_configuration(&format!("{domain}/"))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.issuer, format!("{domain}/"));
+            m.assert();
+        }
+    }
+
+    mod load_openid_configuration {
+        use super::*;
+
+        #[tokio::test]
+        async fn use_the_discovered_endpoints() {
+            let _m = discovery_mock();
+            let _token = mock("POST", "/custom/token")
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            client.load_openid_configuration().await.unwrap();
+            let resp = client.authenticate().await.unwrap();
+            let url = client.authorize_url().build().unwrap();
+
+            assert_eq!(resp, "access_token");
+            assert!(url.starts_with(&format!("{}/custom/authorize?", mockito::server_url())));
+        }
+
+        #[tokio::test]
+        async fn fall_back_to_the_default_paths() {
+            let _m = discovery_mock();
+            let mut client = new_client();
+
+            client.load_openid_configuration().await.unwrap();
+
+            assert_eq!(
+                client.endpoint_url("/oauth/par"),
+                format!("{}/oauth/par", mockito::server_url())
+            );
+        }
+
+        #[tokio::test]
+        async fn validate_tokens_against_the_discovered_jwks() {
+            let domain = format!("{}/discovered-jwks", mockito::server_url());
+            let _m = mock("GET", "/discovered-jwks/.well-known/openid-configuration")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "issuer": format!("{domain}/"),
+                        "authorization_endpoint": format!("{domain}/authorize"),
+                        "token_endpoint": format!("{domain}/oauth/token"),
+                        "jwks_uri": format!("{domain}/custom/jwks.json")
+                    })
+                    .to_string(),
+                )
+                .create();
+            let discovered = mock("GET", "/discovered-jwks/custom/jwks.json")
+                .with_status(200)
+                .with_body(std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap())
+                .expect(1)
+                .create();
+            let default = mock("GET", "/discovered-jwks/.well-known/jwks.json")
+                .expect(0)
+                .create();
+            let mut client = Auth0Client::new(
+                "client_id",
+                "client_secret",
+                &domain,
+                "https://audience.com",
+            );
+            let token = crate::utils::sign_test_jwt(&json!({
+                "iss": format!("{domain}/"),
+                "aud": "client_id",
+                "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                "iat": chrono::Utc::now().timestamp(),
+                "exp": chrono::Utc::now().timestamp() + 3600,
+            }));
+
+            client.load_openid_configuration().await.unwrap();
+            client
+                .valid_id_token(&token, &Default::default())
+                .await
+                .unwrap();
+
+            discovered.assert();
+            default.assert();
+        }
+    }
+}
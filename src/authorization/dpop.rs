@@ -0,0 +1,432 @@
+//! Types, traits and functions relative to DPoP (RFC 9449) proofs and sender-constrained tokens.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, EllipticCurveKeyParameters,
+    EllipticCurveKeyType, Jwk,
+};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use ring::digest::{digest, SHA256};
+use ring::rand::SystemRandom;
+use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+use serde::Serialize;
+
+use crate::error::{Auth0Result, Error};
+use crate::utils::random_string;
+use crate::Auth0Client;
+
+/// The `typ` header of DPoP proofs.
+const DPOP_PROOF_TYPE: &str = "dpop+jwt";
+
+/// An EC P-256 key used to sign DPoP proofs.
+///
+/// # Example
+///
+/// ```
+/// # fn set_key(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::dpop::DpopKey;
+/// let key = DpopKey::generate()?;
+/// println!("Tokens will be bound to {}", key.thumbprint());
+///
+/// client.set_dpop_key(key);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DpopKey {
+    key: EncodingKey,
+    jwk: Jwk,
+    pkcs8: Vec<u8>,
+}
+
+/// The claims of a DPoP proof.
+#[derive(Serialize)]
+struct DpopClaims<'a> {
+    jti: String,
+    htm: &'a str,
+    htu: &'a str,
+    iat: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ath: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<&'a str>,
+}
+
+impl DpopKey {
+    /// Generates a new random key.
+    pub fn generate() -> Auth0Result<Self> {
+        let pkcs8 =
+            EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &SystemRandom::new())
+                .map_err(|_| Error::Unknown("Could not generate a DPoP key".to_owned()))?;
+
+        Self::from_pkcs8_der(pkcs8.as_ref())
+    }
+
+    /// Returns a key from a DER encoded PKCS#8 EC P-256 private key.
+    ///
+    /// Keys must be reused for the lifetime of the tokens bound to them.
+    pub fn from_pkcs8_der(der: &[u8]) -> Auth0Result<Self> {
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, der, &SystemRandom::new())
+                .map_err(|_| Error::Unknown("Invalid DPoP key".to_owned()))?;
+        // The public key is the uncompressed point: 0x04 || x || y.
+        let point = key_pair.public_key().as_ref();
+
+        Ok(Self {
+            key: EncodingKey::from_ec_der(der),
+            jwk: Jwk {
+                common: CommonParameters::default(),
+                algorithm: AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
+                    key_type: EllipticCurveKeyType::EC,
+                    curve: EllipticCurve::P256,
+                    x: URL_SAFE_NO_PAD.encode(&point[1..33]),
+                    y: URL_SAFE_NO_PAD.encode(&point[33..65]),
+                }),
+            },
+            pkcs8: der.to_vec(),
+        })
+    }
+
+    /// Returns the DER encoded PKCS#8 private key, e.g. to persist it.
+    pub fn to_pkcs8_der(&self) -> &[u8] {
+        &self.pkcs8
+    }
+
+    /// Returns the public key of the proofs.
+    pub fn jwk(&self) -> &Jwk {
+        &self.jwk
+    }
+
+    /// Returns the JWK thumbprint (RFC 7638) of the key, which is the `jkt` tokens are bound to.
+    pub fn thumbprint(&self) -> String {
+        let (x, y) = match &self.jwk.algorithm {
+            AlgorithmParameters::EllipticCurve(params) => (&params.x, &params.y),
+            _ => unreachable!("DPoP keys are always EC keys"),
+        };
+        // The members are required to be in lexicographic order without whitespaces.
+        let canonical = format!(r#"{{"crv":"P-256","kty":"EC","x":"{x}","y":"{y}"}}"#);
+
+        URL_SAFE_NO_PAD.encode(digest(&SHA256, canonical.as_bytes()))
+    }
+
+    /// Signs a single use proof for the given request.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method of the request.
+    /// * `url` - The URL of the request, whose query and fragment are left out of the proof.
+    /// * `access_token` - The access token sent along with the request, if any.
+    /// * `nonce` - The last nonce sent back by the server, if any.
+    pub fn proof(
+        &self,
+        method: &str,
+        url: &str,
+        access_token: Option<&str>,
+        nonce: Option<&str>,
+    ) -> Auth0Result<String> {
+        let htu = url.split(['?', '#']).next().unwrap_or(url);
+        let claims = DpopClaims {
+            jti: random_string(16)?,
+            htm: method,
+            htu,
+            iat: chrono::Utc::now().timestamp(),
+            ath: access_token
+                .map(|token| URL_SAFE_NO_PAD.encode(digest(&SHA256, token.as_bytes()))),
+            nonce,
+        };
+        let mut header = Header::new(Algorithm::ES256);
+        header.typ = Some(DPOP_PROOF_TYPE.to_owned());
+        header.jwk = Some(self.jwk.clone());
+
+        Ok(encode(&header, &claims, &self.key)?)
+    }
+}
+
+impl std::fmt::Debug for DpopKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DpopKey")
+            .field("jkt", &self.thumbprint())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Auth0Client {
+    /// Sets the key used to sign DPoP proofs.
+    ///
+    /// Once set, proofs are sent to the token endpoint so the issued tokens are bound
+    /// to the key, and along with the access token on every management API request.
+    pub fn set_dpop_key(&mut self, key: DpopKey) {
+        self.dpop_key = Some(key);
+    }
+
+    /// Returns the key used to sign DPoP proofs, if any.
+    pub fn dpop_key(&self) -> Option<&DpopKey> {
+        self.dpop_key.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::{Authenticatable, AuthenticationError, TokenType};
+    use crate::management::users::{OperateUsers, UserResponse};
+    use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
+    use mockito::{mock, Matcher, Mock};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        let mut client = Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        );
+
+        client.set_dpop_key(DpopKey::generate().unwrap());
+        client
+    }
+
+    fn decode_proof(proof: &str) -> serde_json::Value {
+        let header = decode_header(proof).unwrap();
+        let mut validation = Validation::new(Algorithm::ES256);
+        validation.set_required_spec_claims::<&str>(&[]);
+
+        assert_eq!(header.typ.as_deref(), Some(DPOP_PROOF_TYPE));
+        decode::<serde_json::Value>(
+            proof,
+            &DecodingKey::from_jwk(&header.jwk.unwrap()).unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims
+    }
+
+    mod proof {
+        use super::*;
+
+        #[test]
+        fn sign_the_request_with_the_embedded_key() {
+            let key = DpopKey::generate().unwrap();
+
+            let proof = key
+                .proof(
+                    "GET",
+                    "https://api.example.com/orders",
+                    Some("access_token"),
+                    Some("nonce"),
+                )
+                .unwrap();
+            let claims = decode_proof(&proof);
+
+            assert_eq!(claims["htm"], "GET");
+            assert_eq!(claims["htu"], "https://api.example.com/orders");
+            assert_eq!(claims["nonce"], "nonce");
+            assert_eq!(
+                claims["ath"],
+                URL_SAFE_NO_PAD.encode(digest(&SHA256, b"access_token"))
+            );
+        }
+
+        #[test]
+        fn leave_out_the_query_and_the_fragment() {
+            let key = DpopKey::generate().unwrap();
+
+            for url in [
+                "https://api.example.com/orders?page=1&per_page=50",
+                "https://api.example.com/orders#latest",
+            ] {
+                let claims = decode_proof(&key.proof("GET", url, None, None).unwrap());
+
+                assert_eq!(claims["htu"], "https://api.example.com/orders");
+            }
+        }
+    }
+
+    mod thumbprint {
+        use super::*;
+
+        #[test]
+        fn be_stable_across_reloads() {
+            let key = DpopKey::generate().unwrap();
+            let reloaded = DpopKey::from_pkcs8_der(key.to_pkcs8_der()).unwrap();
+
+            assert_eq!(key.thumbprint(), reloaded.thumbprint());
+            assert_eq!(key.thumbprint().len(), 43);
+        }
+    }
+
+    mod authenticate {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_a_proof_to_the_token_endpoint() {
+            let _m = mock("POST", "/oauth/token")
+                .match_header("DPoP", Matcher::Regex("^ey".to_owned()))
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "token_type": "DPoP" }).to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .authenticate_with_body(Default::default())
+                .await
+                .unwrap();
+
+            assert_eq!(resp.token_type, TokenType::Dpop);
+            assert_eq!(resp.jkt, Some(client.dpop_key().unwrap().thumbprint()));
+        }
+
+        #[tokio::test]
+        async fn not_bind_bearer_tokens_to_the_key() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "token_type": "Bearer" }).to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .authenticate_with_body(Default::default())
+                .await
+                .unwrap();
+
+            assert_eq!(resp.token_type, TokenType::Bearer);
+            assert_eq!(resp.jkt, None);
+        }
+
+        #[tokio::test]
+        async fn retry_with_the_server_nonce() {
+            let _rejected = mock("POST", "/oauth/token")
+                .with_status(400)
+                .with_header("DPoP-Nonce", "server_nonce")
+                .with_body(
+                    json!({
+                        "error": "use_dpop_nonce",
+                        "error_description": "Authorization server requires nonce in DPoP proof"
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create();
+            let _accepted = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.authenticate().await.unwrap();
+
+            assert_eq!(resp, "access_token");
+            _rejected.assert();
+        }
+
+        #[tokio::test]
+        async fn give_up_when_the_nonce_is_rejected_twice() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(400)
+                .with_header("DPoP-Nonce", "server_nonce")
+                .with_body(json!({ "error": "use_dpop_nonce" }).to_string())
+                .expect(2)
+                .create();
+            let mut client = new_client();
+
+            let resp = client.authenticate().await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::UseDpopNonce(nonce))) => {
+                    assert_eq!(nonce, "server_nonce")
+                }
+                _ => panic!("Expected UseDpopNonce variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod request {
+        use super::*;
+
+        fn user_not_found(authorization: &str) -> Mock {
+            mock("GET", "/users/user_id")
+                .match_header("Authorization", authorization)
+                .with_status(404)
+                .with_body(
+                    json!({ "statusCode": 404, "error": "Not Found", "message": "The user does not exist." })
+                        .to_string(),
+                )
+        }
+
+        #[tokio::test]
+        async fn send_the_access_token_with_a_proof() {
+            let _auth = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "token_type": "DPoP" }).to_string(),
+                )
+                .create();
+            let _m = user_not_found("DPoP access_token")
+                .match_header("DPoP", Matcher::Regex("^ey".to_owned()))
+                .create();
+            let mut client = new_client();
+            client.access_token = Some("access_token".to_owned());
+
+            let resp: Auth0Result<UserResponse> = client.get_user("user_id").await;
+
+            match resp {
+                Err(Error::User(_)) => (),
+                _ => panic!("Expected User variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn send_bearer_tokens_without_a_proof() {
+            let _auth = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "token_type": "Bearer" }).to_string(),
+                )
+                .create();
+            let _m = user_not_found("Bearer access_token")
+                .match_header("DPoP", Matcher::Missing)
+                .create();
+            let mut client = new_client();
+            client.access_token = Some("access_token".to_owned());
+
+            let resp: Auth0Result<UserResponse> = client.get_user("user_id").await;
+
+            match resp {
+                Err(Error::User(_)) => (),
+                _ => panic!("Expected User variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn retry_with_the_server_nonce() {
+            let _auth = mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({ "access_token": "access_token", "token_type": "DPoP" }).to_string(),
+                )
+                .create();
+            let _rejected = mock("GET", "/users/user_id")
+                .with_status(401)
+                .with_header("WWW-Authenticate", r#"DPoP error="use_dpop_nonce""#)
+                .with_header("DPoP-Nonce", "server_nonce")
+                .expect(1)
+                .create();
+            let _accepted = user_not_found("DPoP access_token").create();
+            let mut client = new_client();
+            client.access_token = Some("access_token".to_owned());
+
+            let resp: Auth0Result<UserResponse> = client.get_user("user_id").await;
+
+            match resp {
+                Err(Error::User(_)) => (),
+                _ => panic!("Expected User variant, got: {resp:?}"),
+            }
+            _rejected.assert();
+        }
+    }
+}
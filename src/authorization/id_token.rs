@@ -0,0 +1,390 @@
+//! Types and functions relative to the validation of ID tokens.
+
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, TokenData, Validation};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::authorization::jwks::JwksSource;
+use crate::authorization::validation::{authority, check_organization};
+use crate::authorization::{namespaced_claims, valid_jwt_from, OneOrMany};
+use crate::error::{Auth0Result, Error};
+use crate::Auth0Client;
+
+/// The claims of an ID token.
+#[derive(Debug, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: OneOrMany<String>,
+    pub exp: i64,
+    pub iat: i64,
+    pub azp: Option<String>,
+    pub nonce: Option<String>,
+    pub auth_time: Option<i64>,
+    pub org_id: Option<String>,
+    pub org_name: Option<String>,
+    /// Every other claim of the token, e.g. the profile of the user.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl IdTokenClaims {
+    /// Deserializes the custom claims under a namespace, with the namespace stripped from their names.
+    pub fn namespaced<T: DeserializeOwned>(&self, namespace: &str) -> Auth0Result<T> {
+        namespaced_claims(&self.other, namespace)
+    }
+}
+
+/// The values of the authorization request an ID token is checked against.
+#[derive(Debug, Clone, Default)]
+pub struct IdTokenValidation {
+    /// The `nonce` sent to the authorize endpoint.
+    pub nonce: Option<String>,
+    /// The `max_age` sent to the authorize endpoint, in seconds.
+    pub max_age: Option<u64>,
+    /// The organization (ID or name) sent to the authorize endpoint.
+    pub organization: Option<String>,
+    /// The number of seconds of clock skew tolerated, 60 by default.
+    pub leeway: Option<u64>,
+}
+
+/// Validates an ID token issued by Auth0 and returns its decoded payload.
+///
+/// On top of the signature, it performs the checks required by the OIDC specification
+/// and the Auth0 organizations:
+/// * the issuer is the tenant domain and the audience contains the client ID,
+/// * the `azp` claim is the client ID when there are multiple audiences,
+/// * the `nonce` claim matches the one of the request, if any,
+/// * the `auth_time` claim is within `max_age`, if any,
+/// * the `org_id` or `org_name` claim matches the organization of the request, if any.
+///
+/// # Arguments
+///
+/// * `token` - The ID token to validate.
+/// * `domain` - The domain of the tenant the issuer is derived from, with or without `https://`.
+/// * `client_id` - The client ID of the application.
+/// * `options` - The values of the authorization request the token is checked against.
+/// * `jwks` - The jwks to use instead of fetching them, if any.
+///
+/// # Example
+/// ```
+/// # async fn validate_id_token() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::id_token::{valid_id_token, IdTokenValidation};
+/// let options = IdTokenValidation {
+///     nonce: Some("nonce".to_owned()),
+///     ..Default::default()
+/// };
+///
+/// let (token, _) = valid_id_token(
+///     "...id_token...",
+///     "https://domain.com",
+///     "client_id",
+///     &options,
+///     None,
+/// ).await?;
+///
+/// println!("Hello {}", token.claims.sub);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn valid_id_token(
+    token: &str,
+    domain: &str,
+    client_id: &str,
+    options: &IdTokenValidation,
+    jwks: Option<&JwkSet>,
+) -> Auth0Result<(TokenData<IdTokenClaims>, JwkSet)> {
+    let source = JwksSource::of(&authority(domain));
+
+    valid_id_token_from(token, &source, client_id, options, jwks).await
+}
+
+/// Same as [`valid_id_token`], the jwks being fetched from the given source if none is given.
+async fn valid_id_token_from(
+    token: &str,
+    source: &JwksSource<'_>,
+    client_id: &str,
+    options: &IdTokenValidation,
+    jwks: Option<&JwkSet>,
+) -> Auth0Result<(TokenData<IdTokenClaims>, JwkSet)> {
+    let issuer = format!("{}/", source.authority());
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.set_audience(&[client_id]);
+    validation.set_issuer(&[&issuer]);
+    validation.set_required_spec_claims(&["iss", "aud", "exp", "iat", "sub"]);
+    if let Some(leeway) = options.leeway {
+        validation.leeway = leeway;
+    }
+
+    let leeway = validation.leeway as i64;
+    let (token, jwks) = valid_jwt_from::<IdTokenClaims>(token, source, validation, jwks).await?;
+    let claims = &token.claims;
+
+    if claims.aud.len() > 1 && claims.azp.as_deref() != Some(client_id) {
+        return Err(Error::InvalidIdToken(
+            "azp does not match the client ID".to_owned(),
+        ));
+    }
+    if let Some(nonce) = &options.nonce {
+        if claims.nonce.as_ref() != Some(nonce) {
+            return Err(Error::InvalidIdToken("nonce does not match".to_owned()));
+        }
+    }
+    if let Some(max_age) = options.max_age {
+        let auth_time = claims
+            .auth_time
+            .ok_or_else(|| Error::InvalidIdToken("missing auth_time".to_owned()))?;
+
+        // A `max_age` too large to be added to `auth_time` can never be exceeded.
+        let expires_at = i64::try_from(max_age)
+            .ok()
+            .and_then(|max_age| auth_time.checked_add(max_age)?.checked_add(leeway));
+
+        if expires_at.is_some_and(|expires_at| chrono::Utc::now().timestamp() > expires_at) {
+            return Err(Error::InvalidIdToken(
+                "authentication is older than max_age".to_owned(),
+            ));
+        }
+    }
+    if let Some(organization) = &options.organization {
+        check_organization(
+            claims.org_id.as_deref(),
+            claims.org_name.as_deref(),
+            organization,
+        )
+        .map_err(|err| Error::InvalidIdToken(err.to_owned()))?;
+    }
+
+    Ok((token, jwks))
+}
+
+impl Auth0Client {
+    /// Validates an ID token issued to the client.
+    ///
+    /// The organization of the client is checked unless `options` sets another one,
+    /// and the jwks set on the client is used, if any.
+    pub async fn valid_id_token(
+        &mut self,
+        token: &str,
+        options: &IdTokenValidation,
+    ) -> Auth0Result<TokenData<IdTokenClaims>> {
+        let mut options = options.clone();
+        if options.organization.is_none() {
+            options.organization = self.organization.clone();
+        }
+
+        let (token, _) = valid_id_token_from(
+            token,
+            &self.jwks_source(),
+            &self.client_id,
+            &options,
+            self.jwks.as_ref(),
+        )
+        .await?;
+
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::sign_test_jwt;
+    use mockito::{mock, Mock};
+    use serde_json::json;
+
+    fn jwks_mock() -> Mock {
+        let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+
+        mock("GET", "/.well-known/jwks.json")
+            .with_status(200)
+            .with_body(jwks_response)
+            .create()
+    }
+
+    fn id_token(extra: serde_json::Value) -> String {
+        let now = chrono::Utc::now().timestamp();
+        let mut claims = json!({
+            "iss": format!("{}/", mockito::server_url()),
+            "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+            "aud": "client_id",
+            "exp": now + 3600,
+            "iat": now,
+            "nonce": "nonce",
+            "auth_time": now - 60,
+            "org_id": "org_W2pqeQg6pL6Ar3sk",
+            "org_name": "acme",
+            "email": "user@example.com"
+        });
+        // A null claim is left out of the token.
+        for (key, value) in extra.as_object().unwrap() {
+            if value.is_null() {
+                claims.as_object_mut().unwrap().remove(key);
+            } else {
+                claims[key] = value.clone();
+            }
+        }
+        sign_test_jwt(&claims)
+    }
+
+    async fn validate(
+        token: &str,
+        options: IdTokenValidation,
+    ) -> Auth0Result<(TokenData<IdTokenClaims>, JwkSet)> {
+        valid_id_token(token, &mockito::server_url(), "client_id", &options, None).await
+    }
+
+    fn assert_invalid_id_token(res: Auth0Result<(TokenData<IdTokenClaims>, JwkSet)>) {
+        match res {
+            Err(Error::InvalidIdToken(_)) => (),
+            _ => panic!("Expected InvalidIdToken variant, got: {res:?}"),
+        }
+    }
+
+    mod valid_id_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn validate_valid_id_token() {
+            let _m = jwks_mock();
+            let options = IdTokenValidation {
+                nonce: Some("nonce".to_owned()),
+                max_age: Some(3600),
+                organization: Some("org_W2pqeQg6pL6Ar3sk".to_owned()),
+                ..Default::default()
+            };
+
+            let (token, _) = validate(&id_token(json!({})), options).await.unwrap();
+
+            assert_eq!(*token.claims.aud, vec!["client_id"]);
+            assert_eq!(token.claims.other["email"], "user@example.com");
+        }
+
+        #[tokio::test]
+        async fn accept_domains_without_scheme() {
+            let jwks: JwkSet =
+                serde_json::from_str(&std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap())
+                    .unwrap();
+            let token = id_token(json!({ "iss": "https://tenant.eu.auth0.com/" }));
+
+            let res = valid_id_token(
+                &token,
+                "tenant.eu.auth0.com",
+                "client_id",
+                &Default::default(),
+                Some(&jwks),
+            )
+            .await;
+
+            assert!(res.is_ok(), "{res:?}");
+        }
+
+        #[tokio::test]
+        async fn check_azp_with_multiple_audiences() {
+            let _m = jwks_mock();
+            let aud = json!({ "aud": ["client_id", "https://audience.com"] });
+
+            let res = validate(&id_token(aud.clone()), Default::default()).await;
+            assert_invalid_id_token(res);
+
+            let mut claims = aud;
+            claims["azp"] = json!("client_id");
+            let res = validate(&id_token(claims), Default::default()).await;
+            assert!(res.is_ok());
+        }
+
+        #[tokio::test]
+        async fn errored_with_another_nonce() {
+            let _m = jwks_mock();
+            let options = IdTokenValidation {
+                nonce: Some("another_nonce".to_owned()),
+                ..Default::default()
+            };
+
+            assert_invalid_id_token(validate(&id_token(json!({})), options).await);
+        }
+
+        #[tokio::test]
+        async fn errored_with_old_authentication() {
+            let _m = jwks_mock();
+            let options = IdTokenValidation {
+                max_age: Some(0),
+                ..Default::default()
+            };
+            let auth_time = chrono::Utc::now().timestamp() - 3600;
+
+            let res = validate(&id_token(json!({ "auth_time": auth_time })), options).await;
+
+            assert_invalid_id_token(res);
+
+            let options = IdTokenValidation {
+                max_age: Some(3000),
+                leeway: Some(3600),
+                ..Default::default()
+            };
+            let res = validate(&id_token(json!({ "auth_time": auth_time })), options).await;
+
+            assert!(res.is_ok());
+        }
+
+        #[tokio::test]
+        async fn accept_max_ages_overflowing_the_expiration() {
+            let _m = jwks_mock();
+
+            for max_age in [i64::MAX as u64, u64::MAX] {
+                let options = IdTokenValidation {
+                    max_age: Some(max_age),
+                    ..Default::default()
+                };
+                let res = validate(&id_token(json!({})), options).await;
+
+                assert!(res.is_ok(), "{res:?}");
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_without_iat() {
+            let _m = jwks_mock();
+
+            let res = validate(&id_token(json!({ "iat": null })), Default::default()).await;
+
+            match res {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {res:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn check_the_organization_name() {
+            let _m = jwks_mock();
+            let options = |organization: &str| IdTokenValidation {
+                organization: Some(organization.to_owned()),
+                ..Default::default()
+            };
+
+            assert!(validate(&id_token(json!({})), options("ACME"))
+                .await
+                .is_ok());
+            assert_invalid_id_token(validate(&id_token(json!({})), options("umbrella")).await);
+            assert_invalid_id_token(validate(&id_token(json!({})), options("org_other")).await);
+        }
+
+        #[tokio::test]
+        async fn errored_with_another_issuer() {
+            let _m = jwks_mock();
+
+            let res = validate(
+                &id_token(json!({ "iss": "https://evil.com/" })),
+                Default::default(),
+            )
+            .await;
+
+            match res {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {res:?}"),
+            }
+        }
+    }
+}
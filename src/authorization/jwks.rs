@@ -0,0 +1,964 @@
+//! Types and functions relative to fetching and caching the jwks of an authority.
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use lazy_static::lazy_static;
+use reqwest::header::{
+    HeaderMap, HeaderName, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+use crate::error::{Auth0Result, Error};
+use crate::utils::URL_REGEX;
+
+/// The default number of seconds a jwks is cached for when the response has no `max-age`.
+const DEFAULT_JWKS_TTL: u64 = 600;
+
+/// The default minimum number of seconds between two refreshes of the jwks of an authority.
+const DEFAULT_MIN_REFRESH_INTERVAL: u64 = 30;
+
+/// The maximum number of replaced jwks kept per authority for their grace period.
+const MAX_PREVIOUS_JWKS: usize = 3;
+
+/// The DER encoded object identifiers of the supported public key types and curves.
+const RSA_ENCRYPTION_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+const P256_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+const P384_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x22];
+const ED25519_OID: &[u8] = &[0x2b, 0x65, 0x70];
+
+lazy_static! {
+    /// The cache used by the validation functions when no jwks is given to them.
+    static ref GLOBAL_JWKS_CACHE: JwksCache = JwksCache::default();
+    /// The HTTP client the jwks are fetched with, so its connections are reused.
+    static ref JWKS_HTTP_CLIENT: reqwest::Client = reqwest::Client::new();
+}
+
+/// Where the jwks of an authority is fetched from, and with which HTTP client.
+pub(crate) struct JwksSource<'a> {
+    authority: String,
+    jwks_uri: String,
+    http_client: &'a reqwest::Client,
+}
+
+impl<'a> JwksSource<'a> {
+    /// The jwks at the given URI, e.g. the `jwks_uri` of the discovery document of the authority.
+    pub(crate) fn new(authority: &str, jwks_uri: &str, http_client: &'a reqwest::Client) -> Self {
+        Self {
+            authority: authority.to_owned(),
+            jwks_uri: jwks_uri.to_owned(),
+            http_client,
+        }
+    }
+
+    /// The `/.well-known/jwks.json` of the authority, fetched with the HTTP client of the cache.
+    pub(crate) fn of(authority: &str) -> JwksSource<'static> {
+        JwksSource::new(
+            authority,
+            &format!("{authority}/.well-known/jwks.json"),
+            &JWKS_HTTP_CLIENT,
+        )
+    }
+
+    pub(crate) fn authority(&self) -> &str {
+        &self.authority
+    }
+}
+
+/// A cache of the jwks of each authority.
+///
+/// Entries expire after the `max-age` of the `Cache-Control` header sent along with the
+/// jwks, or after the TTL of the cache when there is none.
+///
+/// The `ETag` and `Last-Modified` of the jwks are kept so that refreshes are conditional
+/// requests: a `304 Not Modified` response keeps the cached keys without downloading them again.
+///
+/// With a grace period, the keys removed from a jwks by a refresh are still returned for
+/// that duration, so tokens signed shortly before a key rotation keep validating.
+///
+/// Refreshes forced by tokens signed with unknown keys are limited to one per authority
+/// within the minimum refresh interval, so such tokens cannot make the cache flood the authority.
+#[derive(Debug)]
+pub struct JwksCache {
+    entries: Mutex<HashMap<String, CachedJwks>>,
+    ttl: Duration,
+    /// The grace period in milliseconds, which can be changed on the global cache.
+    grace_period: AtomicU64,
+    /// The minimum refresh interval in milliseconds, which can be changed on the global cache.
+    min_refresh_interval: AtomicU64,
+}
+
+#[derive(Debug)]
+struct CachedJwks {
+    jwks: JwkSet,
+    /// `None` for preloaded jwks, which never expire.
+    expires_at: Option<Instant>,
+    /// `None` for preloaded jwks, which were not fetched.
+    fetched_at: Option<Instant>,
+    validators: Validators,
+    /// The jwks replaced by refreshes, most recent first, along with when they were replaced.
+    previous: VecDeque<(JwkSet, Instant)>,
+}
+
+impl CachedJwks {
+    /// Returns the jwks along with the keys of the previous jwks replaced within the grace period.
+    fn keys(&self, grace_period: Duration) -> JwkSet {
+        let mut jwks = self.jwks.clone();
+
+        for (previous, replaced_at) in &self.previous {
+            if replaced_at.elapsed() >= grace_period {
+                continue;
+            }
+            for key in &previous.keys {
+                if let Some(kid) = &key.common.key_id {
+                    if jwks.find(kid).is_none() {
+                        jwks.keys.push(key.clone());
+                    }
+                }
+            }
+        }
+        jwks
+    }
+}
+
+/// The headers of a jwks response used to make conditional requests.
+#[derive(Debug, Clone, Default)]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// A jwks response, whose jwks is `None` when it was not modified.
+struct JwksResponse {
+    jwks: Option<JwkSet>,
+    max_age: Option<Duration>,
+    validators: Validators,
+}
+
+impl JwksCache {
+    /// Creates a cache keeping jwks for `ttl` when their response has no `max-age`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+            grace_period: AtomicU64::new(0),
+            min_refresh_interval: AtomicU64::new(DEFAULT_MIN_REFRESH_INTERVAL * 1000),
+        }
+    }
+
+    /// Sets for how long the keys removed from a jwks by a refresh are still used, none by default.
+    ///
+    /// Up to 3 previous jwks are kept per authority.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use auth0_client::authorization::jwks::JwksCache;
+    /// JwksCache::global().set_grace_period(Duration::from_secs(300));
+    /// ```
+    pub fn set_grace_period(&self, grace_period: Duration) -> &JwksCache {
+        self.grace_period
+            .store(grace_period.as_millis() as u64, Ordering::Relaxed);
+        self
+    }
+
+    fn grace_period(&self) -> Duration {
+        Duration::from_millis(self.grace_period.load(Ordering::Relaxed))
+    }
+
+    /// Sets the minimum interval between two refreshes of the jwks of an authority, 30 seconds by default.
+    ///
+    /// Within that interval, [`JwksCache::refresh`] returns the cached jwks instead of fetching it.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use auth0_client::authorization::jwks::JwksCache;
+    /// JwksCache::global().set_min_refresh_interval(Duration::from_secs(60));
+    /// ```
+    pub fn set_min_refresh_interval(&self, interval: Duration) -> &JwksCache {
+        self.min_refresh_interval
+            .store(interval.as_millis() as u64, Ordering::Relaxed);
+        self
+    }
+
+    fn min_refresh_interval(&self) -> Duration {
+        Duration::from_millis(self.min_refresh_interval.load(Ordering::Relaxed))
+    }
+
+    /// Returns the cache used by [`valid_jwt`](crate::authorization::valid_jwt) and the
+    /// other validation functions when they are not given a jwks.
+    pub fn global() -> &'static JwksCache {
+        &GLOBAL_JWKS_CACHE
+    }
+
+    /// Returns the jwks of the authority, fetching it if it is not cached or has expired.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn cached_jwks() -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::jwks::JwksCache;
+    /// let jwks = JwksCache::global().get("https://tenant.eu.auth0.com").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, authority: &str) -> Auth0Result<JwkSet> {
+        self.get_from(&JwksSource::of(authority)).await
+    }
+
+    /// Same as [`JwksCache::get`], fetching the jwks from the given source.
+    pub(crate) async fn get_from(&self, source: &JwksSource<'_>) -> Auth0Result<JwkSet> {
+        let cached = self
+            .lock()?
+            .get(cache_key(source.authority()))
+            .filter(|entry| entry.expires_at.is_none_or(|at| at > Instant::now()))
+            .map(|entry| entry.keys(self.grace_period()));
+
+        match cached {
+            Some(jwks) => Ok(jwks),
+            None => self.fetch(source).await,
+        }
+    }
+
+    /// Fetches the jwks of the authority and caches it, whether it is already cached or not.
+    ///
+    /// When the authority is cached, the request is conditional and the cached jwks is kept
+    /// if it was not modified. If it was fetched within the minimum refresh interval, the
+    /// cached jwks is returned without fetching it again.
+    pub async fn refresh(&self, authority: &str) -> Auth0Result<JwkSet> {
+        self.refresh_from(&JwksSource::of(authority)).await
+    }
+
+    /// Same as [`JwksCache::refresh`], fetching the jwks from the given source.
+    pub(crate) async fn refresh_from(&self, source: &JwksSource<'_>) -> Auth0Result<JwkSet> {
+        let authority = source.authority();
+        let recent = self
+            .lock()?
+            .get(cache_key(authority))
+            .filter(|entry| {
+                entry
+                    .fetched_at
+                    .is_some_and(|at| at.elapsed() < self.min_refresh_interval())
+            })
+            .map(|entry| entry.keys(self.grace_period()));
+
+        match recent {
+            Some(jwks) => {
+                tracing::debug!(
+                    "The jwks of {authority} was refreshed too recently to fetch it again"
+                );
+                Ok(jwks)
+            }
+            None => self.fetch(source).await,
+        }
+    }
+
+    /// Fetches the jwks of the authority and caches it, conditionally if it is already cached.
+    async fn fetch(&self, source: &JwksSource<'_>) -> Auth0Result<JwkSet> {
+        let authority = source.authority();
+        let cached = self
+            .lock()?
+            .get(cache_key(authority))
+            .map(|entry| (entry.jwks.clone(), entry.validators.clone()));
+        let response = fetch_jwks_conditionally(
+            source.http_client,
+            &source.jwks_uri,
+            cached.as_ref().map(|(_, validators)| validators),
+        )
+        .await?;
+
+        let jwks = match (response.jwks, cached) {
+            (Some(jwks), _) => jwks,
+            (None, Some((jwks, _))) => {
+                tracing::debug!("The jwks of {authority} was not modified");
+                jwks
+            }
+            (None, None) => return Err(Error::InvalidResponseBody),
+        };
+        let grace_period = self.grace_period();
+        let mut entries = self.lock()?;
+        let mut previous = VecDeque::new();
+
+        if let Some(replaced) = entries.remove(cache_key(authority)) {
+            previous = replaced.previous;
+            if replaced.jwks != jwks {
+                previous.push_front((replaced.jwks, Instant::now()));
+            }
+            previous.retain(|(_, replaced_at)| replaced_at.elapsed() < grace_period);
+            previous.truncate(MAX_PREVIOUS_JWKS);
+        }
+
+        let entry = CachedJwks {
+            jwks,
+            expires_at: Some(Instant::now() + response.max_age.unwrap_or(self.ttl)),
+            fetched_at: Some(Instant::now()),
+            validators: response.validators,
+            previous,
+        };
+        let jwks = entry.keys(grace_period);
+
+        entries.insert(cache_key(authority).to_owned(), entry);
+        Ok(jwks)
+    }
+
+    /// Caches a jwks for the authority which never expires, e.g. one loaded with [`jwks_from_file`].
+    ///
+    /// Validations against the authority then do not call `/.well-known/jwks.json`
+    /// unless the token is signed by a key missing from the jwks.
+    pub fn preload(&self, authority: &str, jwks: JwkSet) -> Auth0Result<()> {
+        let entry = CachedJwks {
+            jwks,
+            expires_at: None,
+            fetched_at: None,
+            validators: Validators::default(),
+            previous: VecDeque::new(),
+        };
+
+        self.lock()?.insert(cache_key(authority).to_owned(), entry);
+        Ok(())
+    }
+
+    /// Spawns a tokio task fetching the jwks of the authority every `interval`.
+    ///
+    /// Validations then always find a fresh jwks in the cache, and rotated keys are
+    /// picked up before the cached jwks expires. Failed refreshes are logged and the
+    /// cached jwks is kept. The task runs until the returned handle is aborted.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn refresher() {
+    /// # use std::time::Duration;
+    /// # use auth0_client::authorization::jwks::JwksCache;
+    /// let refresher = JwksCache::global()
+    ///     .spawn_refresher("https://tenant.eu.auth0.com", Duration::from_secs(300));
+    ///
+    /// // On shutdown
+    /// refresher.abort();
+    /// # }
+    /// ```
+    pub fn spawn_refresher(&'static self, authority: &str, interval: Duration) -> JoinHandle<()> {
+        let authority = authority.to_owned();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+
+            loop {
+                ticker.tick().await;
+                tracing::debug!("Refreshing the jwks of {authority}...");
+
+                if let Err(err) = self.fetch(&JwksSource::of(&authority)).await {
+                    tracing::warn!("Could not refresh the jwks of {authority}: {err}");
+                }
+            }
+        })
+    }
+
+    /// Removes the jwks of the authority from the cache.
+    pub fn invalidate(&self, authority: &str) -> Auth0Result<()> {
+        self.lock()?.remove(cache_key(authority));
+        Ok(())
+    }
+
+    fn lock(&self) -> Auth0Result<std::sync::MutexGuard<'_, HashMap<String, CachedJwks>>> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::Unknown("JWKS cache lock poisoned".to_owned()))
+    }
+}
+
+impl Default for JwksCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_JWKS_TTL))
+    }
+}
+
+/// Returns the key an authority is cached under, so `https://domain.com` and `https://domain.com/` share it.
+fn cache_key(authority: &str) -> &str {
+    authority.trim_end_matches('/')
+}
+
+/// Fetches the jwks from the given URI, only if it changed since the given validators were sent.
+async fn fetch_jwks_conditionally(
+    http_client: &reqwest::Client,
+    url: &str,
+    validators: Option<&Validators>,
+) -> Auth0Result<JwksResponse> {
+    let url = URL_REGEX.replace_all(url, "$1").to_string();
+    let mut request = http_client.get(url);
+
+    if let Some(validators) = validators {
+        if let Some(etag) = &validators.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let res = request.send().await?;
+    let status = res.status();
+
+    if status.is_server_error() {
+        return Err(Error::ServerError(status.as_u16()));
+    }
+    if !status.is_success() && status != StatusCode::NOT_MODIFIED {
+        return Err(Error::InvalidResponseBody);
+    }
+    let headers = res.headers();
+    let max_age = max_age(headers);
+    let not_modified = status == StatusCode::NOT_MODIFIED && validators.is_some();
+    let validators = Validators {
+        // A 304 may omit the validators, which are then still the ones of the cached jwks.
+        etag: header(headers, ETAG).or_else(|| {
+            validators
+                .filter(|_| not_modified)
+                .and_then(|v| v.etag.clone())
+        }),
+        last_modified: header(headers, LAST_MODIFIED).or_else(|| {
+            validators
+                .filter(|_| not_modified)
+                .and_then(|v| v.last_modified.clone())
+        }),
+    };
+    let jwks = if not_modified {
+        None
+    } else {
+        Some(res.json::<JwkSet>().await?)
+    };
+
+    Ok(JwksResponse {
+        jwks,
+        max_age,
+        validators,
+    })
+}
+
+/// Returns the `max-age` of the `Cache-Control` header of a response, if any.
+pub(crate) fn max_age(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(CACHE_CONTROL)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .split(',')
+                .find_map(|directive| directive.trim().strip_prefix("max-age="))
+                .and_then(|max_age| max_age.parse().ok())
+        })
+        .map(Duration::from_secs)
+}
+
+/// Returns the value of a response header as a string.
+fn header(headers: &HeaderMap, name: HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// Loads a jwks from a local file, e.g. a copy of the `/.well-known/jwks.json` of a tenant.
+///
+/// # Example
+/// ```
+/// # fn offline_jwks() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::jwks::{jwks_from_file, JwksCache};
+/// let jwks = jwks_from_file("tests/data/rsa_jwks.json")?;
+///
+/// JwksCache::global().preload("https://tenant.eu.auth0.com", jwks)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn jwks_from_file(path: impl AsRef<Path>) -> Auth0Result<JwkSet> {
+    let content = std::fs::read_to_string(path)?;
+
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Builds a jwk from a PEM encoded (SPKI) public key.
+///
+/// RSA, EC P-256 and P-384, and Ed25519 keys are supported.
+///
+/// # Arguments
+///
+/// * `pem` - The PEM encoded public key, starting with `-----BEGIN PUBLIC KEY-----`.
+/// * `kid` - The id of the key, which tokens reference in their header.
+///
+/// # Example
+/// ```
+/// # fn offline_jwks() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::jwks::jwk_from_pem;
+/// # use jsonwebtoken::jwk::JwkSet;
+/// let pem = std::fs::read("tests/data/rsa_public_key.pem")?;
+///
+/// let jwks = JwkSet {
+///     keys: vec![jwk_from_pem(&pem, "test_kid")?],
+/// };
+/// # Ok(())
+/// # }
+/// ```
+pub fn jwk_from_pem(pem: &[u8], kid: &str) -> Auth0Result<Jwk> {
+    let invalid = |reason: &str| Error::InvalidPublicKey(reason.to_owned());
+
+    let pem = std::str::from_utf8(pem).map_err(|_| invalid("not a PEM document"))?;
+    let body = pem
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "-----BEGIN PUBLIC KEY-----")
+        .skip(1)
+        .take_while(|line| !line.starts_with("-----END"))
+        .collect::<String>();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|_| invalid("invalid base64 content"))?;
+
+    let (_, spki, _) = der_element(&der, 0x30).ok_or_else(|| invalid("invalid SPKI"))?;
+    let (_, algorithm, rest) = der_element(spki, 0x30).ok_or_else(|| invalid("invalid SPKI"))?;
+    let (_, bit_string, _) = der_element(rest, 0x03).ok_or_else(|| invalid("invalid SPKI"))?;
+    let (_, oid, parameters) =
+        der_element(algorithm, 0x06).ok_or_else(|| invalid("invalid algorithm"))?;
+    let key = match bit_string.split_first() {
+        Some((0, key)) => key,
+        _ => return Err(invalid("invalid key bit string")),
+    };
+
+    let jwk = match oid {
+        RSA_ENCRYPTION_OID => {
+            let (_, rsa, _) = der_element(key, 0x30).ok_or_else(|| invalid("invalid RSA key"))?;
+            let (_, n, rest) = der_element(rsa, 0x02).ok_or_else(|| invalid("invalid RSA key"))?;
+            let (_, e, _) = der_element(rest, 0x02).ok_or_else(|| invalid("invalid RSA key"))?;
+
+            if n.is_empty() || e.is_empty() {
+                return Err(invalid("invalid RSA key"));
+            }
+
+            json!({
+                "kty": "RSA",
+                "alg": "RS256",
+                "n": encode_unsigned(n),
+                "e": encode_unsigned(e),
+            })
+        }
+        EC_PUBLIC_KEY_OID => {
+            let (_, curve, _) =
+                der_element(parameters, 0x06).ok_or_else(|| invalid("missing EC curve"))?;
+            let (crv, alg) = match curve {
+                P256_OID => ("P-256", "ES256"),
+                P384_OID => ("P-384", "ES384"),
+                _ => return Err(invalid("unsupported EC curve")),
+            };
+            let point = match key.split_first() {
+                Some((0x04, point)) if point.len() % 2 == 0 => point,
+                _ => return Err(invalid("invalid EC point")),
+            };
+            let (x, y) = point.split_at(point.len() / 2);
+
+            json!({
+                "kty": "EC",
+                "alg": alg,
+                "crv": crv,
+                "x": URL_SAFE_NO_PAD.encode(x),
+                "y": URL_SAFE_NO_PAD.encode(y),
+            })
+        }
+        ED25519_OID => json!({
+            "kty": "OKP",
+            "alg": "EdDSA",
+            "crv": "Ed25519",
+            "x": URL_SAFE_NO_PAD.encode(key),
+        }),
+        _ => return Err(invalid("unsupported key type")),
+    };
+
+    let mut jwk = jwk;
+    jwk["kid"] = kid.into();
+    jwk["use"] = "sig".into();
+
+    Ok(serde_json::from_value(jwk)?)
+}
+
+/// Reads a DER element with the expected tag, returning its tag, its content and the following bytes.
+fn der_element(input: &[u8], expected_tag: u8) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&length, mut rest) = rest.split_first()?;
+
+    if tag != expected_tag {
+        return None;
+    }
+
+    let length = if length & 0x80 == 0 {
+        length as usize
+    } else {
+        let count = (length & 0x7f) as usize;
+
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let (bytes, remaining) = rest.split_at(count);
+        rest = remaining;
+        bytes
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize)
+    };
+
+    if rest.len() < length {
+        return None;
+    }
+    let (content, rest) = rest.split_at(length);
+
+    Some((tag, content, rest))
+}
+
+/// Encodes a DER integer in base64url without its sign byte, as jwks expect.
+fn encode_unsigned(integer: &[u8]) -> String {
+    let start = integer
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(integer.len().saturating_sub(1));
+
+    URL_SAFE_NO_PAD.encode(&integer[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::jwk::AlgorithmParameters;
+    use mockito::mock;
+
+    fn authority(path: &str) -> String {
+        format!("{}/{path}", mockito::server_url())
+    }
+
+    mod get {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_fetch_the_jwks_once() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/cached/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .expect(1)
+                .create();
+            let cache = JwksCache::default();
+
+            cache.get(&authority("cached")).await.unwrap();
+            let jwks = cache.get(&authority("cached/")).await.unwrap();
+
+            assert!(jwks.find("test_kid").is_some());
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn fetch_the_jwks_again_after_max_age() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/expiring/.well-known/jwks.json")
+                .with_status(200)
+                .with_header("cache-control", "public, max-age=0")
+                .with_body(jwks_response)
+                .expect(2)
+                .create();
+            let cache = JwksCache::default();
+
+            cache.get(&authority("expiring")).await.unwrap();
+            cache.get(&authority("expiring")).await.unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn fetch_the_jwks_again_once_invalidated() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/invalidated/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .expect(2)
+                .create();
+            let cache = JwksCache::default();
+
+            cache.get(&authority("invalidated")).await.unwrap();
+            cache.invalidate(&authority("invalidated")).unwrap();
+            cache.get(&authority("invalidated")).await.unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod refresh {
+        use super::*;
+        use mockito::Matcher;
+
+        #[tokio::test]
+        async fn keep_the_cached_jwks_when_not_modified() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let initial = mock("GET", "/conditional/.well-known/jwks.json")
+                .match_header("if-none-match", Matcher::Missing)
+                .with_status(200)
+                .with_header("etag", "\"v1\"")
+                .with_header("last-modified", "Wed, 14 Oct 2026 08:00:00 GMT")
+                .with_body(jwks_response)
+                .expect(1)
+                .create();
+            let not_modified = mock("GET", "/conditional/.well-known/jwks.json")
+                .match_header("if-none-match", "\"v1\"")
+                .match_header("if-modified-since", "Wed, 14 Oct 2026 08:00:00 GMT")
+                .with_status(304)
+                .expect(2)
+                .create();
+            let cache = JwksCache::default();
+            cache.set_min_refresh_interval(Duration::ZERO);
+
+            cache.get(&authority("conditional")).await.unwrap();
+            cache.refresh(&authority("conditional")).await.unwrap();
+            let jwks = cache.refresh(&authority("conditional")).await.unwrap();
+
+            assert!(jwks.find("test_kid").is_some());
+            initial.assert();
+            not_modified.assert();
+        }
+
+        #[tokio::test]
+        async fn serve_the_cached_jwks_within_the_min_refresh_interval() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/throttled/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .expect(1)
+                .create();
+            let cache = JwksCache::default();
+
+            cache.refresh(&authority("throttled")).await.unwrap();
+            let jwks = cache.refresh(&authority("throttled")).await.unwrap();
+
+            assert!(jwks.find("test_kid").is_some());
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_error_responses() {
+            let _unavailable = mock("GET", "/unavailable/.well-known/jwks.json")
+                .with_status(503)
+                .with_body("Service Unavailable")
+                .create();
+            let _missing = mock("GET", "/missing/.well-known/jwks.json")
+                .with_status(404)
+                .with_body(r#"{"keys": []}"#)
+                .create();
+            let cache = JwksCache::default();
+
+            let resp = cache.refresh(&authority("unavailable")).await;
+            match resp {
+                Err(Error::ServerError(503)) => (),
+                _ => panic!("Expected ServerError variant, got: {resp:?}"),
+            }
+
+            let resp = cache.refresh(&authority("missing")).await;
+            match resp {
+                Err(Error::InvalidResponseBody) => (),
+                _ => panic!("Expected InvalidResponseBody variant, got: {resp:?}"),
+            }
+            assert!(cache.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn keep_the_replaced_keys_for_the_grace_period() {
+            let rsa_jwks = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let rotated_jwks = std::fs::read_to_string("tests/data/ec_okp_jwks.json").unwrap();
+            let cache = JwksCache::default();
+            cache
+                .set_grace_period(Duration::from_secs(60))
+                .set_min_refresh_interval(Duration::ZERO);
+            let without_grace_period = JwksCache::default();
+            without_grace_period.set_min_refresh_interval(Duration::ZERO);
+
+            let m = mock("GET", "/rotated/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(rsa_jwks)
+                .create();
+            cache.refresh(&authority("rotated")).await.unwrap();
+            without_grace_period
+                .refresh(&authority("rotated"))
+                .await
+                .unwrap();
+            drop(m);
+
+            let _m = mock("GET", "/rotated/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(rotated_jwks)
+                .create();
+            let jwks = cache.refresh(&authority("rotated")).await.unwrap();
+            let jwks_without_grace_period = without_grace_period
+                .refresh(&authority("rotated"))
+                .await
+                .unwrap();
+
+            assert!(jwks.find("test_ec_kid").is_some());
+            assert!(jwks.find("test_kid").is_some());
+            assert!(jwks_without_grace_period.find("test_ec_kid").is_some());
+            assert!(jwks_without_grace_period.find("test_kid").is_none());
+            assert!(cache
+                .get(&authority("rotated"))
+                .await
+                .unwrap()
+                .find("test_kid")
+                .is_some());
+        }
+    }
+
+    mod spawn_refresher {
+        use super::*;
+
+        #[tokio::test]
+        async fn refresh_the_jwks_in_the_background() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/refreshed/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .expect_at_least(2)
+                .create();
+            let cache: &'static JwksCache = Box::leak(Box::default());
+
+            let refresher =
+                cache.spawn_refresher(&authority("refreshed"), Duration::from_millis(10));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            refresher.abort();
+
+            m.assert();
+            assert!(cache.lock().unwrap().contains_key(&authority("refreshed")));
+        }
+    }
+
+    mod jwk_from_pem {
+        use super::*;
+
+        fn expected_key(file: &str, kid: &str) -> Jwk {
+            jwks_from_file(format!("tests/data/{file}"))
+                .unwrap()
+                .find(kid)
+                .unwrap()
+                .clone()
+        }
+
+        #[test]
+        fn convert_rsa_keys() {
+            let pem = std::fs::read("tests/data/rsa_public_key.pem").unwrap();
+
+            let jwk = jwk_from_pem(&pem, "test_kid").unwrap();
+
+            assert_eq!(jwk.common.key_id.as_deref(), Some("test_kid"));
+            match (
+                jwk.algorithm,
+                expected_key("rsa_jwks.json", "test_kid").algorithm,
+            ) {
+                (AlgorithmParameters::RSA(rsa), AlgorithmParameters::RSA(expected)) => {
+                    assert_eq!(rsa.n, expected.n);
+                    assert_eq!(rsa.e, expected.e);
+                }
+                _ => panic!("Expected RSA keys"),
+            }
+        }
+
+        #[test]
+        fn convert_ec_keys() {
+            let pem = std::fs::read("tests/data/ec_public_key.pem").unwrap();
+
+            let jwk = jwk_from_pem(&pem, "test_ec_kid").unwrap();
+
+            match (
+                jwk.algorithm,
+                expected_key("ec_okp_jwks.json", "test_ec_kid").algorithm,
+            ) {
+                (
+                    AlgorithmParameters::EllipticCurve(ec),
+                    AlgorithmParameters::EllipticCurve(expected),
+                ) => {
+                    assert_eq!(ec.x, expected.x);
+                    assert_eq!(ec.y, expected.y);
+                }
+                _ => panic!("Expected EC keys"),
+            }
+        }
+
+        #[test]
+        fn convert_ed25519_keys() {
+            let pem = std::fs::read("tests/data/ed25519_public_key.pem").unwrap();
+
+            let jwk = jwk_from_pem(&pem, "test_okp_kid").unwrap();
+
+            match (
+                jwk.algorithm,
+                expected_key("ec_okp_jwks.json", "test_okp_kid").algorithm,
+            ) {
+                (
+                    AlgorithmParameters::OctetKeyPair(okp),
+                    AlgorithmParameters::OctetKeyPair(expected),
+                ) => {
+                    assert_eq!(okp.x, expected.x)
+                }
+                _ => panic!("Expected OKP keys"),
+            }
+        }
+
+        #[test]
+        fn reject_private_keys() {
+            let pem = std::fs::read("tests/data/rsa_private_key.pem").unwrap();
+
+            match jwk_from_pem(&pem, "test_kid") {
+                Err(Error::InvalidPublicKey(_)) => (),
+                _ => panic!("Expected InvalidPublicKey variant"),
+            }
+        }
+
+        #[test]
+        fn reject_truncated_keys() {
+            let pem = std::fs::read_to_string("tests/data/rsa_public_key.pem").unwrap();
+            let lines = pem.lines().collect::<Vec<_>>();
+            let truncated = [&lines[..lines.len() - 3], &lines[lines.len() - 1..]].concat();
+            // An RSA key whose modulus is an empty integer.
+            let empty_modulus = STANDARD.encode([
+                0x30, 0x1b, 0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01,
+                0x01, 0x05, 0x00, 0x03, 0x0a, 0x00, 0x30, 0x07, 0x02, 0x00, 0x02, 0x03, 0x01, 0x00,
+                0x01,
+            ]);
+
+            for pem in [
+                truncated.join("\n"),
+                format!("-----BEGIN PUBLIC KEY-----\n{empty_modulus}\n-----END PUBLIC KEY-----"),
+            ] {
+                match jwk_from_pem(pem.as_bytes(), "test_kid") {
+                    Err(Error::InvalidPublicKey(_)) => (),
+                    _ => panic!("Expected InvalidPublicKey variant"),
+                }
+            }
+        }
+    }
+
+    mod preload {
+        use super::*;
+        use crate::authorization::{valid_jwt, Claims};
+        use crate::utils::sign_test_jwt;
+        use jsonwebtoken::{Algorithm, Validation};
+
+        #[tokio::test]
+        async fn validate_tokens_without_fetching_the_jwks() {
+            let jwks = jwks_from_file("tests/data/rsa_jwks.json").unwrap();
+            JwksCache::global()
+                .preload("https://offline.example.com", jwks)
+                .unwrap();
+            let token = sign_test_jwt(&serde_json::json!({ "sub": "sub", "exp": 9999999999u64 }));
+            let mut validation = Validation::new(Algorithm::RS256);
+            validation.validate_aud = false;
+
+            let res =
+                valid_jwt::<Claims>(&token, "https://offline.example.com/", validation, None).await;
+
+            assert!(res.is_ok());
+        }
+    }
+}
@@ -0,0 +1,417 @@
+//! Types and functions relative to logging users out.
+
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, TokenData, Validation};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::authorization::valid_jwt;
+use crate::error::{Auth0Result, Error};
+use crate::utils::URL_REGEX;
+use crate::Auth0Client;
+
+/// The event a back-channel logout token must contain.
+pub const BACKCHANNEL_LOGOUT_EVENT: &str = "http://schemas.openid.net/event/backchannel-logout";
+
+/// A builder for the URLs users are redirected to in order to log out of Auth0.
+///
+/// # Example
+///
+/// ```
+/// # fn logout_url() -> auth0_client::error::Auth0Result<()> {
+/// let client =
+///     auth0_client::Auth0Client::new("client_id", "client_secret", "https://domain.com", "audience");
+///
+/// let url = client
+///     .logout_url()
+///     .return_to("https://app.example.com/")
+///     .allowed_return_urls(&["https://app.example.com/"])
+///     .federated(true)
+///     .build()?;
+///
+/// assert_eq!(
+///     url,
+///     "https://domain.com/v2/logout?client_id=client_id&returnTo=https%3A%2F%2Fapp.example.com%2F&federated"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogoutUrlBuilder {
+    domain: String,
+    client_id: Option<String>,
+    return_to: Option<String>,
+    allowed_return_urls: Vec<String>,
+    federated: bool,
+    oidc: bool,
+    id_token_hint: Option<String>,
+}
+
+impl LogoutUrlBuilder {
+    /// Creates a new builder for the given tenant domain.
+    pub fn new(domain: &str) -> Self {
+        Self {
+            domain: domain.to_owned(),
+            client_id: None,
+            return_to: None,
+            allowed_return_urls: vec![],
+            federated: false,
+            oidc: false,
+            id_token_hint: None,
+        }
+    }
+
+    /// Sets the client ID of the application the user logs out of.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        self.client_id = Some(client_id.to_owned());
+        self
+    }
+
+    /// Sets the URL the user is redirected to after logging out.
+    pub fn return_to(mut self, return_to: &str) -> Self {
+        self.return_to = Some(return_to.to_owned());
+        self
+    }
+
+    /// Sets the URLs `return_to` is checked against when building the URL.
+    ///
+    /// They should mirror the "Allowed Logout URLs" configured on the tenant or the application.
+    /// The query string of `return_to` is ignored during the check.
+    pub fn allowed_return_urls(mut self, allowed_return_urls: &[&str]) -> Self {
+        self.allowed_return_urls = allowed_return_urls.iter().map(|u| u.to_string()).collect();
+        self
+    }
+
+    /// Also logs the user out of the identity provider they used to log in.
+    ///
+    /// Only supported by the `/v2/logout` endpoint.
+    pub fn federated(mut self, federated: bool) -> Self {
+        self.federated = federated;
+        self
+    }
+
+    /// Uses the OIDC `/oidc/logout` endpoint instead of `/v2/logout`.
+    pub fn oidc(mut self, oidc: bool) -> Self {
+        self.oidc = oidc;
+        self
+    }
+
+    /// Sets the ID token previously issued to the user. Only used by the `/oidc/logout` endpoint.
+    pub fn id_token_hint(mut self, id_token_hint: &str) -> Self {
+        self.id_token_hint = Some(id_token_hint.to_owned());
+        self
+    }
+
+    /// Builds the logout URL.
+    ///
+    /// Fails with [`Error::ReturnUrlNotAllowed`] if allowed return URLs are configured
+    /// and `return_to` does not match any of them.
+    pub fn build(&self) -> Auth0Result<String> {
+        if let Some(return_to) = &self.return_to {
+            let without_query = return_to.split('?').next().unwrap_or_default();
+
+            if !self.allowed_return_urls.is_empty()
+                && !self.allowed_return_urls.iter().any(|u| u == without_query)
+            {
+                return Err(Error::ReturnUrlNotAllowed(return_to.clone()));
+            }
+        }
+
+        let mut params = vec![];
+
+        if self.oidc {
+            if let Some(client_id) = &self.client_id {
+                params.push(format!("client_id={}", urlencoding::encode(client_id)));
+            }
+            if let Some(id_token_hint) = &self.id_token_hint {
+                params.push(format!(
+                    "id_token_hint={}",
+                    urlencoding::encode(id_token_hint)
+                ));
+            }
+            if let Some(return_to) = &self.return_to {
+                params.push(format!(
+                    "post_logout_redirect_uri={}",
+                    urlencoding::encode(return_to)
+                ));
+            }
+        } else {
+            if let Some(client_id) = &self.client_id {
+                params.push(format!("client_id={}", urlencoding::encode(client_id)));
+            }
+            if let Some(return_to) = &self.return_to {
+                params.push(format!("returnTo={}", urlencoding::encode(return_to)));
+            }
+            if self.federated {
+                params.push("federated".to_owned());
+            }
+        }
+
+        let path = if self.oidc {
+            "oidc/logout"
+        } else {
+            "v2/logout"
+        };
+        let url = URL_REGEX
+            .replace_all(&format!("{}/{path}", self.domain), "$1")
+            .to_string();
+
+        if params.is_empty() {
+            Ok(url)
+        } else {
+            Ok(format!("{url}?{}", params.join("&")))
+        }
+    }
+}
+
+impl Auth0Client {
+    /// Returns a [`LogoutUrlBuilder`] for the client domain with the client ID already set.
+    pub fn logout_url(&self) -> LogoutUrlBuilder {
+        LogoutUrlBuilder::new(&self.domain).client_id(&self.client_id)
+    }
+}
+
+/// The claims of a back-channel logout token.
+#[derive(Debug, Deserialize)]
+pub struct LogoutTokenClaims {
+    pub iss: String,
+    pub sub: Option<String>,
+    pub sid: Option<String>,
+    pub iat: i64,
+    pub jti: String,
+    pub events: HashMap<String, serde_json::Value>,
+    nonce: Option<String>,
+}
+
+/// Validates a back-channel logout token sent by Auth0 and returns its decoded payload.
+///
+/// On top of the signature, issuer and audience, it checks the token contains the
+/// back-channel logout event, a `sub` or a `sid` and no `nonce` as required by the specification.
+///
+/// # Arguments
+///
+/// * `token` - The `logout_token` received on the back-channel logout endpoint.
+/// * `authority` - The authority to retreive the jwks from, which must match the `iss` claim.
+/// * `client_id` - The client ID of the application, which must match the `aud` claim.
+/// * `jwks` - The jwks to use instead of fetching them, if any.
+///
+/// # Example
+/// ```
+/// # async fn validate_logout_token() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::logout::valid_logout_token;
+/// let (token, _) = valid_logout_token(
+///     "...logout_token...",
+///     "https://domain.com/",
+///     "client_id",
+///     None,
+/// ).await?;
+///
+/// println!("Session {:?} was logged out", token.claims.sid);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn valid_logout_token(
+    token: &str,
+    authority: &str,
+    client_id: &str,
+    jwks: Option<&JwkSet>,
+) -> Auth0Result<(TokenData<LogoutTokenClaims>, JwkSet)> {
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.set_audience(&[client_id]);
+    validation.set_issuer(&[authority]);
+    validation.set_required_spec_claims(&["iss", "aud"]);
+
+    let (token, jwks) = valid_jwt::<LogoutTokenClaims>(token, authority, validation, jwks).await?;
+    let claims = &token.claims;
+
+    if !claims.events.contains_key(BACKCHANNEL_LOGOUT_EVENT) {
+        return Err(Error::InvalidLogoutToken(
+            "missing back-channel logout event".to_owned(),
+        ));
+    }
+    if claims.sub.is_none() && claims.sid.is_none() {
+        return Err(Error::InvalidLogoutToken("missing sub and sid".to_owned()));
+    }
+    if claims.nonce.is_some() {
+        return Err(Error::InvalidLogoutToken("unexpected nonce".to_owned()));
+    }
+
+    Ok((token, jwks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::sign_test_jwt;
+    use mockito::{mock, Mock};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            "https://domain.com/",
+            "https://audience.com",
+        )
+    }
+
+    mod build {
+        use super::*;
+
+        #[test]
+        fn build_a_v2_logout_url() {
+            let url = new_client()
+                .logout_url()
+                .return_to("https://app.example.com/?from=logout")
+                .federated(true)
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/v2/logout?client_id=client_id&returnTo=https%3A%2F%2Fapp.example.com%2F%3Ffrom%3Dlogout&federated"
+            );
+        }
+
+        #[test]
+        fn build_an_oidc_logout_url() {
+            let url = new_client()
+                .logout_url()
+                .oidc(true)
+                .id_token_hint("id_token")
+                .return_to("https://app.example.com/")
+                .federated(true)
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                url,
+                "https://domain.com/oidc/logout?client_id=client_id&id_token_hint=id_token&post_logout_redirect_uri=https%3A%2F%2Fapp.example.com%2F"
+            );
+        }
+
+        #[test]
+        fn build_a_url_without_parameters() {
+            let url = LogoutUrlBuilder::new("https://domain.com").build().unwrap();
+
+            assert_eq!(url, "https://domain.com/v2/logout");
+        }
+
+        #[test]
+        fn accept_allowed_return_urls() {
+            let url = new_client()
+                .logout_url()
+                .return_to("https://app.example.com/?from=logout")
+                .allowed_return_urls(&["https://other.example.com/", "https://app.example.com/"])
+                .build();
+
+            assert!(url.is_ok());
+        }
+
+        #[test]
+        fn reject_return_urls_which_are_not_allowed() {
+            let url = new_client()
+                .logout_url()
+                .return_to("https://evil.example.com/")
+                .allowed_return_urls(&["https://app.example.com/"])
+                .build();
+
+            match url {
+                Err(Error::ReturnUrlNotAllowed(url)) => {
+                    assert_eq!(url, "https://evil.example.com/")
+                }
+                _ => panic!("Expected ReturnUrlNotAllowed variant, got: {url:?}"),
+            }
+        }
+    }
+
+    mod valid_logout_token {
+        use super::*;
+
+        fn jwks_mock() -> Mock {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+
+            mock("GET", "/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .create()
+        }
+
+        fn logout_token(extra: serde_json::Value) -> String {
+            let mut claims = json!({
+                "iss": mockito::server_url(),
+                "aud": "client_id",
+                "iat": 1673537441,
+                "jti": "jti",
+                "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                "sid": "sid",
+                "events": { BACKCHANNEL_LOGOUT_EVENT: {} }
+            });
+            for (key, value) in extra.as_object().unwrap() {
+                claims[key] = value.clone();
+            }
+            sign_test_jwt(&claims)
+        }
+
+        async fn validate(token: &str) -> Auth0Result<(TokenData<LogoutTokenClaims>, JwkSet)> {
+            valid_logout_token(token, &mockito::server_url(), "client_id", None).await
+        }
+
+        #[tokio::test]
+        async fn validate_valid_logout_token() {
+            let _m = jwks_mock();
+
+            let (token, _) = validate(&logout_token(json!({}))).await.unwrap();
+
+            assert_eq!(token.claims.sid, Some("sid".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn errored_with_missing_event() {
+            let _m = jwks_mock();
+
+            let res = validate(&logout_token(json!({ "events": {} }))).await;
+
+            match res {
+                Err(Error::InvalidLogoutToken(_)) => (),
+                _ => panic!("Expected InvalidLogoutToken variant, got: {res:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_with_missing_sub_and_sid() {
+            let _m = jwks_mock();
+
+            let res = validate(&logout_token(json!({ "sub": null, "sid": null }))).await;
+
+            match res {
+                Err(Error::InvalidLogoutToken(_)) => (),
+                _ => panic!("Expected InvalidLogoutToken variant, got: {res:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_with_nonce() {
+            let _m = jwks_mock();
+
+            let res = validate(&logout_token(json!({ "nonce": "nonce" }))).await;
+
+            match res {
+                Err(Error::InvalidLogoutToken(_)) => (),
+                _ => panic!("Expected InvalidLogoutToken variant, got: {res:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_with_another_audience() {
+            let _m = jwks_mock();
+
+            let res = validate(&logout_token(json!({ "aud": "another_client_id" }))).await;
+
+            match res {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {res:?}"),
+            }
+        }
+    }
+}
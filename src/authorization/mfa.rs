@@ -0,0 +1,871 @@
+//! Types, traits and functions relative to multi-factor authentication.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::authorization::{AccessTokenResponse, Authenticatable};
+use crate::error::{Auth0Result, Error};
+use crate::{Auth0Client, GrantType};
+
+/// Trait for completing a multi-factor authentication.
+#[async_trait]
+pub trait Mfa {
+    /// Requests an OTP challenge for the user the `mfa_token` was issued for.
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn challenge(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let challenge = client.mfa_challenge("mfa_token").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_challenge(&mut self, mfa_token: &str) -> Auth0Result<MfaChallengeResponse>;
+
+    /// Exchanges the OTP given by the user's authenticator app for tokens.
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    /// * `otp` - The one-time password given by the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn verify(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let tokens = client.mfa_verify_otp("mfa_token", "123456").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_verify_otp(
+        &mut self,
+        mfa_token: &str,
+        otp: &str,
+    ) -> Auth0Result<AccessTokenResponse>;
+
+    /// Requests an out-of-band challenge (push notification, SMS, voice or email).
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    /// * `authenticator_id` - The authenticator to challenge. Auth0 picks one if `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn challenge(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let challenge = client.mfa_challenge_oob("mfa_token", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_challenge_oob(
+        &mut self,
+        mfa_token: &str,
+        authenticator_id: Option<&str>,
+    ) -> Auth0Result<MfaChallengeResponse>;
+
+    /// Exchanges an out-of-band code for tokens once.
+    ///
+    /// For SMS, voice and email challenges, `binding_code` is the code received by the user.
+    /// If the user has not approved the challenge yet, an
+    /// [`AuthenticationError::AuthorizationPending`](crate::authorization::AuthenticationError::AuthorizationPending)
+    /// error is returned.
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    /// * `oob_code` - The code returned by [`Mfa::mfa_challenge_oob`].
+    /// * `binding_code` - The code received by the user, if any.
+    async fn mfa_verify_oob(
+        &mut self,
+        mfa_token: &str,
+        oob_code: &str,
+        binding_code: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse>;
+
+    /// Polls the token endpoint with an out-of-band code until the user approves the challenge.
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    /// * `oob_code` - The code returned by [`Mfa::mfa_challenge_oob`].
+    /// * `interval` - The number of seconds to wait between each attempt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn poll(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let challenge = client.mfa_challenge_oob("mfa_token", None).await?;
+    /// let oob_code = challenge.oob_code.unwrap_or_default();
+    /// let tokens = client.mfa_poll_oob("mfa_token", &oob_code, 5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_poll_oob(
+        &mut self,
+        mfa_token: &str,
+        oob_code: &str,
+        interval: u64,
+    ) -> Auth0Result<AccessTokenResponse>;
+
+    /// Exchanges a recovery code for tokens.
+    ///
+    /// Auth0 invalidates the used recovery code and returns a new one which should be shown to the user.
+    ///
+    /// # Arguments
+    /// * `mfa_token` - The token returned by Auth0 along with the `mfa_required` error.
+    /// * `recovery_code` - The recovery code given by the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn recover(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let resp = client
+    ///     .mfa_verify_recovery_code("mfa_token", "MDZ9GE8TQ3JDXE2FKU887V4D")
+    ///     .await?;
+    ///
+    /// println!("Your new recovery code is {}", resp.recovery_code.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_verify_recovery_code(
+        &mut self,
+        mfa_token: &str,
+        recovery_code: &str,
+    ) -> Auth0Result<MfaRecoveryCodeResponse>;
+
+    /// Enrolls a new authenticator for the user the token was issued for.
+    ///
+    /// # Arguments
+    /// * `token` - The `mfa_token` or an access token with the `enroll` scope for the `/mfa/` audience.
+    /// * `payload` - A struct describing the authenticator to enroll.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn associate(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::{Mfa, MfaAssociatePayload, MfaAssociateResponse};
+    /// let resp = client
+    ///     .mfa_associate("mfa_token", &MfaAssociatePayload::otp())
+    ///     .await?;
+    ///
+    /// if let MfaAssociateResponse::Otp { barcode_uri, .. } = resp {
+    ///     println!("Scan {barcode_uri} with your authenticator app");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn mfa_associate(
+        &mut self,
+        token: &str,
+        payload: &MfaAssociatePayload,
+    ) -> Auth0Result<MfaAssociateResponse>;
+
+    /// Lists the authenticators enrolled by the user the token was issued for.
+    ///
+    /// # Arguments
+    /// * `token` - An access token with the `read:authenticators` scope for the `/mfa/` audience.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn list(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// let authenticators = client.list_mfa_authenticators("access_token").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_mfa_authenticators(&mut self, token: &str) -> Auth0Result<Vec<Authenticator>>;
+
+    /// Deletes an authenticator enrolled by the user the token was issued for.
+    ///
+    /// # Arguments
+    /// * `token` - An access token with the `remove:authenticators` scope for the `/mfa/` audience.
+    /// * `authenticator_id` - The ID of the authenticator to delete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn delete(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::mfa::Mfa;
+    /// client
+    ///     .delete_mfa_authenticator("access_token", "totp|dev_6NWz8awwC8brh2dN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn delete_mfa_authenticator(
+        &mut self,
+        token: &str,
+        authenticator_id: &str,
+    ) -> Auth0Result<()>;
+}
+
+/// The type of a multi-factor authentication challenge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MfaChallengeType {
+    Otp,
+    Oob,
+}
+
+/// The response we get when we request a multi-factor authentication challenge.
+#[derive(Debug, Deserialize, Clone)]
+pub struct MfaChallengeResponse {
+    pub challenge_type: MfaChallengeType,
+    pub oob_code: Option<String>,
+    pub binding_method: Option<String>,
+}
+
+/// The response we get when we authenticate with a recovery code.
+#[derive(Deserialize)]
+pub struct MfaRecoveryCodeResponse {
+    #[serde(flatten)]
+    pub tokens: AccessTokenResponse,
+    pub recovery_code: Option<String>,
+}
+
+/// The type of an authenticator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthenticatorType {
+    Otp,
+    Oob,
+    RecoveryCode,
+    WebauthnRoaming,
+    WebauthnPlatform,
+}
+
+/// The channel used by an out-of-band authenticator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OobChannel {
+    Sms,
+    Voice,
+    Email,
+    Auth0,
+}
+
+/// A struct containing the payload for enrolling an authenticator.
+#[derive(Serialize)]
+pub struct MfaAssociatePayload {
+    pub authenticator_types: Vec<AuthenticatorType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oob_channels: Option<Vec<OobChannel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+/// The response we get when we enroll an authenticator.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "authenticator_type", rename_all = "kebab-case")]
+pub enum MfaAssociateResponse {
+    /// The secret to register in an authenticator app, also encoded in `barcode_uri`.
+    Otp {
+        secret: String,
+        barcode_uri: String,
+        recovery_codes: Option<Vec<String>>,
+    },
+    /// The `oob_code` to use with [`Mfa::mfa_verify_oob`] to confirm the enrollment.
+    Oob {
+        oob_channel: OobChannel,
+        oob_code: String,
+        binding_method: Option<String>,
+        recovery_codes: Option<Vec<String>>,
+    },
+    /// The challenge data to pass to `navigator.credentials.create()`.
+    WebauthnRoaming {
+        #[serde(flatten)]
+        challenge: HashMap<String, serde_json::Value>,
+    },
+    /// The challenge data to pass to `navigator.credentials.create()`.
+    WebauthnPlatform {
+        #[serde(flatten)]
+        challenge: HashMap<String, serde_json::Value>,
+    },
+}
+
+/// A struct containing an authenticator enrolled by a user.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Authenticator {
+    pub id: String,
+    pub authenticator_type: AuthenticatorType,
+    pub active: bool,
+    pub oob_channel: Option<OobChannel>,
+    pub name: Option<String>,
+}
+
+#[async_trait]
+impl Mfa for Auth0Client {
+    async fn mfa_challenge(&mut self, mfa_token: &str) -> Auth0Result<MfaChallengeResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("mfa_token", mfa_token.to_owned());
+            body.insert("challenge_type", MfaChallengeType::Otp.to_string());
+            body
+        };
+
+        self.authentication_request(Method::POST, "/mfa/challenge", None, Some(body))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn mfa_verify_otp(
+        &mut self,
+        mfa_token: &str,
+        otp: &str,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::MfaOtp.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("mfa_token", mfa_token.to_owned());
+            body.insert("otp", otp.to_owned());
+            body
+        };
+
+        self.authenticate_with_body(body).await
+    }
+
+    async fn mfa_challenge_oob(
+        &mut self,
+        mfa_token: &str,
+        authenticator_id: Option<&str>,
+    ) -> Auth0Result<MfaChallengeResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("mfa_token", mfa_token.to_owned());
+            body.insert("challenge_type", MfaChallengeType::Oob.to_string());
+            if let Some(authenticator_id) = authenticator_id {
+                body.insert("authenticator_id", authenticator_id.to_owned());
+            }
+            body
+        };
+
+        self.authentication_request(Method::POST, "/mfa/challenge", None, Some(body))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn mfa_verify_oob(
+        &mut self,
+        mfa_token: &str,
+        oob_code: &str,
+        binding_code: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = self.mfa_oob_body(mfa_token, oob_code, binding_code)?;
+
+        self.authenticate_with_body(body).await
+    }
+
+    async fn mfa_poll_oob(
+        &mut self,
+        mfa_token: &str,
+        oob_code: &str,
+        interval: u64,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = self.mfa_oob_body(mfa_token, oob_code, None)?;
+
+        self.poll_token(body, interval, None).await
+    }
+
+    async fn mfa_verify_recovery_code(
+        &mut self,
+        mfa_token: &str,
+        recovery_code: &str,
+    ) -> Auth0Result<MfaRecoveryCodeResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::MfaRecoveryCode.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("mfa_token", mfa_token.to_owned());
+            body.insert("recovery_code", recovery_code.to_owned());
+            body
+        };
+
+        let mut response: MfaRecoveryCodeResponse = self.token_request(body).await?;
+
+        self.bind_to_dpop_key(&mut response.tokens);
+        Ok(response)
+    }
+
+    async fn mfa_associate(
+        &mut self,
+        token: &str,
+        payload: &MfaAssociatePayload,
+    ) -> Auth0Result<MfaAssociateResponse> {
+        self.authentication_request(Method::POST, "/mfa/associate", Some(token), Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_mfa_authenticators(&mut self, token: &str) -> Auth0Result<Vec<Authenticator>> {
+        self.authentication_request(
+            Method::GET,
+            "/mfa/authenticators",
+            Some(token),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_mfa_authenticator(
+        &mut self,
+        token: &str,
+        authenticator_id: &str,
+    ) -> Auth0Result<()> {
+        self.authentication_request::<_, IgnoredAny>(
+            Method::DELETE,
+            &format!(
+                "/mfa/authenticators/{}",
+                urlencoding::encode(authenticator_id)
+            ),
+            Some(token),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+impl MfaAssociatePayload {
+    /// Returns a payload to enroll an authenticator app (TOTP).
+    pub fn otp() -> Self {
+        Self::from_authenticator_type(AuthenticatorType::Otp)
+    }
+
+    /// Returns a payload to enroll a phone number receiving codes by SMS.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - The phone number to enroll.
+    pub fn sms(phone_number: &str) -> Self {
+        let mut payload = Self::from_authenticator_type(AuthenticatorType::Oob);
+        payload.oob_channels = Some(vec![OobChannel::Sms]);
+        payload.phone_number = Some(phone_number.to_owned());
+        payload
+    }
+
+    /// Returns a payload to enroll a security key (WebAuthn roaming authenticator).
+    pub fn webauthn_roaming() -> Self {
+        Self::from_authenticator_type(AuthenticatorType::WebauthnRoaming)
+    }
+
+    /// Returns a payload to enroll the device biometrics (WebAuthn platform authenticator).
+    pub fn webauthn_platform() -> Self {
+        Self::from_authenticator_type(AuthenticatorType::WebauthnPlatform)
+    }
+
+    /// Returns an empty payload with only `authenticator_types` field set.
+    ///
+    /// # Arguments
+    ///
+    /// * `authenticator_type` - The type of authenticator to enroll.
+    pub fn from_authenticator_type(authenticator_type: AuthenticatorType) -> Self {
+        Self {
+            authenticator_types: vec![authenticator_type],
+            oob_channels: None,
+            phone_number: None,
+            email: None,
+        }
+    }
+}
+
+impl Auth0Client {
+    /// Builds the token request body for the `mfa-oob` grant.
+    fn mfa_oob_body(
+        &self,
+        mfa_token: &str,
+        oob_code: &str,
+        binding_code: Option<&str>,
+    ) -> Auth0Result<HashMap<&'static str, String>> {
+        let mut body = HashMap::new();
+
+        body.insert("grant_type", GrantType::MfaOob.to_string());
+        body.insert("client_id", self.client_id.clone());
+        body.extend(self.client_authentication()?);
+        body.insert("mfa_token", mfa_token.to_owned());
+        body.insert("oob_code", oob_code.to_owned());
+        if let Some(binding_code) = binding_code {
+            body.insert("binding_code", binding_code.to_owned());
+        }
+        Ok(body)
+    }
+}
+
+impl Display for MfaChallengeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MfaChallengeType::Otp => write!(f, "otp"),
+            MfaChallengeType::Oob => write!(f, "oob"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::AuthenticationError;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod mfa_challenge {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/mfa/challenge")
+                .match_body(Matcher::PartialJson(json!({
+                    "mfa_token": "mfa_token",
+                    "challenge_type": "otp"
+                })))
+                .with_status(200)
+                .with_body(json!({ "challenge_type": "otp" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.mfa_challenge("mfa_token").await.unwrap();
+
+            assert_eq!(resp.challenge_type, MfaChallengeType::Otp);
+        }
+    }
+
+    mod mfa_verify_otp {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "http://auth0.com/oauth/grant-type/mfa-otp",
+                    "mfa_token": "mfa_token",
+                    "otp": "123456"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.mfa_verify_otp("mfa_token", "123456").await.unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+    }
+
+    mod mfa_challenge_oob {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/mfa/challenge")
+                .match_body(Matcher::PartialJson(json!({
+                    "challenge_type": "oob",
+                    "authenticator_id": "sms|dev_NU1Ofuw3Cw0XCt5x"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "challenge_type": "oob",
+                        "binding_method": "prompt",
+                        "oob_code": "abcd1234"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_challenge_oob("mfa_token", Some("sms|dev_NU1Ofuw3Cw0XCt5x"))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.challenge_type, MfaChallengeType::Oob);
+            assert_eq!(resp.oob_code, Some("abcd1234".to_owned()));
+            assert_eq!(resp.binding_method, Some("prompt".to_owned()));
+        }
+    }
+
+    mod mfa_verify_oob {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_authorization_pending() {
+            let _m = mock("POST", "/oauth/token")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "error": "authorization_pending",
+                        "error_description": "Authorization pending: please repeat the request in a few seconds."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.mfa_verify_oob("mfa_token", "abcd1234", None).await;
+
+            match resp {
+                Err(Error::Authentication(AuthenticationError::AuthorizationPending)) => (),
+                _ => panic!("Expected AuthorizationPending variant"),
+            }
+        }
+    }
+
+    mod mfa_poll_oob {
+        use super::*;
+
+        #[tokio::test]
+        async fn retry_while_authorization_is_pending() {
+            let _pending = mock("POST", "/oauth/token")
+                .with_status(400)
+                .with_body(json!({ "error": "authorization_pending" }).to_string())
+                .expect(2)
+                .create();
+            let _success = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "http://auth0.com/oauth/grant-type/mfa-oob",
+                    "oob_code": "abcd1234"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_poll_oob("mfa_token", "abcd1234", 0)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+    }
+
+    mod mfa_verify_recovery_code {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_new_recovery_code() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "http://auth0.com/oauth/grant-type/mfa-recovery-code",
+                    "recovery_code": "MDZ9GE8TQ3JDXE2FKU887V4D"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "access_token": "access_token",
+                        "token_type": "Bearer",
+                        "recovery_code": "BX6YP5HHXQWB9ZTT3LQGZKB5"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_verify_recovery_code("mfa_token", "MDZ9GE8TQ3JDXE2FKU887V4D")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.tokens.access_token, "access_token");
+            assert_eq!(
+                resp.recovery_code,
+                Some("BX6YP5HHXQWB9ZTT3LQGZKB5".to_owned())
+            );
+        }
+    }
+
+    mod mfa_associate {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_otp_secret() {
+            let _m = mock("POST", "/mfa/associate")
+                .match_header("Authorization", "Bearer mfa_token")
+                .match_body(Matcher::Json(json!({ "authenticator_types": ["otp"] })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "authenticator_type": "otp",
+                        "secret": "EN...S",
+                        "barcode_uri": "otpauth://totp/tenant:user?secret=EN...S&issuer=tenant&algorithm=SHA1&digits=6&period=30",
+                        "recovery_codes": ["N3B...XC"]
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_associate("mfa_token", &MfaAssociatePayload::otp())
+                .await
+                .unwrap();
+
+            match resp {
+                MfaAssociateResponse::Otp {
+                    secret,
+                    recovery_codes,
+                    ..
+                } => {
+                    assert_eq!(secret, "EN...S");
+                    assert_eq!(recovery_codes, Some(vec!["N3B...XC".to_owned()]));
+                }
+                _ => panic!("Expected Otp variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn return_the_sms_oob_code() {
+            let _m = mock("POST", "/mfa/associate")
+                .match_body(Matcher::Json(json!({
+                    "authenticator_types": ["oob"],
+                    "oob_channels": ["sms"],
+                    "phone_number": "+33612345678"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "authenticator_type": "oob",
+                        "binding_method": "prompt",
+                        "oob_channel": "sms",
+                        "oob_code": "ata...i0i"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_associate("mfa_token", &MfaAssociatePayload::sms("+33612345678"))
+                .await
+                .unwrap();
+
+            match resp {
+                MfaAssociateResponse::Oob {
+                    oob_channel,
+                    oob_code,
+                    ..
+                } => {
+                    assert_eq!(oob_channel, OobChannel::Sms);
+                    assert_eq!(oob_code, "ata...i0i");
+                }
+                _ => panic!("Expected Oob variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn return_the_webauthn_challenge() {
+            let _m = mock("POST", "/mfa/associate")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "authenticator_type": "webauthn-roaming",
+                        "challenge": "dGVzdA",
+                        "rp": { "id": "tenant.auth0.com", "name": "tenant" }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .mfa_associate("mfa_token", &MfaAssociatePayload::webauthn_roaming())
+                .await
+                .unwrap();
+
+            match resp {
+                MfaAssociateResponse::WebauthnRoaming { challenge } => {
+                    assert_eq!(challenge["challenge"], json!("dGVzdA"));
+                }
+                _ => panic!("Expected WebauthnRoaming variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod list_mfa_authenticators {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/mfa/authenticators")
+                .match_header("Authorization", "Bearer access_token")
+                .with_status(200)
+                .with_body(
+                    json!([
+                        {
+                            "id": "recovery-code|dev_DsvzGfZw2Fg5N3rI",
+                            "authenticator_type": "recovery-code",
+                            "active": true
+                        },
+                        {
+                            "id": "sms|dev_gB342kcL2K22S4yB",
+                            "authenticator_type": "oob",
+                            "oob_channel": "sms",
+                            "name": "+X XXXX1234",
+                            "active": true
+                        }
+                    ])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_mfa_authenticators("access_token")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.len(), 2);
+            assert_eq!(resp[0].authenticator_type, AuthenticatorType::RecoveryCode);
+            assert_eq!(resp[1].oob_channel, Some(OobChannel::Sms));
+        }
+    }
+
+    mod delete_mfa_authenticator {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("DELETE", "/mfa/authenticators/totp%7Cdev_6NWz8awwC8brh2dN")
+                .match_header("Authorization", "Bearer access_token")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_mfa_authenticator("access_token", "totp|dev_6NWz8awwC8brh2dN")
+                .await
+                .unwrap();
+        }
+    }
+}
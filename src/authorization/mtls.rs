@@ -0,0 +1,148 @@
+//! Types, traits and functions relative to mutual TLS client authentication (RFC 8705).
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use reqwest::{Client as ReqwestClient, Identity};
+use ring::digest::{digest, SHA256};
+use serde::Deserialize;
+
+use crate::error::Auth0Result;
+use crate::Auth0Client;
+
+/// The confirmation (`cnf`) claim of a sender-constrained access token.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Confirmation {
+    /// The base64url encoded SHA-256 thumbprint of the certificate the token is bound to.
+    #[serde(rename = "x5t#S256")]
+    pub x5t_s256: Option<String>,
+    /// The thumbprint of the DPoP key the token is bound to.
+    pub jkt: Option<String>,
+}
+
+impl Auth0Client {
+    /// Sets the TLS identity presented to Auth0 and authenticates the client with it.
+    ///
+    /// The client is then authenticated through `self_signed_tls_client_auth`: the client
+    /// secret is no longer sent to the authentication API. The `domain` of the client should
+    /// be the mTLS endpoint alias of the tenant (e.g. `https://mtls.auth.example.com`).
+    ///
+    /// # Arguments
+    ///
+    /// * `pem` - A PEM encoded certificate followed by its PEM encoded private key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn set_identity(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// let pem = std::fs::read("tests/data/tls_identity.pem").unwrap();
+    ///
+    /// client.set_tls_identity(&pem)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_tls_identity(&mut self, pem: &[u8]) -> Auth0Result<()> {
+        let identity = Identity::from_pem(pem)?;
+
+        self.http_client = ReqwestClient::builder().identity(identity).build()?;
+        self.tls_client_auth = true;
+        Ok(())
+    }
+}
+
+impl Confirmation {
+    /// Checks that the token is bound to the given certificate.
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate` - The DER encoded certificate presented by the caller.
+    pub fn is_bound_to(&self, certificate: &[u8]) -> bool {
+        let thumbprint = URL_SAFE_NO_PAD.encode(digest(&SHA256, certificate));
+
+        self.x5t_s256.as_deref() == Some(thumbprint.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::{valid_jwt, Claims};
+    use crate::utils::sign_test_jwt;
+    use jsonwebtoken::{Algorithm, Validation};
+    use mockito::mock;
+    use serde_json::json;
+
+    const THUMBPRINT: &str = "2w_TGj1DQcOnWUx3bOV0peQIwUptu_if-y8g2Cl4RYI";
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod set_tls_identity {
+        use super::*;
+
+        #[test]
+        fn stop_sending_the_client_secret() {
+            let mut client = new_client();
+            let pem = std::fs::read("tests/data/tls_identity.pem").unwrap();
+
+            client.set_tls_identity(&pem).unwrap();
+            let params = client.client_authentication().unwrap();
+
+            assert!(params.is_empty());
+        }
+
+        #[test]
+        fn reject_invalid_identities() {
+            let mut client = new_client();
+
+            assert!(client.set_tls_identity(b"not a pem").is_err());
+        }
+    }
+
+    mod is_bound_to {
+        use super::*;
+
+        #[test]
+        fn match_the_certificate_thumbprint() {
+            let certificate = std::fs::read("tests/data/tls_certificate.der").unwrap();
+            let cnf = Confirmation {
+                x5t_s256: Some(THUMBPRINT.to_owned()),
+                jkt: None,
+            };
+
+            assert!(cnf.is_bound_to(&certificate));
+            assert!(!cnf.is_bound_to(b"another certificate"));
+        }
+    }
+
+    mod valid_jwt {
+        use super::*;
+
+        #[tokio::test]
+        async fn expose_the_confirmation_claim() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let _jwks = mock("GET", "/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .create();
+            let token = sign_test_jwt(&json!({
+                "aud": "https://audience.com",
+                "exp": 9999999999u64,
+                "cnf": { "x5t#S256": THUMBPRINT }
+            }));
+            let mut validation = Validation::new(Algorithm::RS256);
+            validation.set_audience(&["https://audience.com"]);
+
+            let (token, _) = valid_jwt::<Claims>(&token, &mockito::server_url(), validation, None)
+                .await
+                .unwrap();
+
+            assert_eq!(token.claims.cnf.unwrap().x5t_s256.unwrap(), THUMBPRINT);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Types, traits and functions relative to passwordless authentication.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::authorization::{AccessTokenResponse, Authenticatable};
+use crate::error::Auth0Result;
+use crate::{Auth0Client, GrantType};
+
+/// Trait for authenticating a user without a password.
+#[async_trait]
+pub trait Passwordless {
+    /// Sends a passwordless link or code to the given email.
+    ///
+    /// # Arguments
+    /// * `email` - The email to send the link or code to.
+    /// * `send` - Whether to send a link or a code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn start(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::passwordless::{Passwordless, PasswordlessSend};
+    /// client
+    ///     .start_passwordless_email("test@example.com", PasswordlessSend::Code)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn start_passwordless_email(
+        &mut self,
+        email: &str,
+        send: PasswordlessSend,
+    ) -> Auth0Result<()>;
+
+    /// Sends a passwordless code to the given phone number.
+    ///
+    /// # Arguments
+    /// * `phone_number` - The phone number to send the code to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn start(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::passwordless::Passwordless;
+    /// client.start_passwordless_sms("+33612345678").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn start_passwordless_sms(&mut self, phone_number: &str) -> Auth0Result<()>;
+
+    /// Exchanges the code received by the user for tokens.
+    ///
+    /// # Arguments
+    /// * `connection` - The connection the code was sent through.
+    /// * `username` - The email or phone number the code was sent to.
+    /// * `otp` - The code received by the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn verify(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::passwordless::{Passwordless, PasswordlessConnection};
+    /// let tokens = client
+    ///     .verify_passwordless_otp(PasswordlessConnection::Email, "test@example.com", "123456")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn verify_passwordless_otp(
+        &mut self,
+        connection: PasswordlessConnection,
+        username: &str,
+        otp: &str,
+    ) -> Auth0Result<AccessTokenResponse>;
+}
+
+/// The passwordless connection to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordlessConnection {
+    Email,
+    Sms,
+}
+
+/// What to send to the user when starting a passwordless authentication by email.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordlessSend {
+    Link,
+    Code,
+}
+
+#[async_trait]
+impl Passwordless for Auth0Client {
+    async fn start_passwordless_email(
+        &mut self,
+        email: &str,
+        send: PasswordlessSend,
+    ) -> Auth0Result<()> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("connection", PasswordlessConnection::Email.to_string());
+            body.insert("email", email.to_owned());
+            body.insert("send", send.to_string());
+            body
+        };
+
+        self.authentication_request::<_, IgnoredAny>(
+            Method::POST,
+            "/passwordless/start",
+            None,
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn start_passwordless_sms(&mut self, phone_number: &str) -> Auth0Result<()> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("connection", PasswordlessConnection::Sms.to_string());
+            body.insert("phone_number", phone_number.to_owned());
+            body
+        };
+
+        self.authentication_request::<_, IgnoredAny>(
+            Method::POST,
+            "/passwordless/start",
+            None,
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn verify_passwordless_otp(
+        &mut self,
+        connection: PasswordlessConnection,
+        username: &str,
+        otp: &str,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::PasswordlessOtp.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.extend(self.client_authentication()?);
+            body.insert("audience", self.audience.clone());
+            body.insert("realm", connection.to_string());
+            body.insert("username", username.to_owned());
+            body.insert("otp", otp.to_owned());
+            body
+        };
+
+        self.authenticate_with_body(body).await
+    }
+}
+
+impl Display for PasswordlessConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordlessConnection::Email => write!(f, "email"),
+            PasswordlessConnection::Sms => write!(f, "sms"),
+        }
+    }
+}
+
+impl Display for PasswordlessSend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordlessSend::Link => write!(f, "link"),
+            PasswordlessSend::Code => write!(f, "code"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod start_passwordless_email {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/passwordless/start")
+                .match_body(Matcher::PartialJson(json!({
+                    "connection": "email",
+                    "email": "test@example.com",
+                    "send": "code"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "_id": "5e1c6b1e1e1c6b1e1e1c6b1e",
+                        "email": "test@example.com",
+                        "email_verified": false
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            client
+                .start_passwordless_email("test@example.com", PasswordlessSend::Code)
+                .await
+                .unwrap();
+        }
+    }
+
+    mod start_passwordless_sms {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/passwordless/start")
+                .match_body(Matcher::PartialJson(json!({
+                    "connection": "sms",
+                    "phone_number": "+33612345678"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "_id": "5e1c6b1e1e1c6b1e1e1c6b1e",
+                        "phone_number": "+33612345678",
+                        "phone_verified": false
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            client.start_passwordless_sms("+33612345678").await.unwrap();
+        }
+    }
+
+    mod verify_passwordless_otp {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "http://auth0.com/oauth/grant-type/passwordless/otp",
+                    "realm": "sms",
+                    "username": "+33612345678",
+                    "otp": "123456"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .verify_passwordless_otp(PasswordlessConnection::Sms, "+33612345678", "123456")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Types, traits and functions relative to the OIDC dynamic client registration.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::Auth0Client;
+
+/// Trait for registering OIDC clients on a tenant with dynamic client registration enabled.
+#[async_trait]
+pub trait DynamicRegistration {
+    /// Registers a new client through the `/oidc/register` endpoint.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the metadata of the client to register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn register(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::registration::{ClientRegistrationPayload, DynamicRegistration};
+    /// let payload = ClientRegistrationPayload::new(
+    ///     "My Dynamic Application",
+    ///     &["https://app.example.com/callback"],
+    /// );
+    ///
+    /// let registered = client.register_client(&payload).await?;
+    /// println!("Registered {}", registered.client_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn register_client(
+        &mut self,
+        payload: &ClientRegistrationPayload,
+    ) -> Auth0Result<ClientRegistrationResponse>;
+}
+
+/// A struct containing the payload for registering a client.
+#[derive(Debug, Serialize, Clone)]
+pub struct ClientRegistrationPayload {
+    pub client_name: String,
+    pub redirect_uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<String>>,
+}
+
+/// The response we get when we register a client.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ClientRegistrationResponse {
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub client_name: String,
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+    pub token_endpoint_auth_method: Option<String>,
+    /// The time at which the client secret expires, `0` meaning it never does.
+    pub client_secret_expires_at: Option<i64>,
+    pub client_id_issued_at: Option<i64>,
+}
+
+#[async_trait]
+impl DynamicRegistration for Auth0Client {
+    async fn register_client(
+        &mut self,
+        payload: &ClientRegistrationPayload,
+    ) -> Auth0Result<ClientRegistrationResponse> {
+        self.authentication_request(Method::POST, "/oidc/register", None, Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+}
+
+impl ClientRegistrationPayload {
+    /// Returns a payload for registering a client with only the mandatory fields set.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_name` - The name of the client.
+    /// * `redirect_uris` - The URLs users can be redirected to after logging in.
+    pub fn new(client_name: &str, redirect_uris: &[&str]) -> Self {
+        Self {
+            client_name: client_name.to_owned(),
+            redirect_uris: redirect_uris.iter().map(|u| u.to_string()).collect(),
+            token_endpoint_auth_method: None,
+            grant_types: None,
+            response_types: None,
+            logo_uri: None,
+            client_uri: None,
+            policy_uri: None,
+            tos_uri: None,
+            jwks_uri: None,
+            contacts: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::AuthenticationError;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod register_client {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oidc/register")
+                .match_body(Matcher::Json(json!({
+                    "client_name": "My Dynamic Application",
+                    "redirect_uris": ["https://app.example.com/callback"],
+                    "token_endpoint_auth_method": "client_secret_post"
+                })))
+                .with_status(201)
+                .with_body(
+                    json!({
+                        "client_name": "My Dynamic Application",
+                        "client_id": "8SXWY6j3afl2CP5ntwEOpMdPxxy49Gt2",
+                        "client_secret": "Q5O...33P",
+                        "redirect_uris": ["https://app.example.com/callback"],
+                        "client_secret_expires_at": 0
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload = ClientRegistrationPayload::new(
+                "My Dynamic Application",
+                &["https://app.example.com/callback"],
+            );
+            payload.token_endpoint_auth_method = Some("client_secret_post".to_owned());
+            let resp = client.register_client(&payload).await.unwrap();
+
+            assert_eq!(resp.client_id, "8SXWY6j3afl2CP5ntwEOpMdPxxy49Gt2");
+            assert_eq!(resp.client_secret, Some("Q5O...33P".to_owned()));
+            assert_eq!(resp.client_secret_expires_at, Some(0));
+        }
+
+        #[tokio::test]
+        async fn map_authentication_api_errors() {
+            let _m = mock("POST", "/oidc/register")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "error": "invalid_redirect_uri",
+                        "error_description": "One or more redirect_uri values are invalid"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = ClientRegistrationPayload::new("My Dynamic Application", &["nope"]);
+
+            match client.register_client(&payload).await {
+                Err(Error::Authentication(AuthenticationError::Unknown(msg))) => {
+                    assert_eq!(msg, "One or more redirect_uri values are invalid")
+                }
+                _ => panic!("Expected Unknown variant"),
+            }
+        }
+    }
+}
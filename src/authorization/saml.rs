@@ -0,0 +1,191 @@
+//! Types and functions relative to the SAML identity provider of Auth0.
+
+use crate::error::{Auth0Result, Error};
+use crate::utils::URL_REGEX;
+use crate::Auth0Client;
+
+/// Downloads the SAML identity provider metadata of a client.
+///
+/// The metadata is returned as the XML document served by Auth0, ready to be
+/// handed to the service provider.
+///
+/// # Arguments
+///
+/// * `domain` - The domain of the tenant, e.g. `https://tenant.eu.auth0.com`.
+/// * `client_id` - The client ID of the application configured with the SAML2 Web App addon.
+///
+/// # Example
+/// ```
+/// # async fn metadata() -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::saml::fetch_saml_metadata;
+/// let metadata = fetch_saml_metadata("https://tenant.eu.auth0.com", "client_id").await?;
+///
+/// println!("{metadata}");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn fetch_saml_metadata(domain: &str, client_id: &str) -> Auth0Result<String> {
+    let url = URL_REGEX
+        .replace_all(
+            &format!("{domain}/samlp/metadata/{}", urlencoding::encode(client_id)),
+            "$1",
+        )
+        .to_string();
+
+    tracing::debug!("Fetching SAML metadata at {url}...");
+
+    let response = reqwest::get(url).await?;
+
+    if !response.status().is_success() {
+        return Err(Error::InvalidResponseBody);
+    }
+    Ok(response.text().await?)
+}
+
+/// A builder for the SAML-P URLs starting a login with Auth0 as the identity provider.
+///
+/// # Example
+///
+/// ```
+/// let client =
+///     auth0_client::Auth0Client::new("client_id", "client_secret", "https://domain.com", "audience");
+///
+/// let url = client
+///     .saml_login_url()
+///     .connection("google-oauth2")
+///     .relay_state("/dashboard")
+///     .build();
+///
+/// assert_eq!(
+///     url,
+///     "https://domain.com/samlp/client_id?connection=google-oauth2&RelayState=%2Fdashboard"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SamlLoginUrlBuilder {
+    domain: String,
+    client_id: String,
+    connection: Option<String>,
+    relay_state: Option<String>,
+}
+
+impl SamlLoginUrlBuilder {
+    /// Creates a new builder for the given tenant domain and client ID.
+    pub fn new(domain: &str, client_id: &str) -> Self {
+        Self {
+            domain: domain.to_owned(),
+            client_id: client_id.to_owned(),
+            connection: None,
+            relay_state: None,
+        }
+    }
+
+    /// Sets the connection the user logs in with, skipping the login page.
+    pub fn connection(mut self, connection: &str) -> Self {
+        self.connection = Some(connection.to_owned());
+        self
+    }
+
+    /// Sets the `RelayState` sent back to the service provider with the SAML response.
+    pub fn relay_state(mut self, relay_state: &str) -> Self {
+        self.relay_state = Some(relay_state.to_owned());
+        self
+    }
+
+    /// Builds the SAML-P login URL.
+    pub fn build(&self) -> String {
+        let mut params = vec![];
+
+        if let Some(connection) = &self.connection {
+            params.push(format!("connection={}", urlencoding::encode(connection)));
+        }
+        if let Some(relay_state) = &self.relay_state {
+            params.push(format!("RelayState={}", urlencoding::encode(relay_state)));
+        }
+
+        let url = URL_REGEX
+            .replace_all(
+                &format!(
+                    "{}/samlp/{}",
+                    self.domain,
+                    urlencoding::encode(&self.client_id)
+                ),
+                "$1",
+            )
+            .to_string();
+
+        if params.is_empty() {
+            url
+        } else {
+            format!("{url}?{}", params.join("&"))
+        }
+    }
+}
+
+impl Auth0Client {
+    /// Downloads the SAML identity provider metadata of the client.
+    pub async fn saml_metadata(&self) -> Auth0Result<String> {
+        fetch_saml_metadata(&self.domain, &self.client_id).await
+    }
+
+    /// Returns a [`SamlLoginUrlBuilder`] for the client domain and client ID.
+    pub fn saml_login_url(&self) -> SamlLoginUrlBuilder {
+        SamlLoginUrlBuilder::new(&self.domain, &self.client_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+
+    mod fetch_saml_metadata {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_xml_document() {
+            let metadata =
+                r#"<EntityDescriptor entityID="urn:tenant.eu.auth0.com"></EntityDescriptor>"#;
+            let _m = mock("GET", "/samlp/metadata/client_id")
+                .with_status(200)
+                .with_header("content-type", "application/xml")
+                .with_body(metadata)
+                .create();
+            let client = Auth0Client::new(
+                "client_id",
+                "client_secret",
+                &mockito::server_url(),
+                "https://audience.com",
+            );
+
+            let resp = client.saml_metadata().await.unwrap();
+
+            assert_eq!(resp, metadata);
+        }
+
+        #[tokio::test]
+        async fn fail_for_unknown_clients() {
+            let _m = mock("GET", "/samlp/metadata/unknown_client")
+                .with_status(404)
+                .create();
+
+            let resp = fetch_saml_metadata(&mockito::server_url(), "unknown_client").await;
+
+            match resp {
+                Err(Error::InvalidResponseBody) => (),
+                _ => panic!("Expected InvalidResponseBody variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod build {
+        use super::*;
+
+        #[test]
+        fn work_without_parameters() {
+            let url = SamlLoginUrlBuilder::new("https://domain.com/", "client_id").build();
+
+            assert_eq!(url, "https://domain.com/samlp/client_id");
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Types, traits and functions relative to native social logins.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use std::collections::HashMap;
+
+use crate::authorization::{AccessTokenResponse, Authenticatable};
+use crate::error::{Auth0Result, Error};
+use crate::{Auth0Client, GrantType};
+
+/// The token type of an authorization code issued by Sign in with Apple.
+pub const APPLE_AUTHZ_CODE_TOKEN_TYPE: &str = "http://auth0.com/oauth/token-type/apple-authz-code";
+/// The token type of a session info access token issued by the Facebook SDK.
+pub const FACEBOOK_SESSION_TOKEN_TYPE: &str =
+    "http://auth0.com/oauth/token-type/facebook-info-session-access-token";
+
+/// Trait for exchanging tokens issued by native social SDKs for Auth0 tokens.
+#[async_trait]
+pub trait NativeSocial {
+    /// Exchanges a token obtained through a native social SDK for Auth0 tokens.
+    ///
+    /// Apple and Facebook tokens use the token exchange grant on `/oauth/token`
+    /// while Google access tokens use the legacy `/oauth/access_token` endpoint.
+    ///
+    /// # Arguments
+    /// * `token` - The token obtained from the provider SDK.
+    /// * `scope` - The scopes to request, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn exchange(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::social::{NativeSocial, NativeSocialToken};
+    /// let token = NativeSocialToken::Apple {
+    ///     authorization_code: "c1a2...".to_owned(),
+    ///     user_profile: Some(serde_json::json!({ "name": { "firstName": "John" } })),
+    /// };
+    ///
+    /// let tokens = client
+    ///     .exchange_native_social_token(&token, Some("openid profile"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn exchange_native_social_token(
+        &mut self,
+        token: &NativeSocialToken,
+        scope: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse>;
+}
+
+/// A token obtained through a native social SDK.
+#[derive(Debug, Clone)]
+pub enum NativeSocialToken {
+    /// The authorization code returned by Sign in with Apple.
+    ///
+    /// `user_profile` holds the name Apple only returns on the first login.
+    Apple {
+        authorization_code: String,
+        user_profile: Option<serde_json::Value>,
+    },
+    /// The session info access token returned by the Facebook SDK.
+    Facebook {
+        session_info_token: String,
+        user_profile: Option<serde_json::Value>,
+    },
+    /// The access token returned by the Google SDK.
+    Google { access_token: String },
+}
+
+#[async_trait]
+impl NativeSocial for Auth0Client {
+    async fn exchange_native_social_token(
+        &mut self,
+        token: &NativeSocialToken,
+        scope: Option<&str>,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let (subject_token, subject_token_type, user_profile) = match token {
+            NativeSocialToken::Apple {
+                authorization_code,
+                user_profile,
+            } => (
+                authorization_code,
+                APPLE_AUTHZ_CODE_TOKEN_TYPE,
+                user_profile,
+            ),
+            NativeSocialToken::Facebook {
+                session_info_token,
+                user_profile,
+            } => (
+                session_info_token,
+                FACEBOOK_SESSION_TOKEN_TYPE,
+                user_profile,
+            ),
+            NativeSocialToken::Google { access_token } => {
+                let body = {
+                    let mut body = HashMap::new();
+
+                    body.insert("client_id", self.client_id.clone());
+                    body.insert("access_token", access_token.clone());
+                    body.insert("connection", "google-oauth2".to_owned());
+                    if let Some(scope) = scope {
+                        body.insert("scope", scope.to_owned());
+                    }
+                    body
+                };
+
+                return self
+                    .authentication_request(Method::POST, "/oauth/access_token", None, Some(body))
+                    .await?
+                    .ok_or(Error::InvalidResponseBody);
+            }
+        };
+
+        let body = {
+            let mut body = HashMap::new();
+
+            body.insert("grant_type", GrantType::TokenExchange.to_string());
+            body.insert("client_id", self.client_id.clone());
+            body.insert("audience", self.audience.clone());
+            body.insert("subject_token", subject_token.clone());
+            body.insert("subject_token_type", subject_token_type.to_owned());
+            if let Some(user_profile) = user_profile {
+                body.insert("user_profile", user_profile.to_string());
+            }
+            if let Some(scope) = scope {
+                body.insert("scope", scope.to_owned());
+            }
+            body
+        };
+
+        self.authenticate_with_body(body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod exchange_native_social_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn exchange_apple_authorization_codes() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "grant_type": "urn:ietf:params:oauth:grant-type:token-exchange",
+                    "subject_token": "authorization_code",
+                    "subject_token_type": "http://auth0.com/oauth/token-type/apple-authz-code",
+                    "user_profile": "{\"name\":{\"firstName\":\"John\"}}",
+                    "scope": "openid"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let token = NativeSocialToken::Apple {
+                authorization_code: "authorization_code".to_owned(),
+                user_profile: Some(json!({ "name": { "firstName": "John" } })),
+            };
+            let resp = client
+                .exchange_native_social_token(&token, Some("openid"))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+
+        #[tokio::test]
+        async fn exchange_facebook_session_tokens() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "subject_token": "session_info_token",
+                    "subject_token_type": "http://auth0.com/oauth/token-type/facebook-info-session-access-token"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let token = NativeSocialToken::Facebook {
+                session_info_token: "session_info_token".to_owned(),
+                user_profile: None,
+            };
+            let resp = client
+                .exchange_native_social_token(&token, None)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+
+        #[tokio::test]
+        async fn exchange_google_access_tokens() {
+            let _m = mock("POST", "/oauth/access_token")
+                .match_body(Matcher::Json(json!({
+                    "client_id": "client_id",
+                    "access_token": "google_access_token",
+                    "connection": "google-oauth2"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let token = NativeSocialToken::Google {
+                access_token: "google_access_token".to_owned(),
+            };
+            let resp = client
+                .exchange_native_social_token(&token, None)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+    }
+}
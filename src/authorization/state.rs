@@ -0,0 +1,198 @@
+//! Types, traits and functions relative to the `state`, `nonce` and PKCE verifier of interactive flows.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::{Auth0Result, Error};
+use crate::utils::random_string;
+
+/// The number of random bytes of generated states and nonces.
+const RANDOM_VALUE_LENGTH: usize = 32;
+
+/// The default number of seconds a transaction is kept by [`InMemoryStateStore`].
+const DEFAULT_TRANSACTION_LIFETIME: u64 = 600;
+
+/// Generates a cryptographically random `state`.
+pub fn generate_state() -> Auth0Result<String> {
+    random_string(RANDOM_VALUE_LENGTH)
+}
+
+/// Generates a cryptographically random `nonce`.
+pub fn generate_nonce() -> Auth0Result<String> {
+    random_string(RANDOM_VALUE_LENGTH)
+}
+
+/// Generates a cryptographically random PKCE `code_verifier`.
+pub fn generate_code_verifier() -> Auth0Result<String> {
+    random_string(RANDOM_VALUE_LENGTH)
+}
+
+/// The values of an authorization request that must be verified when the user comes back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationTransaction {
+    pub state: String,
+    pub nonce: String,
+    pub redirect_uri: Option<String>,
+    /// The PKCE verifier whose challenge is sent along with the authorization request, if any.
+    pub code_verifier: Option<String>,
+}
+
+impl AuthorizationTransaction {
+    /// Returns a transaction with a freshly generated state, nonce and PKCE code verifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `redirect_uri` - The URL the user is redirected to after logging in, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn login(client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::state::{AuthorizationTransaction, InMemoryStateStore, StateStore};
+    /// let store = InMemoryStateStore::default();
+    /// let transaction = AuthorizationTransaction::new(Some("https://app.example.com/callback"))?;
+    ///
+    /// let url = client.authorize_url().transaction(&transaction).build()?;
+    /// store.save(transaction).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(redirect_uri: Option<&str>) -> Auth0Result<Self> {
+        Ok(Self {
+            state: generate_state()?,
+            nonce: generate_nonce()?,
+            redirect_uri: redirect_uri.map(str::to_owned),
+            code_verifier: Some(generate_code_verifier()?),
+        })
+    }
+}
+
+/// Trait for storing authorization transactions between the redirect to Auth0 and the callback.
+///
+/// Implementations must only return a transaction once so a callback can not be replayed.
+#[async_trait]
+pub trait StateStore: Send + Sync {
+    /// Saves a transaction under its state.
+    async fn save(&self, transaction: AuthorizationTransaction) -> Auth0Result<()>;
+
+    /// Removes and returns the transaction saved under the given state, if any.
+    async fn take(&self, state: &str) -> Auth0Result<Option<AuthorizationTransaction>>;
+
+    /// Checks the state sent back to the callback and returns its transaction.
+    ///
+    /// Fails with [`Error::InvalidState`] if no transaction is known for the state.
+    async fn verify(&self, state: &str) -> Auth0Result<AuthorizationTransaction> {
+        self.take(state)
+            .await?
+            .ok_or_else(|| Error::InvalidState(state.to_owned()))
+    }
+}
+
+/// A [`StateStore`] keeping transactions in memory, suitable for single instance applications.
+#[derive(Debug)]
+pub struct InMemoryStateStore {
+    transactions: Mutex<HashMap<String, (AuthorizationTransaction, Instant)>>,
+    lifetime: Duration,
+}
+
+impl InMemoryStateStore {
+    /// Creates a store dropping transactions older than `lifetime`.
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            transactions: Mutex::new(HashMap::new()),
+            lifetime,
+        }
+    }
+}
+
+impl Default for InMemoryStateStore {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_TRANSACTION_LIFETIME))
+    }
+}
+
+#[async_trait]
+impl StateStore for InMemoryStateStore {
+    async fn save(&self, transaction: AuthorizationTransaction) -> Auth0Result<()> {
+        let mut transactions = self
+            .transactions
+            .lock()
+            .map_err(|_| Error::Unknown("State store lock poisoned".to_owned()))?;
+
+        transactions.retain(|_, (_, created_at)| created_at.elapsed() < self.lifetime);
+        transactions.insert(transaction.state.clone(), (transaction, Instant::now()));
+        Ok(())
+    }
+
+    async fn take(&self, state: &str) -> Auth0Result<Option<AuthorizationTransaction>> {
+        let mut transactions = self
+            .transactions
+            .lock()
+            .map_err(|_| Error::Unknown("State store lock poisoned".to_owned()))?;
+
+        Ok(transactions
+            .remove(state)
+            .filter(|(_, created_at)| created_at.elapsed() < self.lifetime)
+            .map(|(transaction, _)| transaction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod generate_state {
+        use super::*;
+
+        #[test]
+        fn return_distinct_url_safe_values() {
+            let state = generate_state().unwrap();
+
+            assert_ne!(state, generate_state().unwrap());
+            assert_eq!(state.len(), 43);
+            assert!(state
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        }
+    }
+
+    mod in_memory_state_store {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_verify_a_state_once() {
+            let store = InMemoryStateStore::default();
+            let transaction = AuthorizationTransaction::new(None).unwrap();
+
+            store.save(transaction.clone()).await.unwrap();
+
+            assert_eq!(store.verify(&transaction.state).await.unwrap(), transaction);
+            match store.verify(&transaction.state).await {
+                Err(Error::InvalidState(_)) => (),
+                _ => panic!("Expected InvalidState variant"),
+            }
+        }
+
+        #[tokio::test]
+        async fn reject_unknown_states() {
+            let store = InMemoryStateStore::default();
+
+            match store.verify("forged_state").await {
+                Err(Error::InvalidState(state)) => assert_eq!(state, "forged_state"),
+                _ => panic!("Expected InvalidState variant"),
+            }
+        }
+
+        #[tokio::test]
+        async fn reject_expired_states() {
+            let store = InMemoryStateStore::new(Duration::ZERO);
+            let transaction = AuthorizationTransaction::new(None).unwrap();
+
+            store.save(transaction.clone()).await.unwrap();
+
+            assert_eq!(store.take(&transaction.state).await.unwrap(), None);
+        }
+    }
+}
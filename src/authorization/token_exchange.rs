@@ -0,0 +1,193 @@
+//! Types, traits and functions relative to the token exchange grant (RFC 8693).
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::authorization::AccessTokenResponse;
+use crate::error::Auth0Result;
+use crate::{Auth0Client, GrantType};
+
+/// The token type of an OAuth 2.0 access token.
+pub const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+/// The token type of an OAuth 2.0 refresh token.
+pub const REFRESH_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:refresh_token";
+/// The token type of an OpenID Connect ID token.
+pub const ID_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:id_token";
+/// The token type of a JWT.
+pub const JWT_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:jwt";
+
+/// Trait for exchanging a token for Auth0 tokens.
+#[async_trait]
+pub trait TokenExchange {
+    /// Exchanges a subject token for Auth0 tokens through the token exchange grant.
+    ///
+    /// Custom token exchange profiles are selected through the `subject_token_type`
+    /// configured on the tenant, e.g. `urn:acme:legacy-token`.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the token to exchange and the tokens to request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn exchange(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::token_exchange::{TokenExchange, TokenExchangePayload};
+    /// let mut payload = TokenExchangePayload::new("legacy_token", "urn:acme:legacy-token");
+    /// payload.audience = Some("https://api.example.com".to_owned());
+    /// payload.scope = Some("openid read:orders".to_owned());
+    ///
+    /// let tokens = client.exchange_token(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn exchange_token(
+        &mut self,
+        payload: &TokenExchangePayload,
+    ) -> Auth0Result<AccessTokenResponse>;
+}
+
+/// A struct containing the payload for exchanging a token.
+#[derive(Serialize)]
+pub struct TokenExchangePayload {
+    pub subject_token: String,
+    pub subject_token_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_token_type: Option<String>,
+}
+
+/// The body sent to the token endpoint.
+#[derive(Serialize)]
+struct TokenExchangeBody<'a> {
+    grant_type: GrantType,
+    client_id: &'a str,
+    #[serde(flatten)]
+    client_authentication: HashMap<&'static str, String>,
+    #[serde(flatten)]
+    payload: &'a TokenExchangePayload,
+}
+
+#[async_trait]
+impl TokenExchange for Auth0Client {
+    async fn exchange_token(
+        &mut self,
+        payload: &TokenExchangePayload,
+    ) -> Auth0Result<AccessTokenResponse> {
+        let body = TokenExchangeBody {
+            grant_type: GrantType::TokenExchange,
+            client_id: &self.client_id,
+            client_authentication: self.client_authentication()?,
+            payload,
+        };
+
+        let mut response: AccessTokenResponse = self.token_request(body).await?;
+
+        self.bind_to_dpop_key(&mut response);
+        Ok(response)
+    }
+}
+
+impl TokenExchangePayload {
+    /// Returns a payload for exchanging a token with only the subject token fields set.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject_token` - The token to exchange.
+    /// * `subject_token_type` - The type of the token to exchange.
+    pub fn new(subject_token: &str, subject_token_type: &str) -> Self {
+        Self {
+            subject_token: subject_token.to_owned(),
+            subject_token_type: subject_token_type.to_owned(),
+            requested_token_type: None,
+            audience: None,
+            scope: None,
+            actor_token: None,
+            actor_token_type: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod exchange_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::Json(json!({
+                    "grant_type": "urn:ietf:params:oauth:grant-type:token-exchange",
+                    "client_id": "client_id",
+                    "client_secret": "client_secret",
+                    "subject_token": "legacy_token",
+                    "subject_token_type": "urn:acme:legacy-token",
+                    "requested_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                    "audience": "https://api.example.com"
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "access_token": "access_token",
+                        "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                        "token_type": "Bearer"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload = TokenExchangePayload::new("legacy_token", "urn:acme:legacy-token");
+            payload.requested_token_type = Some(ACCESS_TOKEN_TYPE.to_owned());
+            payload.audience = Some("https://api.example.com".to_owned());
+
+            let resp = client.exchange_token(&payload).await.unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+
+        #[tokio::test]
+        async fn send_the_extra_token_params() {
+            let _m = mock("POST", "/oauth/token")
+                .match_body(Matcher::PartialJson(json!({
+                    "subject_token": "legacy_token",
+                    "ext-tenant": "acme"
+                })))
+                .with_status(200)
+                .with_body(json!({ "access_token": "access_token" }).to_string())
+                .create();
+            let mut client = new_client();
+            client
+                .extra_token_params(HashMap::from([(
+                    "ext-tenant".to_owned(),
+                    "acme".to_owned(),
+                )]))
+                .unwrap();
+
+            let payload = TokenExchangePayload::new("legacy_token", "urn:acme:legacy-token");
+            let resp = client.exchange_token(&payload).await.unwrap();
+
+            assert_eq!(resp.access_token, "access_token");
+        }
+    }
+}
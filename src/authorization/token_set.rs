@@ -0,0 +1,163 @@
+//! Types and functions relative to the tokens stored on the client and their rotation.
+
+use crate::error::Auth0Result;
+use crate::Auth0Client;
+
+/// The access token and refresh token of the client, which are always replaced together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenSet {
+    pub access_token: Option<String>,
+    pub refresh_token: Option<String>,
+}
+
+/// A callback called with the new tokens when the refresh token is rotated.
+pub type TokenRotationCallback = Box<dyn Fn(&TokenSet) -> Auth0Result<()> + Send + Sync>;
+
+impl Auth0Client {
+    /// Returns the tokens stored on the client.
+    pub fn token_set(&self) -> TokenSet {
+        TokenSet {
+            access_token: self.access_token.clone(),
+            refresh_token: self.refresh_token.clone(),
+        }
+    }
+
+    /// Replaces the tokens stored on the client, e.g. with the ones persisted by the application.
+    ///
+    /// The access token is sent as a Bearer token until the client authenticates again.
+    pub fn set_token_set(&mut self, token_set: TokenSet) {
+        self.access_token = token_set.access_token;
+        self.access_token_jkt = None;
+        self.refresh_token = token_set.refresh_token;
+    }
+
+    /// Sets a callback called with the new tokens whenever a refresh returns a new refresh token.
+    ///
+    /// With refresh token rotation, the previous refresh token can no longer be used once
+    /// a new one is issued, so the client always stores the new tokens. The callback is
+    /// called right after with the new tokens so the application can persist them: if it
+    /// fails, the refresh returns its error, but the client keeps using the new tokens and
+    /// [`Auth0Client::token_set`] returns them to persist again later.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn on_rotation(mut client: auth0_client::Auth0Client) {
+    /// client.on_token_rotation(|tokens| {
+    ///     println!("Persisting {:?}...", tokens.refresh_token);
+    ///     Ok(())
+    /// });
+    /// # }
+    /// ```
+    pub fn on_token_rotation<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&TokenSet) -> Auth0Result<()> + Send + Sync + 'static,
+    {
+        self.token_rotation_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Stores new tokens on the client, then calls the rotation callback if the refresh token changed.
+    ///
+    /// The tokens are stored even if the callback fails, as the previous refresh token may
+    /// already have been revoked.
+    pub(crate) fn rotate_token_set(&mut self, token_set: TokenSet) -> Auth0Result<()> {
+        let rotated = token_set.refresh_token != self.refresh_token;
+
+        self.set_token_set(token_set);
+        match &self.token_rotation_callback {
+            Some(callback) if rotated => callback(&self.token_set()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::Authenticatable;
+    use crate::error::Error;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    fn new_client() -> Auth0Client {
+        let mut client = Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        );
+        client.set_token_set(TokenSet {
+            access_token: Some("access_token".to_owned()),
+            refresh_token: Some("rotating_refresh_token".to_owned()),
+        });
+        client
+    }
+
+    fn rotation_mock() -> mockito::Mock {
+        mock("POST", "/oauth/token")
+            .match_body(Matcher::PartialJson(json!({
+                "refresh_token": "rotating_refresh_token"
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "access_token": "rotated_access_token",
+                    "refresh_token": "rotated_refresh_token"
+                })
+                .to_string(),
+            )
+            .create()
+    }
+
+    mod on_token_rotation {
+        use super::*;
+
+        #[tokio::test]
+        async fn call_the_callback_with_the_rotated_tokens() {
+            let _m = rotation_mock();
+            let persisted = Arc::new(Mutex::new(None));
+            let mut client = new_client();
+            let store = persisted.clone();
+            client.on_token_rotation(move |tokens| {
+                *store.lock().unwrap() = Some(tokens.clone());
+                Ok(())
+            });
+
+            client
+                .refresh_access_token("rotating_refresh_token")
+                .await
+                .unwrap();
+
+            let expected = TokenSet {
+                access_token: Some("rotated_access_token".to_owned()),
+                refresh_token: Some("rotated_refresh_token".to_owned()),
+            };
+            assert_eq!(*persisted.lock().unwrap(), Some(expected.clone()));
+            assert_eq!(client.token_set(), expected);
+        }
+
+        #[tokio::test]
+        async fn keep_the_rotated_tokens_when_the_callback_fails() {
+            let _m = rotation_mock();
+            let mut client = new_client();
+            client.on_token_rotation(|_| Err(Error::Unknown("Storage is down".to_owned())));
+
+            let resp = client.refresh_access_token("rotating_refresh_token").await;
+
+            match resp {
+                Err(Error::Unknown(_)) => (),
+                _ => panic!("Expected Unknown variant, got: {resp:?}"),
+            }
+            assert_eq!(
+                client.refresh_token(),
+                Some("rotated_refresh_token".to_owned())
+            );
+            assert_eq!(
+                client.access_token(),
+                Some("rotated_access_token".to_owned())
+            );
+        }
+    }
+}
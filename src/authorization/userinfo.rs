@@ -0,0 +1,199 @@
+//! Types, traits and functions relative to the userinfo endpoint.
+
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, Validation};
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::authorization::valid_jwt_from;
+use crate::error::{Auth0Result, Error};
+use crate::Auth0Client;
+
+/// The content type used by Auth0 when the userinfo response is signed.
+const JWT_CONTENT_TYPE: &str = "application/jwt";
+
+/// Trait for fetching the profile of the user an access token was issued for.
+#[async_trait]
+pub trait FetchUserInfo {
+    /// Gets the profile of the user through the OIDC `/userinfo` endpoint.
+    ///
+    /// If the application is configured to return a signed (JWT) response, its signature,
+    /// issuer and audience are validated against the tenant JWKS before it is decoded.
+    ///
+    /// # Arguments
+    /// * `access_token` - An access token issued for the user with the `openid` scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn userinfo(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::userinfo::FetchUserInfo;
+    /// let user = client.userinfo("access_token").await?;
+    ///
+    /// println!("Hello {}", user.name.unwrap_or(user.sub));
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn userinfo(&mut self, access_token: &str) -> Auth0Result<UserInfo>;
+}
+
+/// A struct containing the response from the userinfo endpoint.
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserInfo {
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+    /// Every other claim returned, including namespaced custom claims.
+    #[serde(flatten)]
+    pub claims: HashMap<String, serde_json::Value>,
+}
+
+#[async_trait]
+impl FetchUserInfo for Auth0Client {
+    async fn userinfo(&mut self, access_token: &str) -> Auth0Result<UserInfo> {
+        let (content_type, resp_body) = self
+            .send_authentication_request(
+                Method::GET,
+                "/userinfo",
+                Some(access_token),
+                None::<String>,
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        if !content_type.starts_with(JWT_CONTENT_TYPE) {
+            return Ok(serde_json::from_str::<UserInfo>(&resp_body)?);
+        }
+
+        let source = self.jwks_source();
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(std::slice::from_ref(&self.client_id));
+        validation.set_issuer(&[format!("{}/", source.authority())]);
+        validation.set_required_spec_claims(&["sub"]);
+
+        let (token, _) =
+            valid_jwt_from::<UserInfo>(resp_body.trim(), &source, validation, self.jwks.as_ref())
+                .await?;
+
+        Ok(token.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::sign_test_jwt;
+    use mockito::mock;
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            "https://audience.com",
+        )
+    }
+
+    mod userinfo {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/userinfo")
+                .match_header("Authorization", "Bearer access_token")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c",
+                        "email": "test@example.com",
+                        "email_verified": true,
+                        "https://example.com/roles": ["admin"]
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.userinfo("access_token").await.unwrap();
+
+            assert_eq!(resp.sub, "auth0|63bfd5cdbd7f1c642dd83768");
+            assert_eq!(resp.email_verified, Some(true));
+            assert_eq!(resp.claims["nickname"], json!("test"));
+            assert_eq!(resp.claims["https://example.com/roles"], json!(["admin"]));
+        }
+
+        #[tokio::test]
+        async fn unauthorized() {
+            let _m = mock("GET", "/userinfo")
+                .with_status(401)
+                .with_body("Unauthorized")
+                .create();
+            let mut client = new_client();
+
+            let resp = client.userinfo("access_token").await;
+
+            match resp {
+                Err(Error::Unauthorized) => (),
+                _ => panic!("Expected Unauthorized variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn validate_signed_responses() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let _jwks = mock("GET", "/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .create();
+            let _m = mock("GET", "/userinfo")
+                .with_status(200)
+                .with_header("content-type", "application/jwt; charset=utf-8")
+                .with_body(sign_test_jwt(&json!({
+                    "iss": format!("{}/", mockito::server_url()),
+                    "aud": "client_id",
+                    "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                    "email": "test@example.com"
+                })))
+                .create();
+            let mut client = new_client();
+
+            let resp = client.userinfo("access_token").await.unwrap();
+
+            assert_eq!(resp.sub, "auth0|63bfd5cdbd7f1c642dd83768");
+            assert_eq!(resp.email, Some("test@example.com".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn reject_signed_responses_for_another_audience() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let _jwks = mock("GET", "/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .create();
+            let _m = mock("GET", "/userinfo")
+                .with_status(200)
+                .with_header("content-type", "application/jwt")
+                .with_body(sign_test_jwt(&json!({
+                    "iss": format!("{}/", mockito::server_url()),
+                    "aud": "another_client_id",
+                    "sub": "auth0|63bfd5cdbd7f1c642dd83768"
+                })))
+                .create();
+            let mut client = new_client();
+
+            let resp = client.userinfo("access_token").await;
+
+            match resp {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,1127 @@
+//! Types and functions relative to the validation of the access tokens of an API.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode_header, Algorithm, TokenData, Validation};
+use ring::digest::{Context, SHA256};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use crate::authorization::userinfo::{FetchUserInfo, UserInfo};
+use crate::authorization::{valid_jwt, Claims};
+use crate::error::{Auth0Result, Error};
+use crate::Auth0Client;
+
+/// The default number of seconds of clock skew tolerated on `exp` and `nbf`.
+const DEFAULT_LEEWAY: u64 = 60;
+
+/// A source of the current time, against which the expiration of the tokens is checked.
+///
+/// Inject one with [`Auth0Validation::clock`] to test expiry deterministically.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time as a UNIX timestamp in seconds.
+    fn now(&self) -> u64;
+}
+
+/// The clock of the system, used by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        jsonwebtoken::get_current_timestamp()
+    }
+}
+
+/// A clock always returning the same UNIX timestamp, in seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Returns the authority of a tenant domain given with or without the `https://` scheme,
+/// e.g. `https://tenant.eu.auth0.com`.
+pub(crate) fn authority(domain: &str) -> String {
+    let domain = domain.trim_end_matches('/');
+
+    if domain.contains("://") {
+        domain.to_owned()
+    } else {
+        format!("https://{domain}")
+    }
+}
+
+/// A builder for the validation of the access tokens issued by a tenant for an API.
+///
+/// It presets the issuer (`https://{domain}/`), the audience, the `RS256` algorithm and
+/// a leeway of 60 seconds, and checks the expiration of the tokens.
+///
+/// # Example
+///
+/// ```
+/// # async fn validate(token: &str) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::Claims;
+/// # use auth0_client::authorization::validation::Auth0Validation;
+/// let validation = Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+///     .leeway(30);
+///
+/// let (token, _) = validation.validate::<Claims>(token, None).await?;
+///
+/// println!("Hello {:?}", token.claims.sub);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Auth0Validation {
+    authority: String,
+    audience: Vec<String>,
+    algorithms: Vec<Algorithm>,
+    allowed_kids: Option<Vec<String>>,
+    leeway: u64,
+    validate_exp: bool,
+    validate_nbf: bool,
+    required_claims: Vec<String>,
+    organization: Option<String>,
+    clock: Arc<dyn Clock>,
+    validated_tokens: Option<Arc<ValidatedTokenCache>>,
+    userinfo_fallback: bool,
+}
+
+impl Auth0Validation {
+    /// Creates a validation for the tokens issued by the tenant for the given API.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The domain of the tenant, with or without the `https://` scheme.
+    /// * `audience` - The identifier of the API.
+    pub fn for_api(domain: &str, audience: &str) -> Self {
+        Self {
+            authority: authority(domain),
+            audience: vec![audience.to_owned()],
+            algorithms: vec![Algorithm::RS256],
+            allowed_kids: None,
+            leeway: DEFAULT_LEEWAY,
+            validate_exp: true,
+            validate_nbf: false,
+            required_claims: vec!["exp".to_owned(), "iss".to_owned(), "aud".to_owned()],
+            organization: None,
+            clock: Arc::new(SystemClock),
+            validated_tokens: None,
+            userinfo_fallback: false,
+        }
+    }
+
+    /// Accepts another audience, e.g. the `/userinfo` endpoint for tokens also used for OIDC.
+    pub fn audience(mut self, audience: &str) -> Self {
+        self.audience.push(audience.to_owned());
+        self
+    }
+
+    /// Sets the algorithms the tokens can be signed with, `RS256` by default.
+    pub fn algorithms(mut self, algorithms: &[Algorithm]) -> Self {
+        self.algorithms = algorithms.to_vec();
+        self
+    }
+
+    /// Only accepts the tokens signed with the given keys, e.g. the current signing keys of the tenant.
+    ///
+    /// ```
+    /// # use auth0_client::authorization::validation::Auth0Validation;
+    /// let validation = Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+    ///     .allowed_kids(&["xMkV3Yqv2Ex5PZ5y0ZSHU", "q3UFvAXgWvUz3ALFxPRgo"]);
+    /// ```
+    pub fn allowed_kids(mut self, kids: &[&str]) -> Self {
+        self.allowed_kids = Some(kids.iter().map(|kid| kid.to_string()).collect());
+        self
+    }
+
+    /// Sets the number of seconds of clock skew tolerated on `exp` and `nbf`.
+    pub fn leeway(mut self, leeway: u64) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Sets the clock the expiration of the tokens is checked against, the system one by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use auth0_client::authorization::validation::{Auth0Validation, FixedClock};
+    /// let validation = Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+    ///     .clock(FixedClock(1_700_000_000));
+    /// ```
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Caches up to `capacity` successfully validated tokens, skipping the verification of their
+    /// signature when they are validated again.
+    ///
+    /// Tokens are cached until their `exp` and keyed by the SHA-256 hash of the token and of the
+    /// authority, audience, algorithms, allowed keys and required claims, the least recently used
+    /// one being evicted when the cache is full. The cache is shared by the clones of the
+    /// validation, which only reuse the tokens validated with the same options.
+    ///
+    /// A cached token given with a jwks is only accepted if the jwks contains its signing key.
+    pub fn cache_validated_tokens(mut self, capacity: usize) -> Self {
+        self.validated_tokens = Some(Arc::new(ValidatedTokenCache::new(capacity)));
+        self
+    }
+
+    /// Sets whether [`Auth0Client::verify_identity`] resolves opaque access tokens through the
+    /// `/userinfo` endpoint, `false` by default.
+    ///
+    /// Auth0 issues opaque access tokens when no audience is requested. They cannot be
+    /// validated locally, and are rejected with [`Error::OpaqueToken`] otherwise.
+    pub fn userinfo_fallback(mut self, userinfo_fallback: bool) -> Self {
+        self.userinfo_fallback = userinfo_fallback;
+        self
+    }
+
+    /// Sets whether the expiration of the tokens is checked, `true` by default.
+    pub fn validate_exp(mut self, validate_exp: bool) -> Self {
+        self.validate_exp = validate_exp;
+        self
+    }
+
+    /// Sets whether the `nbf` claim of the tokens is checked, `false` by default.
+    pub fn validate_nbf(mut self, validate_nbf: bool) -> Self {
+        self.validate_nbf = validate_nbf;
+        self
+    }
+
+    /// Sets the claims the tokens must contain, `exp`, `iss` and `aud` by default.
+    pub fn required_claims(mut self, claims: &[&str]) -> Self {
+        self.required_claims = claims.iter().map(|claim| claim.to_string()).collect();
+        self
+    }
+
+    /// Requires the tokens to be issued for the given organization.
+    ///
+    /// Organization IDs (`org_...`) are compared with the `org_id` claim, and names are
+    /// compared case-insensitively with the `org_name` claim.
+    pub fn organization(mut self, organization: &str) -> Self {
+        self.organization = Some(organization.to_owned());
+        self
+    }
+
+    /// The authority the jwks is retrieved from, e.g. `https://tenant.eu.auth0.com`.
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
+    /// The issuer the tokens must have, e.g. `https://tenant.eu.auth0.com/`.
+    pub fn issuer(&self) -> String {
+        format!("{}/", self.authority)
+    }
+
+    /// Builds the [`Validation`] to give to [`valid_jwt`].
+    ///
+    /// `jsonwebtoken` checks `exp` and `nbf` against the system clock, the one of the
+    /// builder is only used by [`Auth0Validation::validate`].
+    pub fn build(&self) -> Validation {
+        let mut validation = Validation::new(Algorithm::RS256);
+
+        validation.algorithms = self.algorithms.clone();
+        validation.leeway = self.leeway;
+        validation.validate_exp = self.validate_exp;
+        validation.validate_nbf = self.validate_nbf;
+        validation.set_issuer(&[self.issuer()]);
+        validation.set_audience(&self.audience);
+        validation.set_required_spec_claims(&self.required_claims);
+        validation
+    }
+
+    /// Validates a token issued by the tenant for the API and decodes its payload into `C`.
+    ///
+    /// It fails with [`Error::OpaqueToken`] if the token is not a JWT, with
+    /// [`Error::UntrustedSigningKey`] if the token is signed with an algorithm or a key which is
+    /// not allowed, and with [`Error::OrganizationMismatch`] if the token was issued for another
+    /// organization than the required one.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The access token to validate.
+    /// * `jwks` - The jwks to use instead of the ones of the tenant, if any.
+    pub async fn validate<C: DeserializeOwned>(
+        &self,
+        token: &str,
+        jwks: Option<&JwkSet>,
+    ) -> Auth0Result<(TokenData<C>, JwkSet)> {
+        if !is_jwt(token) {
+            return Err(Error::OpaqueToken);
+        }
+        self.check_signing_key(token)?;
+
+        let key = self.cache_key(token);
+        let now = self.clock.now();
+        let cached = match &self.validated_tokens {
+            Some(cache) => cache
+                .get(&key, now.saturating_sub(self.leeway))?
+                .filter(|cached| signed_by(cached, jwks)),
+            None => None,
+        };
+        let is_cached = cached.is_some();
+
+        let (token, jwks) = match cached {
+            Some(validated) => validated,
+            None => {
+                let mut validation = self.build();
+                validation.validate_exp = false;
+                validation.validate_nbf = false;
+
+                valid_jwt::<Value>(token, &self.authority, validation, jwks).await?
+            }
+        };
+        let timestamp = |name| token.claims.get(name).and_then(|value| value.as_u64());
+
+        if let Some(exp) = timestamp("exp").filter(|_| self.validate_exp) {
+            if exp.saturating_add(self.leeway) < now {
+                return Err(jsonwebtoken::errors::Error::from(ErrorKind::ExpiredSignature).into());
+            }
+        }
+        if let Some(nbf) = timestamp("nbf").filter(|_| self.validate_nbf) {
+            if nbf > now.saturating_add(self.leeway) {
+                return Err(jsonwebtoken::errors::Error::from(ErrorKind::ImmatureSignature).into());
+            }
+        }
+
+        if let Some(organization) = &self.organization {
+            let claim = |name| token.claims.get(name).and_then(|value| value.as_str());
+
+            check_organization(claim("org_id"), claim("org_name"), organization)
+                .map_err(|err| Error::OrganizationMismatch(err.to_owned()))?;
+        }
+
+        if let Some(cache) = self.validated_tokens.as_ref().filter(|_| !is_cached) {
+            if let Some(exp) = timestamp("exp") {
+                let validated = (token.clone(), jwks.clone());
+                cache.insert(key, validated, exp, now.saturating_sub(self.leeway))?;
+            }
+        }
+
+        let token = TokenData {
+            header: token.header,
+            claims: serde_json::from_value(token.claims)?,
+        };
+        Ok((token, jwks))
+    }
+
+    /// Hashes the token along with the options it is validated with, so that the clones of the
+    /// validation configured differently do not share their cached tokens.
+    fn cache_key(&self, token: &str) -> Vec<u8> {
+        let options = format!(
+            "{:?}",
+            (
+                &self.authority,
+                &self.audience,
+                &self.algorithms,
+                &self.allowed_kids,
+                &self.required_claims,
+            )
+        );
+        let mut context = Context::new(&SHA256);
+
+        context.update(options.as_bytes());
+        context.update(&[0]);
+        context.update(token.as_bytes());
+        context.finish().as_ref().to_vec()
+    }
+
+    /// Checks the algorithm and the key of the token before anything else, failing closed.
+    fn check_signing_key(&self, token: &str) -> Auth0Result<()> {
+        let header = decode_header(token)?;
+
+        if !self.algorithms.contains(&header.alg) {
+            return Err(Error::UntrustedSigningKey(format!(
+                "the {:?} algorithm is not allowed",
+                header.alg
+            )));
+        }
+        if let Some(allowed_kids) = &self.allowed_kids {
+            match header.kid {
+                Some(kid) if allowed_kids.contains(&kid) => (),
+                Some(kid) => {
+                    return Err(Error::UntrustedSigningKey(format!(
+                        "the key {kid} is not allowed"
+                    )))
+                }
+                None => return Err(Error::JwtMissingKid),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether the token is a JWT, as opposed to the opaque access tokens of Auth0.
+///
+/// Auth0 opaque tokens may look like JWTs (e.g. encrypted ones with five parts), so the
+/// token must have three parts and a header with a supported algorithm.
+pub fn is_jwt(token: &str) -> bool {
+    token.split('.').count() == 3 && decode_header(token).is_ok()
+}
+
+/// The header and payload of a token decoded by [`inspect_token`], whose signature was NOT verified.
+#[derive(Debug, Clone)]
+pub struct InspectedToken {
+    pub alg: Algorithm,
+    pub kid: Option<String>,
+    /// The claims of the token, which must not be trusted.
+    pub claims: Value,
+}
+
+impl InspectedToken {
+    /// Returns the `iss` claim, e.g. to select the tenant a token must be validated against.
+    pub fn issuer(&self) -> Option<&str> {
+        self.claims.get("iss").and_then(|iss| iss.as_str())
+    }
+}
+
+/// Decodes the header and payload of a JWT WITHOUT verifying it.
+///
+/// The result is unsafe for authentication or authorization decisions: it is only meant for
+/// logging, debugging and routing, e.g. dispatching a token to the validation of its issuer.
+///
+/// # Example
+/// ```
+/// # async fn dispatch(token: &str) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::validation::{inspect_token, validate_access_token};
+/// let issuer = inspect_token(token)?.issuer().unwrap_or_default().to_owned();
+///
+/// // Only the validation proves the token was issued by the tenant.
+/// let token = validate_access_token(token, &issuer, "https://api.example.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn inspect_token(token: &str) -> Auth0Result<InspectedToken> {
+    if !is_jwt(token) {
+        return Err(Error::OpaqueToken);
+    }
+
+    let header = decode_header(token)?;
+    let payload = token.split('.').nth(1).unwrap_or_default();
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|err| jsonwebtoken::errors::Error::from(ErrorKind::Base64(err)))?;
+
+    Ok(InspectedToken {
+        alg: header.alg,
+        kid: header.kid,
+        claims: serde_json::from_slice(&payload)?,
+    })
+}
+
+/// How the identity of a [`VerifiedIdentity`] was verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentitySource {
+    /// The access token is a JWT which was validated locally.
+    AccessToken,
+    /// The access token is opaque and was resolved through the `/userinfo` endpoint.
+    UserInfo,
+}
+
+/// The user or application an access token was issued for.
+#[derive(Debug, Clone)]
+pub struct VerifiedIdentity {
+    pub sub: String,
+    pub source: IdentitySource,
+    /// The claims of the access token, or the profile returned by `/userinfo`.
+    pub claims: HashMap<String, Value>,
+}
+
+impl From<UserInfo> for VerifiedIdentity {
+    fn from(user: UserInfo) -> Self {
+        let mut claims = user.claims;
+        let profile = [
+            ("email", user.email.map(Value::from)),
+            ("email_verified", user.email_verified.map(Value::from)),
+            ("name", user.name.map(Value::from)),
+            ("picture", user.picture.map(Value::from)),
+        ];
+
+        for (name, value) in profile {
+            if let Some(value) = value {
+                claims.insert(name.to_owned(), value);
+            }
+        }
+        claims.insert("sub".to_owned(), Value::from(user.sub.clone()));
+
+        Self {
+            sub: user.sub,
+            source: IdentitySource::UserInfo,
+            claims,
+        }
+    }
+}
+
+impl Auth0Client {
+    /// Verifies the identity behind an access token.
+    ///
+    /// JWTs are validated locally with `validation`, and opaque tokens are resolved through
+    /// the `/userinfo` endpoint if [`Auth0Validation::userinfo_fallback`] is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn identity(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::authorization::validation::Auth0Validation;
+    /// let validation = Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+    ///     .userinfo_fallback(true);
+    ///
+    /// let identity = client.verify_identity("access_token", &validation).await?;
+    ///
+    /// println!("Hello {} ({:?})", identity.sub, identity.source);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_identity(
+        &mut self,
+        token: &str,
+        validation: &Auth0Validation,
+    ) -> Auth0Result<VerifiedIdentity> {
+        if !is_jwt(token) {
+            if !validation.userinfo_fallback {
+                return Err(Error::OpaqueToken);
+            }
+            return Ok(self.userinfo(token).await?.into());
+        }
+
+        let (token, _) = validation
+            .validate::<HashMap<String, Value>>(token, None)
+            .await?;
+        let sub = token
+            .claims
+            .get("sub")
+            .and_then(|sub| sub.as_str())
+            .ok_or_else(|| {
+                jsonwebtoken::errors::Error::from(ErrorKind::MissingRequiredClaim("sub".to_owned()))
+            })?
+            .to_owned();
+
+        Ok(VerifiedIdentity {
+            sub,
+            source: IdentitySource::AccessToken,
+            claims: token.claims,
+        })
+    }
+}
+
+/// A token which passed the validation, along with the jwks it was validated with.
+type ValidatedToken = (TokenData<Value>, JwkSet);
+
+/// Returns whether a cached token was signed by a key of `jwks`, if one is given.
+fn signed_by((token, _): &ValidatedToken, jwks: Option<&JwkSet>) -> bool {
+    match (jwks, &token.header.kid) {
+        (None, _) => true,
+        (Some(jwks), Some(kid)) => jwks.find(kid).is_some(),
+        (Some(_), None) => false,
+    }
+}
+
+/// A least recently used cache of validated tokens, keyed by the hash of the token and of the
+/// options of the validation.
+#[derive(Debug)]
+struct ValidatedTokenCache {
+    capacity: usize,
+    entries: Mutex<ValidatedTokenEntries>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    validated: ValidatedToken,
+    exp: u64,
+    /// The value of `uses` when the token was last used.
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct ValidatedTokenEntries {
+    /// A counter increasing with every use of the cache.
+    uses: u64,
+    tokens: HashMap<Vec<u8>, CachedToken>,
+    /// The keys of the tokens, from the least to the most recently used.
+    recency: BTreeMap<u64, Vec<u8>>,
+    /// The keys of the tokens, from the first to the last to expire.
+    expirations: BTreeSet<(u64, Vec<u8>)>,
+}
+
+impl ValidatedTokenEntries {
+    fn touch(&mut self, key: &[u8]) -> Option<&CachedToken> {
+        self.uses += 1;
+        let uses = self.uses;
+        let cached = self.tokens.get_mut(key)?;
+
+        self.recency.remove(&cached.last_used);
+        self.recency.insert(uses, key.to_vec());
+        cached.last_used = uses;
+        Some(cached)
+    }
+
+    fn remove(&mut self, key: &[u8]) -> Option<CachedToken> {
+        let cached = self.tokens.remove(key)?;
+
+        self.recency.remove(&cached.last_used);
+        self.expirations.remove(&(cached.exp, key.to_vec()));
+        Some(cached)
+    }
+
+    /// Drops the tokens which expired before `now`.
+    fn remove_expired(&mut self, now: u64) {
+        while let Some((exp, key)) = self.expirations.first().cloned() {
+            if exp >= now {
+                break;
+            }
+            self.remove(&key);
+        }
+    }
+}
+
+impl ValidatedTokenCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(ValidatedTokenEntries::default()),
+        }
+    }
+
+    /// Returns the token cached under `key`, unless it expired before `now`.
+    fn get(&self, key: &[u8], now: u64) -> Auth0Result<Option<ValidatedToken>> {
+        let mut entries = self.lock()?;
+        entries.remove_expired(now);
+
+        Ok(entries.touch(key).map(|cached| cached.validated.clone()))
+    }
+
+    fn insert(
+        &self,
+        key: Vec<u8>,
+        validated: ValidatedToken,
+        exp: u64,
+        now: u64,
+    ) -> Auth0Result<()> {
+        let mut entries = self.lock()?;
+
+        if self.capacity == 0 || exp < now {
+            return Ok(());
+        }
+        entries.remove_expired(now);
+        entries.remove(&key);
+
+        if entries.tokens.len() >= self.capacity {
+            let least_recently_used = entries
+                .recency
+                .first_key_value()
+                .map(|(_, key)| key.clone());
+
+            if let Some(least_recently_used) = least_recently_used {
+                entries.remove(&least_recently_used);
+            }
+        }
+
+        entries.uses += 1;
+        let last_used = entries.uses;
+        entries.recency.insert(last_used, key.clone());
+        entries.expirations.insert((exp, key.clone()));
+        entries.tokens.insert(
+            key,
+            CachedToken {
+                validated,
+                exp,
+                last_used,
+            },
+        );
+        Ok(())
+    }
+
+    fn lock(&self) -> Auth0Result<std::sync::MutexGuard<'_, ValidatedTokenEntries>> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::Unknown("Validated token cache lock poisoned".to_owned()))
+    }
+}
+
+/// Validates an access token issued by the tenant for the API and decodes its claims.
+///
+/// The jwks of the tenant is fetched and cached by
+/// [`JwksCache::global`](crate::authorization::jwks::JwksCache::global), and the token is checked
+/// with the defaults of [`Auth0Validation::for_api`]. Use [`Auth0Validation`] directly to tune
+/// them or to decode custom claims.
+///
+/// # Arguments
+///
+/// * `token` - The access token to validate, without the `Bearer ` prefix.
+/// * `domain` - The domain of the tenant, with or without the `https://` scheme.
+/// * `audience` - The identifier of the API.
+///
+/// # Example
+/// ```
+/// # async fn protected(token: &str) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::authorization::validation::validate_access_token;
+/// let token = validate_access_token(token, "tenant.eu.auth0.com", "https://api.example.com").await?;
+///
+/// token.claims.require_scopes(&["read:orders"])?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn validate_access_token(
+    token: &str,
+    domain: &str,
+    audience: &str,
+) -> Auth0Result<TokenData<Claims>> {
+    let (token, _) = Auth0Validation::for_api(domain, audience)
+        .validate::<Claims>(token, None)
+        .await?;
+
+    Ok(token)
+}
+
+/// Checks the organization claims of a token against the expected organization ID or name.
+pub(crate) fn check_organization(
+    org_id: Option<&str>,
+    org_name: Option<&str>,
+    organization: &str,
+) -> Result<(), &'static str> {
+    if organization.starts_with("org_") {
+        if org_id != Some(organization) {
+            return Err("org_id does not match");
+        }
+    } else if !org_name.is_some_and(|name| name.eq_ignore_ascii_case(organization)) {
+        return Err("org_name does not match");
+    }
+
+    Ok(())
+}
+
+impl From<Auth0Validation> for Validation {
+    fn from(validation: Auth0Validation) -> Self {
+        validation.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::utils::sign_test_jwt;
+    use serde_json::json;
+
+    fn jwks() -> JwkSet {
+        serde_json::from_str(&std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap()).unwrap()
+    }
+
+    fn access_token(aud: &str) -> String {
+        let now = chrono::Utc::now().timestamp();
+
+        sign_test_jwt(&json!({
+            "iss": "https://tenant.eu.auth0.com/",
+            "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+            "aud": [aud, "https://tenant.eu.auth0.com/userinfo"],
+            "exp": now + 3600,
+            "iat": now,
+            "org_id": "org_W2pqeQg6pL6Ar3sk",
+            "org_name": "acme",
+        }))
+    }
+
+    mod for_api {
+        use super::*;
+
+        #[test]
+        fn preset_the_tenant_defaults() {
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com/", "https://api.example.com").build();
+
+            assert_eq!(validation.algorithms, vec![Algorithm::RS256]);
+            assert_eq!(validation.leeway, DEFAULT_LEEWAY);
+            assert!(validation.validate_exp);
+            assert_eq!(
+                validation.iss.unwrap().into_iter().collect::<Vec<_>>(),
+                vec!["https://tenant.eu.auth0.com/"]
+            );
+            assert_eq!(
+                validation.aud.unwrap().into_iter().collect::<Vec<_>>(),
+                vec!["https://api.example.com"]
+            );
+        }
+    }
+
+    mod validate {
+        use super::*;
+
+        #[tokio::test]
+        async fn validate_tokens_of_the_api() {
+            let validation =
+                Auth0Validation::for_api("https://tenant.eu.auth0.com", "https://api.example.com");
+
+            let (token, _) = validation
+                .validate::<Claims>(&access_token("https://api.example.com"), Some(&jwks()))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                token.claims.sub.as_deref(),
+                Some("auth0|63bfd5cdbd7f1c642dd83768")
+            );
+        }
+
+        #[tokio::test]
+        async fn reject_tokens_of_other_apis() {
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com");
+
+            let resp = validation
+                .validate::<Claims>(&access_token("https://other.example.com"), Some(&jwks()))
+                .await;
+
+            match resp {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn check_the_organization() {
+            let token = access_token("https://api.example.com");
+            let validation = |organization: &str| {
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+                    .organization(organization)
+            };
+
+            for organization in ["org_W2pqeQg6pL6Ar3sk", "ACME"] {
+                let resp = validation(organization)
+                    .validate::<Claims>(&token, Some(&jwks()))
+                    .await;
+                assert!(resp.is_ok());
+            }
+            for organization in ["org_other", "umbrella"] {
+                let resp = validation(organization)
+                    .validate::<Claims>(&token, Some(&jwks()))
+                    .await;
+                match resp {
+                    Err(Error::OrganizationMismatch(_)) => (),
+                    _ => panic!("Expected OrganizationMismatch variant, got: {resp:?}"),
+                }
+            }
+        }
+
+        #[tokio::test]
+        async fn check_the_expiration_against_the_clock() {
+            let now = chrono::Utc::now().timestamp() as u64;
+            let validation = |now: u64| {
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+                    .clock(FixedClock(now))
+            };
+            let token = access_token("https://api.example.com");
+
+            let resp = validation(now + 3600 + DEFAULT_LEEWAY + 1)
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            match resp {
+                Err(Error::InvalidJwt(err)) => {
+                    assert_eq!(*err.kind(), ErrorKind::ExpiredSignature)
+                }
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+
+            let resp = validation(now + 3600 + DEFAULT_LEEWAY)
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            assert!(resp.is_ok());
+
+            let resp = validation(now + 3601)
+                .leeway(0)
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            assert!(resp.is_err());
+        }
+
+        #[tokio::test]
+        async fn skip_the_signature_of_cached_tokens() {
+            let now = chrono::Utc::now().timestamp() as u64;
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+                    .cache_validated_tokens(10);
+            let token = access_token("https://api.example.com");
+
+            validation
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await
+                .unwrap();
+            // Without a jwks, a cached token does not need the one of the tenant.
+            let (cached, _) = validation.validate::<Claims>(&token, None).await.unwrap();
+
+            assert_eq!(
+                cached.claims.sub.as_deref(),
+                Some("auth0|63bfd5cdbd7f1c642dd83768")
+            );
+
+            let resp = validation
+                .clock(FixedClock(now + 7200))
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            match resp {
+                Err(Error::InvalidJwt(err)) => {
+                    assert_eq!(*err.kind(), ErrorKind::ExpiredSignature)
+                }
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn validate_cached_tokens_again_with_other_options() {
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com")
+                    .cache_validated_tokens(10);
+            let token = access_token("https://api.example.com");
+
+            validation
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await
+                .unwrap();
+
+            let resp = validation
+                .clone()
+                .required_claims(&["exp", "iss", "aud", "nbf"])
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            match resp {
+                Err(Error::InvalidJwt(err)) => assert_eq!(
+                    *err.kind(),
+                    ErrorKind::MissingRequiredClaim("nbf".to_owned())
+                ),
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn validate_cached_tokens_again_with_other_keys() {
+            let authority = format!("{}/cached-tokens", mockito::server_url());
+            let _m = mockito::mock("GET", "/cached-tokens/.well-known/jwks.json")
+                .with_body(r#"{"keys": []}"#)
+                .create();
+            let validation = Auth0Validation::for_api(&authority, "https://api.example.com")
+                .cache_validated_tokens(10);
+            let token = sign_test_jwt(&json!({
+                "iss": format!("{authority}/"),
+                "aud": "https://api.example.com",
+                "exp": chrono::Utc::now().timestamp() + 3600,
+            }));
+            let mut other_keys = jwks();
+            other_keys.keys[0].common.key_id = Some("other_kid".to_owned());
+
+            validation
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await
+                .unwrap();
+
+            let resp = validation
+                .validate::<Claims>(&token, Some(&other_keys))
+                .await;
+            match resp {
+                Err(Error::JwtMissingKid) => (),
+                _ => panic!("Expected JwtMissingKid variant, got: {resp:?}"),
+            }
+        }
+
+        fn validated() -> ValidatedToken {
+            let token = TokenData {
+                header: jsonwebtoken::Header::default(),
+                claims: json!({}),
+            };
+            (token, JwkSet { keys: vec![] })
+        }
+
+        #[test]
+        fn evict_the_least_recently_used_token() {
+            let cache = ValidatedTokenCache::new(2);
+
+            cache
+                .insert(b"first".to_vec(), validated(), 100, 0)
+                .unwrap();
+            cache
+                .insert(b"second".to_vec(), validated(), 100, 0)
+                .unwrap();
+            cache.get(b"first", 0).unwrap();
+            cache
+                .insert(b"third".to_vec(), validated(), 100, 0)
+                .unwrap();
+
+            assert!(cache.get(b"first", 0).unwrap().is_some());
+            assert!(cache.get(b"second", 0).unwrap().is_none());
+            assert!(cache.get(b"third", 0).unwrap().is_some());
+        }
+
+        #[test]
+        fn drop_the_expired_tokens() {
+            let cache = ValidatedTokenCache::new(2);
+
+            cache
+                .insert(b"first".to_vec(), validated(), 100, 0)
+                .unwrap();
+            cache
+                .insert(b"second".to_vec(), validated(), 200, 0)
+                .unwrap();
+
+            assert!(cache.get(b"first", 150).unwrap().is_none());
+            assert!(cache.get(b"second", 150).unwrap().is_some());
+
+            let entries = cache.lock().unwrap();
+            assert_eq!(entries.tokens.len(), 1);
+            assert_eq!(entries.recency.len(), 1);
+            assert_eq!(entries.expirations.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn reject_untrusted_signing_keys() {
+            let token = access_token("https://api.example.com");
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com");
+
+            let resp = validation
+                .clone()
+                .allowed_kids(&["test_kid"])
+                .validate::<Claims>(&token, Some(&jwks()))
+                .await;
+            assert!(resp.is_ok());
+
+            for validation in [
+                validation.clone().allowed_kids(&["other_kid"]),
+                validation.algorithms(&[Algorithm::ES256]),
+            ] {
+                let resp = validation.validate::<Claims>(&token, Some(&jwks())).await;
+                match resp {
+                    Err(Error::UntrustedSigningKey(_)) => (),
+                    _ => panic!("Expected UntrustedSigningKey variant, got: {resp:?}"),
+                }
+            }
+        }
+    }
+
+    mod verify_identity {
+        use super::*;
+        use mockito::mock;
+
+        fn new_client() -> Auth0Client {
+            Auth0Client::new(
+                "client_id",
+                "client_secret",
+                &format!("{}/verify_identity", mockito::server_url()),
+                "https://audience.com",
+            )
+        }
+
+        #[test]
+        fn detect_opaque_tokens() {
+            assert!(is_jwt(&access_token("https://api.example.com")));
+            assert!(!is_jwt("2qWkPrmhXrXV9XxGNL7Wq3tmWbPuq0GT"));
+            assert!(!is_jwt(
+                "eyJhbGciOiJkaXIiLCJlbmMiOiJBMjU2R0NNIn0..iv.ciphertext.tag"
+            ));
+        }
+
+        #[tokio::test]
+        async fn resolve_opaque_tokens_through_userinfo() {
+            let _m = mock("GET", "/verify_identity/userinfo")
+                .match_header("Authorization", "Bearer opaque_token")
+                .with_status(200)
+                .with_body(
+                    json!({ "sub": "auth0|63bfd5cdbd7f1c642dd83768", "email": "test@example.com" })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+            let validation =
+                Auth0Validation::for_api("tenant.eu.auth0.com", "https://api.example.com");
+
+            let resp = client.verify_identity("opaque_token", &validation).await;
+            match resp {
+                Err(Error::OpaqueToken) => (),
+                _ => panic!("Expected OpaqueToken variant, got: {resp:?}"),
+            }
+
+            let identity = client
+                .verify_identity("opaque_token", &validation.userinfo_fallback(true))
+                .await
+                .unwrap();
+            assert_eq!(identity.sub, "auth0|63bfd5cdbd7f1c642dd83768");
+            assert_eq!(identity.source, IdentitySource::UserInfo);
+            assert_eq!(identity.claims["email"], json!("test@example.com"));
+        }
+    }
+
+    mod validate_access_token {
+        use super::*;
+        use mockito::mock;
+
+        #[tokio::test]
+        async fn fetch_the_jwks_of_the_tenant() {
+            let jwks_response = std::fs::read_to_string("tests/data/rsa_jwks.json").unwrap();
+            let m = mock("GET", "/validate_access_token/.well-known/jwks.json")
+                .with_status(200)
+                .with_body(jwks_response)
+                .create();
+            let domain = format!("{}/validate_access_token", mockito::server_url());
+            let now = chrono::Utc::now().timestamp();
+            let token = sign_test_jwt(&json!({
+                "iss": format!("{domain}/"),
+                "sub": "auth0|63bfd5cdbd7f1c642dd83768",
+                "aud": "https://api.example.com",
+                "exp": now + 3600,
+                "scope": "read:orders",
+            }));
+
+            let token = validate_access_token(&token, &domain, "https://api.example.com")
+                .await
+                .unwrap();
+
+            assert!(token.claims.has_scope("read:orders"));
+            m.assert();
+
+            let resp = validate_access_token(
+                &access_token("https://api.example.com"),
+                &domain,
+                "https://api.example.com",
+            )
+            .await;
+            match resp {
+                Err(Error::InvalidJwt(_)) => (),
+                _ => panic!("Expected InvalidJwt variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod inspect_token {
+        use super::*;
+
+        #[test]
+        fn decode_without_verification() {
+            let token = access_token("https://api.example.com");
+            let forged = format!("{}.forged", token.rsplit_once('.').unwrap().0);
+
+            let inspected = inspect_token(&forged).unwrap();
+
+            assert_eq!(inspected.alg, Algorithm::RS256);
+            assert_eq!(inspected.kid.as_deref(), Some("test_kid"));
+            assert_eq!(inspected.issuer(), Some("https://tenant.eu.auth0.com/"));
+            assert_eq!(inspected.claims["org_name"], json!("acme"));
+        }
+
+        #[test]
+        fn reject_opaque_tokens() {
+            match inspect_token("2qWkPrmhXrXV9XxGNL7Wq3tmWbPuq0GT") {
+                Err(Error::OpaqueToken) => (),
+                res => panic!("Expected OpaqueToken variant, got: {res:?}"),
+            }
+        }
+    }
+}
@@ -1,11 +1,13 @@
 //! Types relative to error handling.
 
 use reqwest::Error as ReqwestError;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use serde_json::Error as SerdeJsonError;
 use thiserror::Error as ThisError;
 
-use crate::users::UserError;
+use crate::authorization::AuthenticationError;
+use crate::management::users::UserError;
+use crate::management::ManagementError;
 
 /// The error type which represent an error returned by the Auth0 API.
 #[derive(Deserialize)]
@@ -17,6 +19,35 @@ pub struct Auth0ApiError {
     pub error_code: Option<String>,
 }
 
+/// The error type which represent an error returned by the Auth0 authentication API.
+///
+/// The `/dbconnections` endpoints use `code` and `description` instead of the OAuth fields.
+#[derive(Deserialize)]
+pub struct Auth0OAuthError {
+    #[serde(alias = "code")]
+    pub error: String,
+    #[serde(alias = "description", default, deserialize_with = "string_or_json")]
+    pub error_description: Option<String>,
+    /// The token to continue into the MFA flow with, sent along with `mfa_required` errors.
+    #[serde(default)]
+    pub mfa_token: Option<String>,
+}
+
+/// Deserializes a value as a string, keeping non-string values (such as the password policy
+/// details sent back by the signup endpoint) as their JSON representation.
+fn string_or_json<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => Some(s),
+            Some(value) => Some(value.to_string()),
+            None => None,
+        },
+    )
+}
+
 /// The error type which is returned if some error occurs duging a request.
 #[derive(Debug, ThisError)]
 pub enum Error {
@@ -35,8 +66,14 @@ pub enum Error {
     TooManyRequests,
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("Server error: {0}")]
+    ServerError(u16),
     #[error("User error: {0}")]
     User(#[from] UserError),
+    #[error("Management error: {0}")]
+    Management(#[from] ManagementError),
+    #[error("Authentication error: {0}")]
+    Authentication(#[from] AuthenticationError),
     #[error("Unimplemented")]
     Unimplemented,
     #[error("Unknown error: {0}")]
@@ -47,6 +84,32 @@ pub enum Error {
     InvalidUsername,
     #[error("Invalid password")]
     InvalidPassword,
+    #[error("Return URL is not allowed: {0}")]
+    ReturnUrlNotAllowed(String),
+    #[error("Invalid logout token: {0}")]
+    InvalidLogoutToken(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(String),
+    #[error("Reserved parameter: {0}")]
+    ReservedParameter(String),
+    #[error("Insufficient scope, missing: {}", missing.join(" "))]
+    InsufficientScope { missing: Vec<String> },
+    #[error("Opaque token, which cannot be validated locally")]
+    OpaqueToken,
+    #[error("Untrusted signing key: {0}")]
+    UntrustedSigningKey(String),
+    #[error("Organization mismatch: {0}")]
+    OrganizationMismatch(String),
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
+    #[error("Invalid state: {0}")]
+    InvalidState(String),
+    #[error("Invalid callback: {0}")]
+    InvalidCallback(String),
+    #[error("Multi-factor authentication required")]
+    MfaRequired { mfa_token: String },
 }
 
 pub type Auth0Result<T> = Result<T, Error>;
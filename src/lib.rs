@@ -40,29 +40,53 @@
 //! # }
 //! ```
 
-
 use error::{Auth0ApiError, Auth0Result, Error};
 use jsonwebtoken::jwk::JwkSet;
 use jsonwebtoken::{Algorithm, Validation};
 use reqwest::{Client as ReqwestClient, Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fmt::Display;
 
-use crate::authorization::{valid_jwt, Authenticatable};
-use crate::utils::URL_REGEX;
+use crate::authorization::client_assertion::ClientAssertionKey;
+use crate::authorization::discovery::OpenIdConfiguration;
+use crate::authorization::dpop::DpopKey;
+use crate::authorization::token_set::TokenRotationCallback;
+use crate::authorization::{valid_jwt_from, Authenticatable, Claims, RESERVED_TOKEN_PARAMS};
+use crate::utils::{check_extra_params, URL_REGEX};
 
 pub mod authorization;
 pub mod error;
-pub mod users;
+pub mod management;
 mod utils;
 
+pub use management::users;
+
 /// The grant type to use when authenticating.
 #[derive(Debug, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GrantType {
     ClientCredentials,
+    AuthorizationCode,
     Password,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/password-realm")]
+    PasswordRealm,
+    RefreshToken,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:device_code")]
+    DeviceCode,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/passwordless/otp")]
+    PasswordlessOtp,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-otp")]
+    MfaOtp,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-oob")]
+    MfaOob,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-recovery-code")]
+    MfaRecoveryCode,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:token-exchange")]
+    TokenExchange,
+    #[serde(rename = "urn:openid:params:grant-type:ciba")]
+    Ciba,
 }
 
 /// The client used to make requests towards the Auth0 API.
@@ -73,8 +97,18 @@ pub struct Auth0Client {
     audience: String,
     grant_type: GrantType,
     access_token: Option<String>,
+    access_token_jkt: Option<String>,
+    refresh_token: Option<String>,
     http_client: ReqwestClient,
     jwks: Option<JwkSet>,
+    client_assertion_key: Option<ClientAssertionKey>,
+    tls_client_auth: bool,
+    dpop_key: Option<DpopKey>,
+    openid_configuration: Option<OpenIdConfiguration>,
+    organization: Option<String>,
+    audience_tokens: HashMap<String, String>,
+    extra_token_params: HashMap<String, String>,
+    token_rotation_callback: Option<TokenRotationCallback>,
 }
 
 impl Auth0Client {
@@ -87,8 +121,18 @@ impl Auth0Client {
             audience: audience.to_owned(),
             grant_type: GrantType::ClientCredentials,
             access_token: None,
+            access_token_jkt: None,
+            refresh_token: None,
             http_client: ReqwestClient::new(),
             jwks: None,
+            client_assertion_key: None,
+            tls_client_auth: false,
+            dpop_key: None,
+            openid_configuration: None,
+            organization: None,
+            audience_tokens: HashMap::new(),
+            extra_token_params: HashMap::new(),
+            token_rotation_callback: None,
         }
     }
 
@@ -97,7 +141,7 @@ impl Auth0Client {
         self.jwks.as_ref()
     }
 
-    /// Set the JWKS
+    /// Set the JWKS, used instead of the cached JWKS of the tenant to validate tokens
     pub fn set_jwks(&mut self, jwks: JwkSet) {
         self.jwks = Some(jwks);
     }
@@ -108,6 +152,30 @@ impl Auth0Client {
         self
     }
 
+    /// Sets the organization (ID or name) users and the client authenticate to.
+    ///
+    /// It is sent by the `client_credentials` and password grants and set on authorize URLs.
+    pub fn organization(&mut self, organization: Option<&str>) -> &Auth0Client {
+        self.organization = organization.map(str::to_owned);
+        self
+    }
+
+    /// Sets parameters sent along with every token request, e.g. the `ext-*` parameters read by Actions.
+    ///
+    /// Fails with [`Error::ReservedParameter`] if one of them is a parameter of token requests.
+    pub fn extra_token_params(
+        &mut self,
+        extra_params: HashMap<String, String>,
+    ) -> Auth0Result<&Auth0Client> {
+        check_extra_params(
+            extra_params.keys().map(String::as_str),
+            RESERVED_TOKEN_PARAMS,
+        )?;
+
+        self.extra_token_params = extra_params;
+        Ok(self)
+    }
+
     /// Make a request towards the Auth0 API. It uses the `audience` field as the base URL.
     ///
     /// If access token is expired, it will first try to get a new one.
@@ -135,6 +203,38 @@ impl Auth0Client {
         path: &str,
         body: Option<B>,
     ) -> Auth0Result<Option<R>>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+        E: From<Auth0ApiError> + Into<Error>,
+    {
+        self.send_request::<B, R, E>(method, path, body, true).await
+    }
+
+    /// Same as [`Auth0Client::request`] for endpoints responding with secrets,
+    /// so their response body is never logged.
+    pub(crate) async fn sensitive_request<B, R, E>(
+        &mut self,
+        method: Method,
+        path: &str,
+        body: Option<B>,
+    ) -> Auth0Result<Option<R>>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+        E: From<Auth0ApiError> + Into<Error>,
+    {
+        self.send_request::<B, R, E>(method, path, body, false)
+            .await
+    }
+
+    async fn send_request<B, R, E>(
+        &mut self,
+        method: Method,
+        path: &str,
+        body: Option<B>,
+        log_body: bool,
+    ) -> Auth0Result<Option<R>>
     where
         B: Serialize,
         R: DeserializeOwned,
@@ -144,55 +244,95 @@ impl Auth0Client {
             .replace_all(&format!("{}/{path}", self.audience), "$1")
             .to_string();
 
-            tracing::debug!("Starting {method} request at {url}...");
-
-        let mut req = match method {
-            Method::GET => self.http_client.get(&url),
-            Method::POST => self.http_client.post(&url),
-            Method::PATCH => self.http_client.patch(&url),
-            Method::DELETE => self.http_client.delete(&url),
-            _ => return Err(Error::Unimplemented),
-        };
-
-        if let Some(mut access_token) = self.access_token.clone() {
-            // Check validity of stored token.
-            let mut validation = Validation::new(Algorithm::RS256);
-            validation.set_audience(&[self.audience.clone()]);
-            validation.set_issuer(&[self.domain.clone()]);
-            let stored_token = valid_jwt(
-                &access_token,
-                &self.domain,
-                validation,
-                self.jwks.as_ref(),
-            )
-            .await;
-
-            match stored_token {
-                Ok((_, jwks)) => self.jwks = Some(jwks),
-                Err(e) => {
+        tracing::debug!("Starting {method} request at {url}...");
+
+        let access_token = match self.access_token.clone() {
+            Some(mut access_token) => {
+                // Check validity of stored token.
+                let mut validation = Validation::new(Algorithm::RS256);
+                validation.set_audience(std::slice::from_ref(&self.audience));
+                validation.set_issuer(std::slice::from_ref(&self.domain));
+                let stored_token = valid_jwt_from::<Claims>(
+                    &access_token,
+                    &self.jwks_source(),
+                    validation,
+                    self.jwks.as_ref(),
+                )
+                .await;
+
+                if let Err(e) = stored_token {
                     tracing::debug!("Stored access token is invalid: {}", e.to_string());
                     tracing::debug!("Trying to get a new one...");
 
                     // Token is invalid so we try to get a new one once.
                     access_token = self.authenticate().await?;
                 }
+                Some(access_token)
+            }
+            None => None,
+        };
+        // Only tokens bound to the key are sent with the DPoP scheme, others stay Bearer tokens.
+        let dpop_key = self
+            .dpop_key
+            .as_ref()
+            .filter(|key| self.access_token_jkt.as_deref() == Some(key.thumbprint().as_str()));
+        let mut dpop_nonce = None;
+
+        let response = loop {
+            let mut req = match method {
+                Method::GET => self.http_client.get(&url),
+                Method::POST => self.http_client.post(&url),
+                Method::PATCH => self.http_client.patch(&url),
+                Method::PUT => self.http_client.put(&url),
+                Method::DELETE => self.http_client.delete(&url),
+                _ => return Err(Error::Unimplemented),
+            };
+
+            match (&access_token, dpop_key) {
+                (Some(access_token), Some(key)) => {
+                    req = req
+                        .header("Authorization", format!("DPoP {access_token}"))
+                        .header(
+                            "DPoP",
+                            key.proof(
+                                method.as_str(),
+                                &url,
+                                Some(access_token),
+                                dpop_nonce.as_deref(),
+                            )?,
+                        );
+                }
+                (Some(access_token), None) => {
+                    req = req.header("Authorization", format!("Bearer {access_token}"));
+                }
+                (None, _) => (),
             }
 
-            req = req.header("Authorization", format!("Bearer {access_token}"));
-        }
+            if let Some(body) = &body {
+                req = req.json(body)
+            }
 
-        if let Some(body) = body {
-            req = req.json(&body)
-        }
+            let response = req.send().await?;
 
-        let response = req.send().await?;
+            // Resource servers may require a nonce they send back in the first response.
+            match use_dpop_nonce(&response) {
+                Some(nonce) if dpop_key.is_some() && dpop_nonce.is_none() => {
+                    tracing::debug!("Retrying with the DPoP nonce sent by Auth0...");
+                    dpop_nonce = Some(nonce);
+                }
+                _ => break response,
+            }
+        };
         let status = response.status();
         let resp_body = response.text().await?;
 
-        tracing::debug!("Response from Auth0 ({}): {resp_body}", status.as_u16());
+        match log_body {
+            true => tracing::debug!("Response from Auth0 ({}): {resp_body}", status.as_u16()),
+            false => tracing::debug!("Response from Auth0 ({})", status.as_u16()),
+        }
 
         if status.is_success() {
-            if status == StatusCode::NO_CONTENT {
+            if status == StatusCode::NO_CONTENT || resp_body.is_empty() {
                 Ok(None)
             } else {
                 Ok(Some(serde_json::from_str::<R>(&resp_body)?))
@@ -201,6 +341,7 @@ impl Auth0Client {
             match status {
                 StatusCode::TOO_MANY_REQUESTS => Err(Error::TooManyRequests),
                 StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+                status if status.is_server_error() => Err(Error::ServerError(status.as_u16())),
                 _ => {
                     let err: E = serde_json::from_str::<Auth0ApiError>(&resp_body)?.into();
 
@@ -211,11 +352,46 @@ impl Auth0Client {
     }
 }
 
+/// Returns the nonce of a `use_dpop_nonce` challenge sent by a resource server, if any.
+fn use_dpop_nonce(response: &reqwest::Response) -> Option<String> {
+    let challenged = response.status() == StatusCode::UNAUTHORIZED
+        && response
+            .headers()
+            .get(reqwest::header::WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.contains("use_dpop_nonce"));
+
+    response
+        .headers()
+        .get("DPoP-Nonce")
+        .and_then(|value| value.to_str().ok())
+        .filter(|_| challenged)
+        .map(str::to_owned)
+}
+
 impl Display for GrantType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             GrantType::ClientCredentials => write!(f, "client_credentials"),
+            GrantType::AuthorizationCode => write!(f, "authorization_code"),
             GrantType::Password => write!(f, "password"),
+            GrantType::PasswordRealm => {
+                write!(f, "http://auth0.com/oauth/grant-type/password-realm")
+            }
+            GrantType::RefreshToken => write!(f, "refresh_token"),
+            GrantType::DeviceCode => write!(f, "urn:ietf:params:oauth:grant-type:device_code"),
+            GrantType::PasswordlessOtp => {
+                write!(f, "http://auth0.com/oauth/grant-type/passwordless/otp")
+            }
+            GrantType::MfaOtp => write!(f, "http://auth0.com/oauth/grant-type/mfa-otp"),
+            GrantType::MfaOob => write!(f, "http://auth0.com/oauth/grant-type/mfa-oob"),
+            GrantType::MfaRecoveryCode => {
+                write!(f, "http://auth0.com/oauth/grant-type/mfa-recovery-code")
+            }
+            GrantType::TokenExchange => {
+                write!(f, "urn:ietf:params:oauth:grant-type:token-exchange")
+            }
+            GrantType::Ciba => write!(f, "urn:openid:params:grant-type:ciba"),
         }
     }
 }
@@ -260,6 +436,44 @@ mod tests {
         }
     }
 
+    mod organization {
+        use super::*;
+
+        #[test]
+        fn set_the_organization() {
+            let mut client = new_client();
+            client.organization(Some("org_W2pqeQg6pL6Ar3sk"));
+
+            assert_eq!(client.organization.as_deref(), Some("org_W2pqeQg6pL6Ar3sk"));
+
+            client.organization(None);
+
+            assert_eq!(client.organization, None);
+        }
+    }
+
+    mod extra_token_params {
+        use super::*;
+
+        #[test]
+        fn reject_reserved_parameters() {
+            let mut client = new_client();
+
+            let resp = client
+                .extra_token_params(HashMap::from([(
+                    "audience".to_owned(),
+                    "https://other.com".to_owned(),
+                )]))
+                .map(|_| ());
+
+            match resp {
+                Err(Error::ReservedParameter(name)) => assert_eq!(name, "audience"),
+                _ => panic!("Expected ReservedParameter variant, got: {resp:?}"),
+            }
+            assert!(client.extra_token_params.is_empty());
+        }
+    }
+
     mod request {
         use super::*;
 
@@ -275,7 +489,7 @@ mod tests {
         mod errors {
             use super::*;
 
-            use crate::users::UserError;
+            use crate::management::users::UserError;
 
             #[tokio::test]
             async fn too_many_requests() {
@@ -0,0 +1,353 @@
+//! Types, traits and functions relative to the Auth0 Management API.
+//!
+//! Requests are sent towards the `audience` of the client, e.g. `https://tenant.eu.auth0.com/api/v2`,
+//! with the access token of the client.
+
+use serde::de::{DeserializeOwned, Error as DeError};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use std::ops::Deref;
+use thiserror::Error as ThisError;
+
+use crate::error::Auth0ApiError;
+
+pub mod actions;
+pub mod client_grants;
+pub mod clients;
+pub mod connections;
+pub mod email_provider;
+pub mod email_templates;
+pub mod hooks;
+pub mod log_streams;
+pub mod logs;
+pub mod organizations;
+pub mod refresh_tokens;
+pub mod resource_servers;
+pub mod roles;
+pub mod rules;
+pub mod sessions;
+pub mod user_blocks;
+pub mod users;
+
+/// A permission of an API, as assigned to users and roles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionRef {
+    /// The identifier (audience) of the API defining the permission.
+    pub resource_server_identifier: String,
+    pub permission_name: String,
+}
+
+impl PermissionRef {
+    /// Creates a reference to the permission of the API with the given identifier.
+    pub fn new(resource_server_identifier: &str, permission_name: &str) -> Self {
+        Self {
+            resource_server_identifier: resource_server_identifier.to_owned(),
+            permission_name: permission_name.to_owned(),
+        }
+    }
+}
+
+/// A permission of an API assigned to a user or a role.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Permission {
+    pub resource_server_identifier: String,
+    pub permission_name: String,
+    pub resource_server_name: Option<String>,
+    pub description: Option<String>,
+    /// How the permission is assigned to the user, directly or through roles.
+    #[serde(default)]
+    pub sources: Vec<PermissionSource>,
+}
+
+/// How a permission is assigned to a user.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PermissionSource {
+    /// The ID of the role granting the permission, or of the user for direct assignments.
+    pub source_id: String,
+    pub source_name: String,
+    /// `DIRECT` or `ROLE`.
+    pub source_type: String,
+}
+
+/// An error representing the possible errors that can occur when interacting with the Auth0 Management API.
+#[derive(Debug, ThisError)]
+pub enum ManagementError {
+    #[error("Invalid request body: {0}")]
+    InvalidRequestBody(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    #[error("Unknown management error: {0}")]
+    Unknown(String),
+}
+
+impl From<Auth0ApiError> for ManagementError {
+    fn from(api_error: Auth0ApiError) -> Self {
+        match (api_error.status_code, api_error.error_code.as_deref()) {
+            (_, Some("invalid_body")) | (400, _) => Self::InvalidRequestBody(api_error.message),
+            (404, _) => Self::NotFound(api_error.message),
+            (409, _) => Self::Conflict(api_error.message),
+            _ => Self::Unknown(api_error.message),
+        }
+    }
+}
+
+/// The order of a sorted list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// The parameters shared by the list endpoints of the Management API.
+///
+/// # Example
+///
+/// ```
+/// # use auth0_client::management::{ListParams, SortOrder};
+/// let params = ListParams::default()
+///     .fields(&["user_id", "email"])
+///     .sort("created_at", SortOrder::Descending)
+///     .page(2)
+///     .per_page(25);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListParams {
+    fields: Vec<String>,
+    include_fields: Option<bool>,
+    sort: Option<(String, SortOrder)>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+    include_totals: Option<bool>,
+}
+
+impl ListParams {
+    /// Only returns the given fields of each item, or every other field with `include_fields(false)`.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.fields = fields.iter().map(|field| field.to_string()).collect();
+        self
+    }
+
+    /// Sets whether the `fields` are included in, or excluded from, the items.
+    pub fn include_fields(mut self, include_fields: bool) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sorts the items by the given field.
+    pub fn sort(mut self, field: &str, order: SortOrder) -> Self {
+        self.sort = Some((field.to_owned(), order));
+        self
+    }
+
+    /// Sets the index of the page to return, starting at 0.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the number of items per page.
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets whether the total number of items is returned along with the page.
+    pub fn include_totals(mut self, include_totals: bool) -> Self {
+        self.include_totals = Some(include_totals);
+        self
+    }
+
+    /// Returns the URL-encoded query parameters.
+    pub(crate) fn query(&self) -> Vec<String> {
+        let mut query = vec![];
+
+        if !self.fields.is_empty() {
+            query.push(format!(
+                "fields={}",
+                urlencoding::encode(&self.fields.join(","))
+            ));
+        }
+        if let Some(include_fields) = self.include_fields {
+            query.push(format!("include_fields={include_fields}"));
+        }
+        if let Some((field, order)) = &self.sort {
+            let order = match order {
+                SortOrder::Ascending => 1,
+                SortOrder::Descending => -1,
+            };
+            query.push(format!(
+                "sort={}",
+                urlencoding::encode(&format!("{field}:{order}"))
+            ));
+        }
+        if let Some(page) = self.page {
+            query.push(format!("page={page}"));
+        }
+        if let Some(per_page) = self.per_page {
+            query.push(format!("per_page={per_page}"));
+        }
+        if let Some(include_totals) = self.include_totals {
+            query.push(format!("include_totals={include_totals}"));
+        }
+        query
+    }
+
+    /// Appends the query parameters to the path, which may already have some.
+    pub(crate) fn apply(&self, path: &str) -> String {
+        let query = self.query();
+
+        match (query.is_empty(), path.contains('?')) {
+            (true, _) => path.to_owned(),
+            (false, true) => format!("{path}&{}", query.join("&")),
+            (false, false) => format!("{path}?{}", query.join("&")),
+        }
+    }
+}
+
+/// The default number of items per page of the endpoints with checkpoint pagination.
+const DEFAULT_TAKE: u32 = 50;
+
+/// Appends the checkpoint pagination parameters to the path.
+///
+/// `take` is always sent, as the endpoints fall back to offset pagination without it.
+pub(crate) fn checkpoint_path(path: &str, from: Option<&str>, take: Option<u32>) -> String {
+    let mut path = format!("{path}?take={}", take.unwrap_or(DEFAULT_TAKE));
+    if let Some(from) = from {
+        path.push_str(&format!("&from={}", urlencoding::encode(from)));
+    }
+    path
+}
+
+/// A page of items returned by a list endpoint of the Management API.
+///
+/// The totals are only set when the page was requested with [`ListParams::include_totals`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub start: Option<u64>,
+    pub limit: Option<u64>,
+    pub total: Option<u64>,
+}
+
+impl<T> Deref for Page<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Page<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Items(Vec<serde_json::Value>),
+            // The items are under a key depending on the endpoint, e.g. `users`.
+            Totals {
+                start: Option<u64>,
+                limit: Option<u64>,
+                total: Option<u64>,
+                #[serde(flatten)]
+                other: HashMap<String, serde_json::Value>,
+            },
+        }
+
+        let (items, start, limit, total) = match Repr::deserialize(deserializer)? {
+            Repr::Items(items) => (items, None, None, None),
+            Repr::Totals {
+                start,
+                limit,
+                total,
+                other,
+            } => {
+                let items = other
+                    .into_values()
+                    .find_map(|value| match value {
+                        serde_json::Value::Array(items) => Some(items),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+
+                (items, start, limit, total)
+            }
+        };
+        let items = items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()
+            .map_err(D::Error::custom)?;
+
+        Ok(Self {
+            items,
+            start,
+            limit,
+            total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    mod list_params {
+        use super::*;
+
+        #[test]
+        fn build_the_query() {
+            let params = ListParams::default()
+                .fields(&["user_id", "email"])
+                .include_fields(true)
+                .sort("created_at", SortOrder::Descending)
+                .page(2)
+                .per_page(25)
+                .include_totals(true);
+
+            assert_eq!(
+                params.apply("/users?q=email%3A%22a%22"),
+                "/users?q=email%3A%22a%22&fields=user_id%2Cemail&include_fields=true&sort=created_at%3A-1&page=2&per_page=25&include_totals=true"
+            );
+            assert_eq!(ListParams::default().apply("/users"), "/users");
+        }
+    }
+
+    mod page {
+        use super::*;
+
+        #[test]
+        fn deserialize_a_list() {
+            let page: Page<String> = serde_json::from_value(json!(["a", "b"])).unwrap();
+
+            assert_eq!(*page, vec!["a", "b"]);
+            assert_eq!(page.total, None);
+        }
+
+        #[test]
+        fn deserialize_a_list_with_totals() {
+            let page: Page<String> = serde_json::from_value(json!({
+                "start": 0,
+                "limit": 50,
+                "length": 2,
+                "total": 2,
+                "users": ["a", "b"]
+            }))
+            .unwrap();
+
+            assert_eq!(*page, vec!["a", "b"]);
+            assert_eq!(page.total, Some(2));
+        }
+    }
+}
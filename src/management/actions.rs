@@ -0,0 +1,1214 @@
+//! Types, traits and functions relative to the actions API.
+//!
+//! An action is Node.js code run by Auth0 at a point of a flow, e.g. after the login of a user.
+//! Changes to an action are only run once the action is deployed.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the actions of the tenant.
+#[async_trait]
+pub trait OperateActions {
+    /// Lists the actions of the tenant through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the actions to list.
+    /// * `filter` - Only lists the matching actions, if set.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_actions(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::actions::{ActionFilter, OperateActions};
+    /// let filter = ActionFilter {
+    ///     trigger_id: Some("post-login".to_owned()),
+    ///     deployed: Some(true),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// for action in client.list_actions(&Default::default(), &filter).await? {
+    ///     println!("{}: {:?}", action.name, action.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_actions(
+        &mut self,
+        params: &ListParams,
+        filter: &ActionFilter,
+    ) -> Auth0Result<Page<Action>>;
+
+    /// Gets an action through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action to get.
+    async fn get_action(&mut self, action_id: &str) -> Auth0Result<Action>;
+
+    /// Creates an action through the Auth0 actions API.
+    ///
+    /// The action is not run until it is deployed with [`OperateActions::deploy_action`].
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create an action.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_action(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::actions::{ActionTrigger, CreateActionPayload, OperateActions};
+    /// let code = std::fs::read_to_string("actions/add-roles.js").unwrap();
+    /// let payload = CreateActionPayload::new("Add roles", ActionTrigger::new("post-login", "v3"), &code);
+    ///
+    /// let action = client.create_action(&payload).await?;
+    /// client.deploy_action(&action.id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_action(&mut self, payload: &CreateActionPayload) -> Auth0Result<Action>;
+
+    /// Updates the draft of an action through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action to update.
+    /// * `payload` - The properties to update.
+    async fn update_action(
+        &mut self,
+        action_id: &str,
+        payload: &UpdateActionPayload,
+    ) -> Auth0Result<Action>;
+
+    /// Deletes an action through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action to delete.
+    /// * `force` - Whether the action is deleted even if it is bound to a trigger.
+    async fn delete_action(&mut self, action_id: &str, force: bool) -> Auth0Result<()>;
+
+    /// Deploys the draft of an action through the Auth0 actions API.
+    ///
+    /// The deployment creates a new version of the action, which runs once it is built.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action to deploy.
+    async fn deploy_action(&mut self, action_id: &str) -> Auth0Result<ActionVersion>;
+
+    /// Runs the draft of an action with a sample event through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action to test.
+    /// * `payload` - The event the action is run with, depending on its trigger.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn test_action(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::actions::OperateActions;
+    /// let event = serde_json::json!({ "user": { "email": "user@example.com" } });
+    ///
+    /// let result = client.test_action("action_id", &event).await?;
+    /// println!("{result}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn test_action(&mut self, action_id: &str, payload: &Value) -> Auth0Result<Value>;
+
+    /// Lists the versions of an action through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action.
+    /// * `params` - The page of the versions to list.
+    async fn list_action_versions(
+        &mut self,
+        action_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<ActionVersion>>;
+
+    /// Gets a version of an action through the Auth0 actions API.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action.
+    /// * `version_id` - The ID of the version to get.
+    async fn get_action_version(
+        &mut self,
+        action_id: &str,
+        version_id: &str,
+    ) -> Auth0Result<ActionVersion>;
+
+    /// Deploys a previous version of an action through the Auth0 actions API, i.e. rolls it back.
+    ///
+    /// The deployment creates a new version with the code of the previous one.
+    ///
+    /// # Arguments
+    /// * `action_id` - The ID of the action.
+    /// * `version_id` - The ID of the version to deploy.
+    /// * `update_draft` - Whether the draft of the action is replaced with the deployed code.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn rollback(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::actions::OperateActions;
+    /// let versions = client
+    ///     .list_action_versions("action_id", &Default::default())
+    ///     .await?;
+    ///
+    /// // The versions are listed from the most recent one.
+    /// if let Some(previous) = versions.iter().find(|version| !version.deployed) {
+    ///     client
+    ///         .deploy_action_version("action_id", &previous.id, true)
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn deploy_action_version(
+        &mut self,
+        action_id: &str,
+        version_id: &str,
+        update_draft: bool,
+    ) -> Auth0Result<ActionVersion>;
+
+    /// Lists the actions bound to a trigger through the Auth0 actions API, in their order of execution.
+    ///
+    /// # Arguments
+    /// * `trigger_id` - The ID of the trigger, e.g. `post-login`.
+    /// * `params` - The page of the bindings to list.
+    async fn get_trigger_bindings(
+        &mut self,
+        trigger_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<TriggerBinding>>;
+
+    /// Replaces the actions bound to a trigger through the Auth0 actions API.
+    ///
+    /// The actions are run in the order of the bindings, and the actions not in the bindings
+    /// are unbound from the trigger. Only deployed actions can be bound.
+    ///
+    /// # Arguments
+    /// * `trigger_id` - The ID of the trigger, e.g. `post-login`.
+    /// * `bindings` - The actions to bind, in their order of execution.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn bind(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::actions::{ActionRef, OperateActions, TriggerBindingUpdate};
+    /// let bindings = [
+    ///     TriggerBindingUpdate::new(ActionRef::ActionName("Add roles".to_owned())),
+    ///     TriggerBindingUpdate::new(ActionRef::ActionId("action_id".to_owned())),
+    /// ];
+    ///
+    /// client.update_trigger_bindings("post-login", &bindings).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn update_trigger_bindings(
+        &mut self,
+        trigger_id: &str,
+        bindings: &[TriggerBindingUpdate],
+    ) -> Auth0Result<Vec<TriggerBinding>>;
+}
+
+/// An action of the tenant.
+///
+/// The action is edited as a draft, while the triggers run its deployed version.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Action {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+    /// The code of the draft of the action, which may differ from the deployed one.
+    pub code: Option<String>,
+    /// The Node.js runtime of the action, e.g. `node18`.
+    pub runtime: Option<String>,
+    /// The npm packages of the draft of the action.
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+    /// The secrets of the action, without their values.
+    #[serde(default)]
+    pub secrets: Vec<ActionSecret>,
+    /// The build status of the draft of the action.
+    pub status: Option<ActionStatus>,
+    /// Whether the draft of the action has no change since its last deployment.
+    #[serde(default)]
+    pub all_changes_deployed: bool,
+    /// The version run by the triggers, unless the action was never deployed.
+    pub deployed_version: Option<ActionVersion>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Action {
+    /// Returns the code run by the triggers, unless the action was never deployed.
+    pub fn deployed_code(&self) -> Option<&str> {
+        self.deployed_version.as_ref()?.code.as_deref()
+    }
+}
+
+/// A trigger of an action, i.e. the flow it runs in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionTrigger {
+    /// The ID of the trigger, e.g. `post-login` or `pre-user-registration`.
+    pub id: String,
+    /// The version of the trigger, e.g. `v3`.
+    pub version: String,
+}
+
+impl ActionTrigger {
+    pub fn new(id: &str, version: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            version: version.to_owned(),
+        }
+    }
+}
+
+/// An npm package required by an action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionDependency {
+    pub name: String,
+    /// The version of the package, e.g. `1.2.0` or `latest`.
+    pub version: String,
+    /// The registry of the package, the public npm registry by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_url: Option<String>,
+}
+
+impl ActionDependency {
+    pub fn new(name: &str, version: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            version: version.to_owned(),
+            registry_url: None,
+        }
+    }
+}
+
+/// A secret of an action, as returned by Auth0 without its value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ActionSecret {
+    pub name: String,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// A secret sent to an action, available to its code as `event.secrets.<name>`.
+///
+/// The value is write-only: it is encrypted by Auth0, never returned and never debug printed.
+#[derive(Clone, PartialEq, Eq, Serialize)]
+pub struct ActionSecretValue {
+    pub name: String,
+    value: String,
+}
+
+impl ActionSecretValue {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ActionSecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActionSecretValue")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The build status of an action or of one of its versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionStatus {
+    Draft,
+    Pending,
+    Building,
+    Packaged,
+    Built,
+    Retrying,
+    Failed,
+    /// A status this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A version of an action, created by each deployment.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ActionVersion {
+    pub id: String,
+    pub action_id: Option<String>,
+    /// The number of the version, incremented by each deployment.
+    pub number: Option<u32>,
+    pub code: Option<String>,
+    pub runtime: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+    pub status: Option<ActionStatus>,
+    /// Whether this version is the one run by the triggers.
+    #[serde(default)]
+    pub deployed: bool,
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// An action bound to a trigger.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TriggerBinding {
+    pub id: String,
+    pub trigger_id: String,
+    /// The name of the binding in the flow editor of the dashboard.
+    pub display_name: Option<String>,
+    pub action: Option<Action>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The action of a binding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ActionRef {
+    ActionId(String),
+    ActionName(String),
+    /// The ID of an existing binding, to keep its action.
+    BindingId(String),
+}
+
+/// A binding of [`OperateActions::update_trigger_bindings`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TriggerBindingUpdate {
+    #[serde(rename = "ref")]
+    pub action: ActionRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+impl TriggerBindingUpdate {
+    pub fn new(action: ActionRef) -> Self {
+        Self {
+            action,
+            display_name: None,
+        }
+    }
+}
+
+/// The filters of [`OperateActions::list_actions`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActionFilter {
+    pub trigger_id: Option<String>,
+    pub action_name: Option<String>,
+    /// Only lists the deployed actions, or the ones never deployed.
+    pub deployed: Option<bool>,
+    /// Only lists the actions installed from the marketplace, or the ones written for the tenant.
+    pub installed: Option<bool>,
+}
+
+/// The payload for creating an action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreateActionPayload {
+    pub name: String,
+    pub supported_triggers: Vec<ActionTrigger>,
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<ActionDependency>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<ActionSecretValue>,
+}
+
+impl CreateActionPayload {
+    pub fn new(name: &str, trigger: ActionTrigger, code: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            supported_triggers: vec![trigger],
+            code: code.to_owned(),
+            runtime: None,
+            dependencies: vec![],
+            secrets: vec![],
+        }
+    }
+}
+
+/// The payload for updating the draft of an action.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateActionPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_triggers: Option<Vec<ActionTrigger>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<String>,
+    /// Replaces the npm packages of the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<ActionDependency>>,
+    /// Replaces the secrets of the action: the secrets not sent are deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionSecretValue>>,
+}
+
+/// The payload for deploying a version of an action.
+#[derive(Serialize)]
+struct DeployActionVersionPayload {
+    update_draft: bool,
+}
+
+/// The payload for replacing the bindings of a trigger.
+#[derive(Serialize)]
+struct UpdateTriggerBindingsPayload<'a> {
+    bindings: &'a [TriggerBindingUpdate],
+}
+
+/// The response of [`OperateActions::update_trigger_bindings`].
+#[derive(Deserialize)]
+struct TriggerBindingsResponse {
+    bindings: Vec<TriggerBinding>,
+}
+
+/// The response of [`OperateActions::test_action`].
+#[derive(Deserialize)]
+struct TestActionResponse {
+    payload: Value,
+}
+
+#[async_trait]
+impl OperateActions for Auth0Client {
+    async fn list_actions(
+        &mut self,
+        params: &ListParams,
+        filter: &ActionFilter,
+    ) -> Auth0Result<Page<Action>> {
+        let deployed = filter.deployed.map(|deployed| deployed.to_string());
+        let installed = filter.installed.map(|installed| installed.to_string());
+        let query = [
+            ("triggerId", &filter.trigger_id),
+            ("actionName", &filter.action_name),
+            ("deployed", &deployed),
+            ("installed", &installed),
+        ]
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .as_ref()
+                .map(|value| format!("{key}={}", urlencoding::encode(value)))
+        })
+        .collect::<Vec<_>>();
+        let path = match query.is_empty() {
+            true => "/actions/actions".to_owned(),
+            false => format!("/actions/actions?{}", query.join("&")),
+        };
+
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_action(&mut self, action_id: &str) -> Auth0Result<Action> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/actions/actions/{action_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_action(&mut self, payload: &CreateActionPayload) -> Auth0Result<Action> {
+        self.request::<_, _, ManagementError>(Method::POST, "/actions/actions", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_action(
+        &mut self,
+        action_id: &str,
+        payload: &UpdateActionPayload,
+    ) -> Auth0Result<Action> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/actions/actions/{action_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_action(&mut self, action_id: &str, force: bool) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/actions/actions/{action_id}?force={force}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn deploy_action(&mut self, action_id: &str) -> Auth0Result<ActionVersion> {
+        self.request::<_, _, ManagementError>(
+            Method::POST,
+            &format!("/actions/actions/{action_id}/deploy"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn test_action(&mut self, action_id: &str, payload: &Value) -> Auth0Result<Value> {
+        let resp: TestActionResponse = self
+            .request::<_, _, ManagementError>(
+                Method::POST,
+                &format!("/actions/actions/{action_id}/test"),
+                Some(json!({ "payload": payload })),
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        Ok(resp.payload)
+    }
+
+    async fn list_action_versions(
+        &mut self,
+        action_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<ActionVersion>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!("/actions/actions/{action_id}/versions")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_action_version(
+        &mut self,
+        action_id: &str,
+        version_id: &str,
+    ) -> Auth0Result<ActionVersion> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/actions/actions/{action_id}/versions/{version_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn deploy_action_version(
+        &mut self,
+        action_id: &str,
+        version_id: &str,
+        update_draft: bool,
+    ) -> Auth0Result<ActionVersion> {
+        self.request::<_, _, ManagementError>(
+            Method::POST,
+            &format!("/actions/actions/{action_id}/versions/{version_id}/deploy"),
+            Some(DeployActionVersionPayload { update_draft }),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_trigger_bindings(
+        &mut self,
+        trigger_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<TriggerBinding>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!("/actions/triggers/{trigger_id}/bindings")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_trigger_bindings(
+        &mut self,
+        trigger_id: &str,
+        bindings: &[TriggerBindingUpdate],
+    ) -> Auth0Result<Vec<TriggerBinding>> {
+        let resp: TriggerBindingsResponse = self
+            .request::<_, _, ManagementError>(
+                Method::PATCH,
+                &format!("/actions/triggers/{trigger_id}/bindings"),
+                Some(UpdateTriggerBindingsPayload { bindings }),
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        Ok(resp.bindings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn action() -> Value {
+        json!({
+            "id": "910b1053-577f-4d81-a8c8-020e7319a38a",
+            "name": "Add roles",
+            "supported_triggers": [{ "id": "post-login", "version": "v3" }],
+            "code": "exports.onExecutePostLogin = async (event, api) => {};",
+            "runtime": "node18",
+            "status": "built",
+            "all_changes_deployed": false,
+            "created_at": "2023-01-12T09:24:45.761Z",
+            "updated_at": "2023-01-12T09:24:45.761Z"
+        })
+    }
+
+    mod list_actions {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_filter_and_the_page() {
+            let _m = mock("GET", "/actions/actions")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("triggerId".to_owned(), "post-login".to_owned()),
+                    Matcher::UrlEncoded("deployed".to_owned(), "true".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "10".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({ "actions": [action()], "total": 1, "page": 0, "per_page": 10 })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let filter = ActionFilter {
+                trigger_id: Some("post-login".to_owned()),
+                deployed: Some(true),
+                ..Default::default()
+            };
+            let resp = client
+                .list_actions(&ListParams::default().per_page(10), &filter)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.total, Some(1));
+            assert_eq!(resp[0].supported_triggers[0].id, "post-login");
+            assert_eq!(resp[0].status, Some(ActionStatus::Built));
+        }
+    }
+
+    mod create_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/actions/actions")
+                .match_body(Matcher::Json(json!({
+                    "name": "Add roles",
+                    "supported_triggers": [{ "id": "post-login", "version": "v3" }],
+                    "code": "exports.onExecutePostLogin = async (event, api) => {};"
+                })))
+                .with_status(201)
+                .with_body(action().to_string())
+                .create();
+            let mut client = new_client();
+
+            let payload = CreateActionPayload::new(
+                "Add roles",
+                ActionTrigger::new("post-login", "v3"),
+                "exports.onExecutePostLogin = async (event, api) => {};",
+            );
+            let resp = client.create_action(&payload).await.unwrap();
+
+            assert_eq!(resp.runtime.as_deref(), Some("node18"));
+            assert!(!resp.all_changes_deployed);
+        }
+
+        #[tokio::test]
+        async fn errored_with_invalid_trigger() {
+            let _m = mock("POST", "/actions/actions")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "statusCode": 400,
+                        "error": "Bad Request",
+                        "message": "Invalid trigger: unknown-trigger"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = CreateActionPayload::new(
+                "Add roles",
+                ActionTrigger::new("unknown-trigger", "v3"),
+                "exports.onExecutePostLogin = async (event, api) => {};",
+            );
+            let resp = client.create_action(&payload).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::InvalidRequestBody(_))) => (),
+                _ => panic!("Expected InvalidRequestBody variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_dependencies_and_secrets() {
+            let _m = mock(
+                "PATCH",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a",
+            )
+            .match_body(Matcher::Json(json!({
+                "dependencies": [{ "name": "axios", "version": "1.2.0" }],
+                "secrets": [{ "name": "API_KEY", "value": "s3cr3t" }]
+            })))
+            .with_status(200)
+            .with_body({
+                let mut action = action();
+                action["dependencies"] = json!([{ "name": "axios", "version": "1.2.0" }]);
+                action["secrets"] = json!([
+                    { "name": "API_KEY", "updated_at": "2023-01-12T09:24:45.761Z" }
+                ]);
+                action.to_string()
+            })
+            .create();
+            let mut client = new_client();
+
+            let payload = UpdateActionPayload {
+                dependencies: Some(vec![ActionDependency::new("axios", "1.2.0")]),
+                secrets: Some(vec![ActionSecretValue::new("API_KEY", "s3cr3t")]),
+                ..Default::default()
+            };
+            let resp = client
+                .update_action("910b1053-577f-4d81-a8c8-020e7319a38a", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                resp.dependencies,
+                vec![ActionDependency::new("axios", "1.2.0")]
+            );
+            assert_eq!(resp.secrets[0].name, "API_KEY");
+            assert!(!format!("{payload:?}").contains("s3cr3t"));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_action() {
+            let _m = mock("PATCH", "/actions/actions/unknown_action")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The action does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .update_action("unknown_action", &UpdateActionPayload::default())
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod delete_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_force_flag() {
+            let m = mock(
+                "DELETE",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a",
+            )
+            .match_query(Matcher::UrlEncoded("force".to_owned(), "true".to_owned()))
+            .with_status(204)
+            .create();
+            let mut client = new_client();
+
+            client
+                .delete_action("910b1053-577f-4d81-a8c8-020e7319a38a", true)
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_action() {
+            let _m = mock("DELETE", "/actions/actions/unknown_action")
+                .match_query(Matcher::Any)
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The action does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.delete_action("unknown_action", false).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod deploy_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock(
+                "POST",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a/deploy",
+            )
+            .with_status(200)
+            .with_body(
+                json!({
+                    "id": "12a3b9e6-06e6-4a29-96bf-90c82fe79a0d",
+                    "action_id": "910b1053-577f-4d81-a8c8-020e7319a38a",
+                    "number": 2,
+                    "status": "pending",
+                    "deployed": false
+                })
+                .to_string(),
+            )
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .deploy_action("910b1053-577f-4d81-a8c8-020e7319a38a")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.number, Some(2));
+            assert_eq!(resp.status, Some(ActionStatus::Pending));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_action() {
+            let _m = mock("POST", "/actions/actions/unknown_action/deploy")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The action does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.deploy_action("unknown_action").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod test_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn return_the_result_payload() {
+            let _m = mock(
+                "POST",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a/test",
+            )
+            .match_body(Matcher::Json(json!({
+                "payload": { "user": { "email": "user@example.com" } }
+            })))
+            .with_status(200)
+            .with_body(
+                json!({ "payload": { "logs": "", "stats": { "total_ms": 12 } } }).to_string(),
+            )
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .test_action(
+                    "910b1053-577f-4d81-a8c8-020e7319a38a",
+                    &json!({ "user": { "email": "user@example.com" } }),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(resp["stats"]["total_ms"], 12);
+        }
+    }
+
+    fn action_version(number: u32, deployed: bool) -> Value {
+        json!({
+            "id": format!("version_{number}"),
+            "action_id": "910b1053-577f-4d81-a8c8-020e7319a38a",
+            "number": number,
+            "code": format!("// version {number}"),
+            "status": "built",
+            "deployed": deployed,
+            "created_at": "2023-01-12T09:24:45.761Z"
+        })
+    }
+
+    mod get_action {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_deployed_version() {
+            let _m = mock("GET", "/actions/actions/deployed_action")
+                .with_status(200)
+                .with_body({
+                    let mut action = action();
+                    action["deployed_version"] = action_version(2, true);
+                    action.to_string()
+                })
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_action("deployed_action").await.unwrap();
+
+            assert_eq!(resp.deployed_code(), Some("// version 2"));
+            assert_ne!(resp.code.as_deref(), resp.deployed_code());
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_action() {
+            let _m = mock("GET", "/actions/actions/unknown_action")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The action does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_action("unknown_action").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod list_action_versions {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_page() {
+            let _m = mock(
+                "GET",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a/versions",
+            )
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "1".to_owned()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "versions": [action_version(2, true), action_version(1, false)],
+                    "total": 2,
+                    "page": 1,
+                    "per_page": 50
+                })
+                .to_string(),
+            )
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_action_versions(
+                    "910b1053-577f-4d81-a8c8-020e7319a38a",
+                    &ListParams::default().page(1),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(resp.len(), 2);
+            assert!(resp[0].deployed);
+            assert_eq!(resp[1].number, Some(1));
+        }
+    }
+
+    mod deploy_action_version {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_update_draft_flag() {
+            let _m = mock(
+                "POST",
+                "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a/versions/version_1/deploy",
+            )
+            .match_body(Matcher::Json(json!({ "update_draft": true })))
+            .with_status(200)
+            .with_body(action_version(3, false).to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .deploy_action_version("910b1053-577f-4d81-a8c8-020e7319a38a", "version_1", true)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.number, Some(3));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_version() {
+            let _m = mock("POST", "/actions/actions/910b1053-577f-4d81-a8c8-020e7319a38a/versions/unknown_version/deploy")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The version does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .deploy_action_version(
+                    "910b1053-577f-4d81-a8c8-020e7319a38a",
+                    "unknown_version",
+                    false,
+                )
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    fn trigger_binding() -> Value {
+        json!({
+            "id": "a1b2c3d4-binding",
+            "trigger_id": "post-login",
+            "display_name": "Add roles",
+            "action": action(),
+            "created_at": "2023-01-12T09:24:45.761Z",
+            "updated_at": "2023-01-12T09:24:45.761Z"
+        })
+    }
+
+    mod get_trigger_bindings {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/actions/triggers/post-login/bindings")
+                .with_status(200)
+                .with_body(
+                    json!({ "bindings": [trigger_binding()], "total": 1, "page": 0, "per_page": 50 })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_trigger_bindings("post-login", &Default::default())
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].display_name.as_deref(), Some("Add roles"));
+            assert_eq!(resp[0].action.as_ref().unwrap().name, "Add roles");
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_trigger() {
+            let _m = mock("GET", "/actions/triggers/unknown-trigger/bindings")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The trigger does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_trigger_bindings("unknown-trigger", &Default::default())
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_trigger_bindings {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_action_refs() {
+            let _m = mock("PATCH", "/actions/triggers/pre-user-registration/bindings")
+                .match_body(Matcher::Json(json!({
+                    "bindings": [
+                        { "ref": { "type": "binding_id", "value": "a1b2c3d4-binding" } },
+                        {
+                            "ref": { "type": "action_name", "value": "Add roles" },
+                            "display_name": "Roles"
+                        }
+                    ]
+                })))
+                .with_status(200)
+                .with_body(json!({ "bindings": [trigger_binding()] }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let bindings = [
+                TriggerBindingUpdate::new(ActionRef::BindingId("a1b2c3d4-binding".to_owned())),
+                TriggerBindingUpdate {
+                    display_name: Some("Roles".to_owned()),
+                    ..TriggerBindingUpdate::new(ActionRef::ActionName("Add roles".to_owned()))
+                },
+            ];
+            let resp = client
+                .update_trigger_bindings("pre-user-registration", &bindings)
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].id, "a1b2c3d4-binding");
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Types, traits and functions relative to the client grants API.
+//!
+//! A client grant allows an application to request access tokens for an API with the
+//! client credentials grant, e.g. for machine-to-machine applications.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the client grants of the tenant.
+#[async_trait]
+pub trait OperateClientGrants {
+    /// Lists the client grants of the tenant through the Auth0 client grants API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the client grants to list.
+    /// * `filter` - Only lists the client grants of an application or an API, if set.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_grants(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::client_grants::{ClientGrantFilter, OperateClientGrants};
+    /// let filter = ClientGrantFilter {
+    ///     audience: Some("https://api.example.com".to_owned()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let grants = client.list_client_grants(&Default::default(), &filter).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_client_grants(
+        &mut self,
+        params: &ListParams,
+        filter: &ClientGrantFilter,
+    ) -> Auth0Result<Page<ClientGrant>>;
+
+    /// Creates a client grant through the Auth0 client grants API.
+    ///
+    /// # Arguments
+    /// * `client_id` - The client ID of the application.
+    /// * `audience` - The identifier of the API.
+    /// * `scope` - The scopes the application is granted on the API.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_grant(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::client_grants::OperateClientGrants;
+    /// let grant = client
+    ///     .create_client_grant("app_client_id", "https://api.example.com", &["read:orders"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_client_grant(
+        &mut self,
+        client_id: &str,
+        audience: &str,
+        scope: &[&str],
+    ) -> Auth0Result<ClientGrant>;
+
+    /// Replaces the scopes of a client grant through the Auth0 client grants API.
+    ///
+    /// # Arguments
+    /// * `grant_id` - The ID of the client grant to update.
+    /// * `scope` - The scopes the application is granted on the API.
+    async fn update_client_grant(
+        &mut self,
+        grant_id: &str,
+        scope: &[&str],
+    ) -> Auth0Result<ClientGrant>;
+
+    /// Deletes a client grant through the Auth0 client grants API.
+    ///
+    /// # Arguments
+    /// * `grant_id` - The ID of the client grant to delete.
+    async fn delete_client_grant(&mut self, grant_id: &str) -> Auth0Result<()>;
+}
+
+/// A grant of an application to request access tokens for an API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ClientGrant {
+    pub id: String,
+    pub client_id: String,
+    /// The identifier of the API.
+    pub audience: String,
+    #[serde(default)]
+    pub scope: Vec<String>,
+}
+
+/// The filters of [`OperateClientGrants::list_client_grants`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientGrantFilter {
+    pub client_id: Option<String>,
+    pub audience: Option<String>,
+}
+
+/// The payload for creating a client grant.
+#[derive(Serialize)]
+struct CreateClientGrantPayload<'a> {
+    client_id: &'a str,
+    audience: &'a str,
+    scope: &'a [&'a str],
+}
+
+/// The payload for updating a client grant.
+#[derive(Serialize)]
+struct UpdateClientGrantPayload<'a> {
+    scope: &'a [&'a str],
+}
+
+#[async_trait]
+impl OperateClientGrants for Auth0Client {
+    async fn list_client_grants(
+        &mut self,
+        params: &ListParams,
+        filter: &ClientGrantFilter,
+    ) -> Auth0Result<Page<ClientGrant>> {
+        let query = [
+            ("client_id", &filter.client_id),
+            ("audience", &filter.audience),
+        ]
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .as_ref()
+                .map(|value| format!("{key}={}", urlencoding::encode(value)))
+        })
+        .collect::<Vec<_>>();
+        let path = match query.is_empty() {
+            true => "/client-grants".to_owned(),
+            false => format!("/client-grants?{}", query.join("&")),
+        };
+
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_client_grant(
+        &mut self,
+        client_id: &str,
+        audience: &str,
+        scope: &[&str],
+    ) -> Auth0Result<ClientGrant> {
+        self.request::<_, _, ManagementError>(
+            Method::POST,
+            "/client-grants",
+            Some(CreateClientGrantPayload {
+                client_id,
+                audience,
+                scope,
+            }),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_client_grant(
+        &mut self,
+        grant_id: &str,
+        scope: &[&str],
+    ) -> Auth0Result<ClientGrant> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/client-grants/{grant_id}"),
+            Some(UpdateClientGrantPayload { scope }),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_client_grant(&mut self, grant_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/client-grants/{grant_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn client_grant() -> String {
+        json!({
+            "id": "cgr_0tK3QKvbSvXcXz7w",
+            "client_id": "app_client_id",
+            "audience": "https://api.example.com",
+            "scope": ["read:orders"]
+        })
+        .to_string()
+    }
+
+    mod list_client_grants {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_filter_and_the_page() {
+            let _m = mock("GET", "/client-grants")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("client_id".to_owned(), "app_client_id".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "5".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(format!("[{}]", client_grant()))
+                .create();
+            let mut client = new_client();
+
+            let filter = ClientGrantFilter {
+                client_id: Some("app_client_id".to_owned()),
+                ..Default::default()
+            };
+            let resp = client
+                .list_client_grants(&ListParams::default().per_page(5), &filter)
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].scope, vec!["read:orders"]);
+        }
+    }
+
+    mod create_client_grant {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/client-grants")
+                .match_body(Matcher::Json(json!({
+                    "client_id": "app_client_id",
+                    "audience": "https://api.example.com",
+                    "scope": ["read:orders"]
+                })))
+                .with_status(201)
+                .with_body(client_grant())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .create_client_grant("app_client_id", "https://api.example.com", &["read:orders"])
+                .await
+                .unwrap();
+
+            assert_eq!(resp.id, "cgr_0tK3QKvbSvXcXz7w");
+        }
+
+        #[tokio::test]
+        async fn errored_with_existing_grant() {
+            let _m = mock("POST", "/client-grants")
+                .with_status(409)
+                .with_body(
+                    json!({
+                        "statusCode": 409,
+                        "error": "Conflict",
+                        "message": "A resource with the same identifier already exists",
+                        "errorCode": "client_grant_conflict"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .create_client_grant("app_client_id", "https://api.example.com", &["read:orders"])
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::Conflict(_))) => (),
+                _ => panic!("Expected Conflict variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_client_grant {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_scope() {
+            let m = mock("PATCH", "/client-grants/cgr_0tK3QKvbSvXcXz7w")
+                .match_body(Matcher::Json(json!({ "scope": ["read:orders"] })))
+                .with_status(200)
+                .with_body(client_grant())
+                .create();
+            let mut client = new_client();
+
+            client
+                .update_client_grant("cgr_0tK3QKvbSvXcXz7w", &["read:orders"])
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_grant() {
+            let _m = mock("PATCH", "/client-grants/cgr_unknown")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The grant does not exist.",
+                        "errorCode": "inexistent_client_grant"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .update_client_grant("cgr_unknown", &["read:orders"])
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod delete_client_grant {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/client-grants/cgr_0tK3QKvbSvXcXz7w")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_client_grant("cgr_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_grant() {
+            let _m = mock("DELETE", "/client-grants/cgr_unknown")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The grant does not exist.",
+                        "errorCode": "inexistent_client_grant"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.delete_client_grant("cgr_unknown").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,576 @@
+//! Types, traits and functions relative to the clients API, i.e. the applications of the tenant.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the applications of the tenant.
+#[async_trait]
+pub trait OperateClients {
+    /// Lists the applications of the tenant through the Auth0 clients API.
+    ///
+    /// Only the fields set with [`ListParams::fields`] are returned, if any: the other
+    /// fields of the application are then left empty.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the applications to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_clients(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::clients::OperateClients;
+    /// # use auth0_client::management::ListParams;
+    /// let params = ListParams::default().fields(&["client_id", "name", "app_type"]);
+    ///
+    /// for application in client.list_clients(&params).await? {
+    ///     println!("{}: {:?}", application.name, application.app_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_clients(&mut self, params: &ListParams) -> Auth0Result<Page<Client>>;
+
+    /// Gets an application through the Auth0 clients API.
+    ///
+    /// # Arguments
+    /// * `client_id` - The client ID of the application to get.
+    async fn get_client(&mut self, client_id: &str) -> Auth0Result<Client>;
+
+    /// Creates an application through the Auth0 clients API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create an application.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_client(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::clients::{AppType, CreateClientPayload, OperateClients};
+    /// let mut payload = CreateClientPayload::new("Dashboard", AppType::RegularWeb);
+    /// payload.callbacks = Some(vec!["https://dashboard.example.com/callback".to_owned()]);
+    ///
+    /// let application = client.create_client(&payload).await?;
+    /// println!("Created {}", application.client_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_client(&mut self, payload: &CreateClientPayload) -> Auth0Result<Client>;
+
+    /// Updates an application through the Auth0 clients API.
+    ///
+    /// # Arguments
+    /// * `client_id` - The client ID of the application to update.
+    /// * `payload` - The fields of the application to update.
+    async fn update_client(
+        &mut self,
+        client_id: &str,
+        payload: &UpdateClientPayload,
+    ) -> Auth0Result<Client>;
+
+    /// Deletes an application through the Auth0 clients API.
+    ///
+    /// # Arguments
+    /// * `client_id` - The client ID of the application to delete.
+    async fn delete_client(&mut self, client_id: &str) -> Auth0Result<()>;
+
+    /// Rotates the client secret of an application through the Auth0 clients API.
+    ///
+    /// The previous secret stops working immediately.
+    ///
+    /// # Arguments
+    /// * `client_id` - The client ID of the application.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn rotate(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::clients::OperateClients;
+    /// let application = client.rotate_client_secret("app_client_id").await?;
+    ///
+    /// println!("New secret: {:?}", application.client_secret);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn rotate_client_secret(&mut self, client_id: &str) -> Auth0Result<Client>;
+
+    /// Rotates the client secret of the application of the client through the Auth0 clients API,
+    /// and authenticates with the new secret from then on.
+    ///
+    /// The client needs the `update:client_keys` scope on the Management API.
+    async fn rotate_own_client_secret(&mut self) -> Auth0Result<Client>;
+}
+
+/// The type of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppType {
+    Native,
+    Spa,
+    RegularWeb,
+    /// A machine-to-machine application.
+    NonInteractive,
+    /// A type this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How the access tokens and ID tokens of an application are signed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtConfiguration {
+    /// `HS256` or `RS256`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// The lifetime of the ID tokens, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifetime_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_encoded: Option<bool>,
+}
+
+/// Whether the refresh tokens of an application are rotated on use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RotationType {
+    Rotating,
+    NonRotating,
+}
+
+/// Whether the refresh tokens of an application expire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExpirationType {
+    Expiring,
+    NonExpiring,
+}
+
+/// The settings of the refresh tokens of an application.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshTokenConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation_type: Option<RotationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_type: Option<ExpirationType>,
+    /// The number of seconds a rotated refresh token can still be used, for concurrent requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leeway: Option<u64>,
+    /// The absolute lifetime of the refresh tokens, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infinite_token_lifetime: Option<bool>,
+    /// The inactivity lifetime of the refresh tokens, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_token_lifetime: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infinite_idle_token_lifetime: Option<bool>,
+}
+
+/// An application of the tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Client {
+    pub client_id: String,
+    #[serde(default)]
+    pub name: String,
+    pub description: Option<String>,
+    pub app_type: Option<AppType>,
+    pub client_secret: Option<String>,
+    #[serde(default)]
+    pub callbacks: Vec<String>,
+    #[serde(default)]
+    pub allowed_logout_urls: Vec<String>,
+    #[serde(default)]
+    pub web_origins: Vec<String>,
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+    #[serde(default)]
+    pub grant_types: Vec<String>,
+    pub jwt_configuration: Option<JwtConfiguration>,
+    pub refresh_token: Option<RefreshTokenConfiguration>,
+    /// `none`, `client_secret_post` or `client_secret_basic`.
+    pub token_endpoint_auth_method: Option<String>,
+    pub is_first_party: Option<bool>,
+    pub oidc_conformant: Option<bool>,
+    #[serde(default)]
+    pub client_metadata: HashMap<String, String>,
+}
+
+/// A struct containing the payload for creating an application.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateClientPayload {
+    pub name: String,
+    pub app_type: AppType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callbacks: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_configuration: Option<JwtConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<RefreshTokenConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_party: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_conformant: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateClientPayload {
+    /// Returns a payload for creating an application with only `name` and `app_type` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the application.
+    /// * `app_type` - The type of the application.
+    pub fn new(name: &str, app_type: AppType) -> Self {
+        Self {
+            name: name.to_owned(),
+            app_type,
+            description: None,
+            callbacks: None,
+            allowed_logout_urls: None,
+            web_origins: None,
+            allowed_origins: None,
+            grant_types: None,
+            jwt_configuration: None,
+            refresh_token: None,
+            token_endpoint_auth_method: None,
+            is_first_party: None,
+            oidc_conformant: None,
+            client_metadata: None,
+        }
+    }
+}
+
+/// A struct containing the payload for updating an application.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateClientPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<AppType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callbacks: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_configuration: Option<JwtConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<RefreshTokenConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint_auth_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_conformant: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_metadata: Option<HashMap<String, String>>,
+}
+
+#[async_trait]
+impl OperateClients for Auth0Client {
+    async fn list_clients(&mut self, params: &ListParams) -> Auth0Result<Page<Client>> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply("/clients"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_client(&mut self, client_id: &str) -> Auth0Result<Client> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/clients/{client_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_client(&mut self, payload: &CreateClientPayload) -> Auth0Result<Client> {
+        self.sensitive_request::<_, _, ManagementError>(Method::POST, "/clients", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_client(
+        &mut self,
+        client_id: &str,
+        payload: &UpdateClientPayload,
+    ) -> Auth0Result<Client> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/clients/{client_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_client(&mut self, client_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/clients/{client_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn rotate_client_secret(&mut self, client_id: &str) -> Auth0Result<Client> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::POST,
+            &format!("/clients/{client_id}/rotate-secret"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn rotate_own_client_secret(&mut self) -> Auth0Result<Client> {
+        let client_id = self.client_id.clone();
+        let application = self.rotate_client_secret(&client_id).await?;
+
+        self.client_secret = application
+            .client_secret
+            .clone()
+            .ok_or(Error::InvalidResponseBody)?;
+        Ok(application)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn application() -> serde_json::Value {
+        json!({
+            "client_id": "app_client_id",
+            "name": "Dashboard",
+            "app_type": "regular_web",
+            "client_secret": "app_client_secret",
+            "callbacks": ["https://dashboard.example.com/callback"],
+            "grant_types": ["authorization_code", "refresh_token"],
+            "jwt_configuration": {
+                "alg": "RS256",
+                "lifetime_in_seconds": 36000,
+                "secret_encoded": false
+            },
+            "refresh_token": {
+                "rotation_type": "rotating",
+                "expiration_type": "expiring",
+                "leeway": 0,
+                "token_lifetime": 2592000,
+                "infinite_token_lifetime": false
+            },
+            "token_endpoint_auth_method": "client_secret_post",
+            "is_first_party": true
+        })
+    }
+
+    mod models {
+        use super::*;
+
+        #[test]
+        fn deserialize_unknown_app_types() {
+            let mut application = application();
+            application["app_type"] = json!("resource_server");
+
+            let application: Client = serde_json::from_value(application).unwrap();
+
+            assert_eq!(application.app_type, Some(AppType::Unknown));
+        }
+    }
+
+    mod list_clients {
+        use super::*;
+
+        #[tokio::test]
+        async fn filter_the_fields() {
+            let _m = mock("GET", "/clients")
+                .match_query(Matcher::UrlEncoded(
+                    "fields".to_owned(),
+                    "client_id,app_type".to_owned(),
+                ))
+                .with_status(200)
+                .with_body(json!([{ "client_id": "app_client_id", "app_type": "spa" }]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_clients(&ListParams::default().fields(&["client_id", "app_type"]))
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].app_type, Some(AppType::Spa));
+            assert!(resp[0].callbacks.is_empty());
+        }
+    }
+
+    mod get_client {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/clients/app_client_id")
+                .with_status(200)
+                .with_body(application().to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_client("app_client_id").await.unwrap();
+
+            assert_eq!(
+                resp.jwt_configuration.unwrap().alg.as_deref(),
+                Some("RS256")
+            );
+            assert_eq!(
+                resp.refresh_token.unwrap().rotation_type,
+                Some(RotationType::Rotating)
+            );
+        }
+    }
+
+    mod create_client {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/clients")
+                .match_body(Matcher::Json(json!({
+                    "name": "Dashboard",
+                    "app_type": "regular_web",
+                    "callbacks": ["https://dashboard.example.com/callback"]
+                })))
+                .with_status(201)
+                .with_body(application().to_string())
+                .create();
+            let mut client = new_client();
+
+            let mut payload = CreateClientPayload::new("Dashboard", AppType::RegularWeb);
+            payload.callbacks = Some(vec!["https://dashboard.example.com/callback".to_owned()]);
+            let resp = client.create_client(&payload).await.unwrap();
+
+            assert_eq!(resp.client_secret.as_deref(), Some("app_client_secret"));
+        }
+    }
+
+    mod update_client {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let _m = mock("PATCH", "/clients/app_client_id")
+                .match_body(Matcher::Json(json!({
+                    "refresh_token": {
+                        "rotation_type": "non-rotating",
+                        "expiration_type": "non-expiring"
+                    }
+                })))
+                .with_status(200)
+                .with_body(application().to_string())
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateClientPayload {
+                refresh_token: Some(RefreshTokenConfiguration {
+                    rotation_type: Some(RotationType::NonRotating),
+                    expiration_type: Some(ExpirationType::NonExpiring),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            client
+                .update_client("app_client_id", &payload)
+                .await
+                .unwrap();
+        }
+    }
+
+    mod delete_client {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/clients/app_client_id")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client.delete_client("app_client_id").await.unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod rotate_client_secret {
+        use super::*;
+
+        fn rotate_mock(client_id: &str) -> mockito::Mock {
+            let mut application = application();
+            application["client_id"] = json!(client_id);
+            application["client_secret"] = json!("rotated_client_secret");
+
+            mock(
+                "POST",
+                format!("/clients/{client_id}/rotate-secret").as_str(),
+            )
+            .with_status(200)
+            .with_body(application.to_string())
+            .create()
+        }
+
+        #[tokio::test]
+        async fn return_the_new_secret() {
+            let _m = rotate_mock("app_client_id");
+            let mut client = new_client();
+
+            let resp = client.rotate_client_secret("app_client_id").await.unwrap();
+
+            assert_eq!(resp.client_secret.as_deref(), Some("rotated_client_secret"));
+            assert_eq!(client.client_secret, "client_secret");
+        }
+
+        #[tokio::test]
+        async fn update_the_own_secret() {
+            let _m = rotate_mock("client_id");
+            let mut client = new_client();
+
+            client.rotate_own_client_secret().await.unwrap();
+
+            assert_eq!(client.client_secret, "rotated_client_secret");
+        }
+    }
+}
@@ -0,0 +1,753 @@
+//! Types, traits and functions relative to the connections API.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::connections::options::ConnectionOptions;
+use crate::management::{checkpoint_path, ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+pub mod options;
+
+/// Trait for operating the connections of the tenant.
+#[async_trait]
+pub trait OperateConnections {
+    /// Lists the connections of the tenant through the Auth0 connections API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the connections to list.
+    /// * `strategy` - Only lists the connections with this strategy, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_connections(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::connections::OperateConnections;
+    /// let connections = client
+    ///     .list_connections(&Default::default(), Some("samlp"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_connections(
+        &mut self,
+        params: &ListParams,
+        strategy: Option<&str>,
+    ) -> Auth0Result<Page<Connection>>;
+
+    /// Gets a connection through the Auth0 connections API.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_connection(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::connections::OperateConnections;
+    /// # use auth0_client::management::connections::options::DatabaseOptions;
+    /// let connection = client.get_connection("con_0tK3QKvbSvXcXz7w").await?;
+    ///
+    /// if let Some(options) = connection.options::<DatabaseOptions>()? {
+    ///     println!("Password policy: {:?}", options.password_policy);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_connection(&mut self, connection_id: &str) -> Auth0Result<Connection>;
+
+    /// Creates a connection through the Auth0 connections API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create a connection.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_connection(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::connections::{CreateConnectionPayload, OperateConnections};
+    /// # use auth0_client::management::connections::options::OidcOptions;
+    /// let options = OidcOptions {
+    ///     discovery_url: Some("https://idp.example.com/.well-known/openid-configuration".to_owned()),
+    ///     client_id: Some("client_id".to_owned()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let connection = client
+    ///     .create_connection(&CreateConnectionPayload::with_options("acme-oidc", options))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_connection<O>(
+        &mut self,
+        payload: &CreateConnectionPayload<O>,
+    ) -> Auth0Result<Connection>
+    where
+        O: Serialize + Send + Sync;
+
+    /// Updates a connection through the Auth0 connections API.
+    ///
+    /// The options of the payload replace the options of the connection as a whole.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection to update.
+    /// * `payload` - The fields of the connection to update.
+    async fn update_connection<O>(
+        &mut self,
+        connection_id: &str,
+        payload: &UpdateConnectionPayload<O>,
+    ) -> Auth0Result<Connection>
+    where
+        O: Serialize + Send + Sync;
+
+    /// Deletes a connection through the Auth0 connections API, along with its users.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection to delete.
+    async fn delete_connection(&mut self, connection_id: &str) -> Auth0Result<()>;
+
+    /// Checks that an enterprise connection is online through the Auth0 connections API.
+    ///
+    /// An error is returned when the connection cannot reach its identity provider.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection to check.
+    async fn check_connection_status(&mut self, connection_id: &str) -> Auth0Result<()>;
+
+    /// Replaces the applications a connection is enabled for through the Auth0 connections API.
+    ///
+    /// It updates the `enabled_clients` of the connection, removing the applications missing
+    /// from `client_ids`. [`OperateConnections::update_connection_clients`] only updates the
+    /// given applications instead.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection.
+    /// * `client_ids` - The client IDs of every application to enable the connection for.
+    async fn set_enabled_clients(
+        &mut self,
+        connection_id: &str,
+        client_ids: &[&str],
+    ) -> Auth0Result<Connection>;
+
+    /// Lists the applications a connection is enabled for through the Auth0 connections API.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection.
+    /// * `from` - The `next` checkpoint of the previous page, if any.
+    /// * `take` - The number of applications per page, 50 by default.
+    async fn list_connection_clients(
+        &mut self,
+        connection_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<ConnectionClientsPage>;
+
+    /// Enables or disables a connection for applications through the Auth0 connections API.
+    ///
+    /// The connection is left as is for the applications missing from `clients`.
+    ///
+    /// # Arguments
+    /// * `connection_id` - The ID of the connection.
+    /// * `clients` - The applications to enable or disable the connection for.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn update_clients(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::connections::{ConnectionClientStatus, OperateConnections};
+    /// let clients = [
+    ///     ConnectionClientStatus::enabled("new_client_id"),
+    ///     ConnectionClientStatus::disabled("old_client_id"),
+    /// ];
+    ///
+    /// client
+    ///     .update_connection_clients("con_0tK3QKvbSvXcXz7w", &clients)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn update_connection_clients(
+        &mut self,
+        connection_id: &str,
+        clients: &[ConnectionClientStatus],
+    ) -> Auth0Result<()>;
+}
+
+/// A connection of the tenant, i.e. a source of users.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Connection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub display_name: Option<String>,
+    /// The options of the strategy, see [`Connection::options`].
+    pub options: Option<Value>,
+    /// The client IDs of the applications the connection is enabled for.
+    #[serde(default)]
+    pub enabled_clients: Vec<String>,
+    #[serde(default)]
+    pub realms: Vec<String>,
+    pub is_domain_connection: Option<bool>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl Connection {
+    /// Deserializes the options of the connection, or returns `None` if its strategy differs.
+    pub fn options<O: ConnectionOptions>(&self) -> Auth0Result<Option<O>> {
+        match &self.options {
+            Some(options) if self.strategy == O::STRATEGY => {
+                Ok(Some(serde_json::from_value(options.clone())?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// A page of the applications a connection is enabled for.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConnectionClientsPage {
+    pub clients: Vec<ConnectionClient>,
+    /// The checkpoint of the next page, if there are more applications.
+    pub next: Option<String>,
+}
+
+/// An application a connection is enabled for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ConnectionClient {
+    pub client_id: String,
+}
+
+/// Whether a connection is enabled for an application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConnectionClientStatus {
+    pub client_id: String,
+    /// `true` to enable the connection for the application, `false` to disable it.
+    pub status: bool,
+}
+
+impl ConnectionClientStatus {
+    /// Enables the connection for the application with the given client ID.
+    pub fn enabled(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_owned(),
+            status: true,
+        }
+    }
+
+    /// Disables the connection for the application with the given client ID.
+    pub fn disabled(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_owned(),
+            status: false,
+        }
+    }
+}
+
+/// A struct containing the payload for creating a connection.
+///
+/// `O` is the type of the options, typed for the common strategies in [`options`].
+#[derive(Serialize)]
+pub struct CreateConnectionPayload<O = Value> {
+    pub name: String,
+    pub strategy: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<O>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateConnectionPayload {
+    /// Returns a payload for creating a connection with untyped options.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the connection.
+    /// * `strategy` - The strategy of the connection, e.g. `waad`.
+    pub fn new(name: &str, strategy: &str) -> Self {
+        Self::empty(name, strategy)
+    }
+}
+
+impl<O: ConnectionOptions> CreateConnectionPayload<O> {
+    /// Returns a payload for creating a connection with the strategy of the options.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the connection.
+    /// * `options` - The options of the connection.
+    pub fn with_options(name: &str, options: O) -> Self {
+        Self {
+            options: Some(options),
+            ..Self::empty(name, O::STRATEGY)
+        }
+    }
+}
+
+impl<O> CreateConnectionPayload<O> {
+    fn empty(name: &str, strategy: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            strategy: strategy.to_owned(),
+            display_name: None,
+            options: None,
+            enabled_clients: None,
+            realms: None,
+            is_domain_connection: None,
+            metadata: None,
+        }
+    }
+}
+
+/// A struct containing the payload for updating a connection.
+#[derive(Serialize)]
+pub struct UpdateConnectionPayload<O = Value> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<O>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl<O> Default for UpdateConnectionPayload<O> {
+    fn default() -> Self {
+        Self {
+            display_name: None,
+            options: None,
+            enabled_clients: None,
+            realms: None,
+            is_domain_connection: None,
+            metadata: None,
+        }
+    }
+}
+
+#[async_trait]
+impl OperateConnections for Auth0Client {
+    async fn list_connections(
+        &mut self,
+        params: &ListParams,
+        strategy: Option<&str>,
+    ) -> Auth0Result<Page<Connection>> {
+        let path = match strategy {
+            Some(strategy) => format!("/connections?strategy={}", urlencoding::encode(strategy)),
+            None => "/connections".to_owned(),
+        };
+
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_connection(&mut self, connection_id: &str) -> Auth0Result<Connection> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/connections/{connection_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_connection<O>(
+        &mut self,
+        payload: &CreateConnectionPayload<O>,
+    ) -> Auth0Result<Connection>
+    where
+        O: Serialize + Send + Sync,
+    {
+        self.request::<_, _, ManagementError>(Method::POST, "/connections", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_connection<O>(
+        &mut self,
+        connection_id: &str,
+        payload: &UpdateConnectionPayload<O>,
+    ) -> Auth0Result<Connection>
+    where
+        O: Serialize + Send + Sync,
+    {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/connections/{connection_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_connection(&mut self, connection_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/connections/{connection_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_connection_status(&mut self, connection_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::GET,
+            &format!("/connections/{connection_id}/status"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_enabled_clients(
+        &mut self,
+        connection_id: &str,
+        client_ids: &[&str],
+    ) -> Auth0Result<Connection> {
+        let payload: UpdateConnectionPayload = UpdateConnectionPayload {
+            enabled_clients: Some(client_ids.iter().map(|id| id.to_string()).collect()),
+            ..Default::default()
+        };
+
+        self.update_connection(connection_id, &payload).await
+    }
+
+    async fn list_connection_clients(
+        &mut self,
+        connection_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<ConnectionClientsPage> {
+        let path = checkpoint_path(&format!("/connections/{connection_id}/clients"), from, take);
+
+        self.request::<_, _, ManagementError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_connection_clients(
+        &mut self,
+        connection_id: &str,
+        clients: &[ConnectionClientStatus],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::PATCH,
+            &format!("/connections/{connection_id}/clients"),
+            Some(clients),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::connections::options::{DatabaseOptions, OidcOptions, SamlpOptions};
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn database_connection() -> Value {
+        json!({
+            "id": "con_0tK3QKvbSvXcXz7w",
+            "name": "Username-Password-Authentication",
+            "strategy": "auth0",
+            "options": {
+                "password_policy": "good",
+                "brute_force_protection": true,
+                "mfa": { "active": true }
+            },
+            "enabled_clients": ["client_id"],
+            "realms": ["Username-Password-Authentication"],
+            "is_domain_connection": false
+        })
+    }
+
+    mod options {
+        use super::*;
+
+        #[test]
+        fn keep_the_other_options() {
+            let connection: Connection = serde_json::from_value(database_connection()).unwrap();
+
+            let options = connection.options::<DatabaseOptions>().unwrap().unwrap();
+
+            assert_eq!(options.password_policy.as_deref(), Some("good"));
+            assert_eq!(
+                serde_json::to_value(&options).unwrap(),
+                database_connection()["options"]
+            );
+        }
+
+        #[test]
+        fn ignore_other_strategies() {
+            let connection: Connection = serde_json::from_value(database_connection()).unwrap();
+
+            assert_eq!(connection.options::<SamlpOptions>().unwrap(), None);
+        }
+    }
+
+    mod list_connections {
+        use super::*;
+
+        #[tokio::test]
+        async fn filter_by_strategy() {
+            let _m = mock("GET", "/connections")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("strategy".to_owned(), "auth0".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "10".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(json!([database_connection()]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_connections(&ListParams::default().per_page(10), Some("auth0"))
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].enabled_clients, vec!["client_id"]);
+        }
+    }
+
+    mod get_connection {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/connections/con_0tK3QKvbSvXcXz7w")
+                .with_status(200)
+                .with_body(database_connection().to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_connection("con_0tK3QKvbSvXcXz7w").await.unwrap();
+
+            assert_eq!(resp.strategy, "auth0");
+        }
+    }
+
+    mod create_connection {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_strategy_of_the_options() {
+            let _m = mock("POST", "/connections")
+                .match_body(Matcher::Json(json!({
+                    "name": "acme-oidc",
+                    "strategy": "oidc",
+                    "options": {
+                        "type": "back_channel",
+                        "discovery_url": "https://idp.example.com/.well-known/openid-configuration"
+                    }
+                })))
+                .with_status(201)
+                .with_body(
+                    json!({
+                        "id": "con_1tK3QKvbSvXcXz7w",
+                        "name": "acme-oidc",
+                        "strategy": "oidc",
+                        "options": {
+                            "type": "back_channel",
+                            "discovery_url": "https://idp.example.com/.well-known/openid-configuration"
+                        }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let options = OidcOptions {
+                channel: Some("back_channel".to_owned()),
+                discovery_url: Some(
+                    "https://idp.example.com/.well-known/openid-configuration".to_owned(),
+                ),
+                ..Default::default()
+            };
+            let resp = client
+                .create_connection(&CreateConnectionPayload::with_options(
+                    "acme-oidc",
+                    options.clone(),
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.options::<OidcOptions>().unwrap(), Some(options));
+        }
+    }
+
+    mod update_connection {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let _m = mock("PATCH", "/connections/con_0tK3QKvbSvXcXz7w")
+                .match_body(Matcher::Json(json!({ "display_name": "Email" })))
+                .with_status(200)
+                .with_body(database_connection().to_string())
+                .create();
+            let mut client = new_client();
+
+            let payload: UpdateConnectionPayload = UpdateConnectionPayload {
+                display_name: Some("Email".to_owned()),
+                ..Default::default()
+            };
+            client
+                .update_connection("con_0tK3QKvbSvXcXz7w", &payload)
+                .await
+                .unwrap();
+        }
+    }
+
+    mod delete_connection {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/connections/con_0tK3QKvbSvXcXz7w")
+                .with_status(202)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_connection("con_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod check_connection_status {
+        use super::*;
+
+        #[tokio::test]
+        async fn succeed_when_online() {
+            let _m = mock("GET", "/connections/con_0tK3QKvbSvXcXz7w/status")
+                .with_status(200)
+                .create();
+            let mut client = new_client();
+
+            client
+                .check_connection_status("con_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn errored_when_offline() {
+            let _m = mock("GET", "/connections/con_2tK3QKvbSvXcXz7w/status")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "Connection is offline"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.check_connection_status("con_2tK3QKvbSvXcXz7w").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod enabled_clients {
+        use super::*;
+
+        #[tokio::test]
+        async fn set_enabled_clients() {
+            let m = mock("PATCH", "/connections/con_3tK3QKvbSvXcXz7w")
+                .match_body(Matcher::Json(json!({
+                    "enabled_clients": ["client_id", "other_client_id"]
+                })))
+                .with_status(200)
+                .with_body(database_connection().to_string())
+                .create();
+            let mut client = new_client();
+
+            client
+                .set_enabled_clients("con_3tK3QKvbSvXcXz7w", &["client_id", "other_client_id"])
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn list_connection_clients() {
+            let _m = mock("GET", "/connections/con_0tK3QKvbSvXcXz7w/clients")
+                .match_query(Matcher::UrlEncoded("take".to_owned(), "50".to_owned()))
+                .with_status(200)
+                .with_body(
+                    json!({ "clients": [{ "client_id": "client_id" }], "next": "Mg==" })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_connection_clients("con_0tK3QKvbSvXcXz7w", None, None)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.clients[0].client_id, "client_id");
+            assert_eq!(resp.next.as_deref(), Some("Mg=="));
+        }
+
+        #[tokio::test]
+        async fn update_connection_clients() {
+            let m = mock("PATCH", "/connections/con_0tK3QKvbSvXcXz7w/clients")
+                .match_body(Matcher::Json(json!([
+                    { "client_id": "new_client_id", "status": true },
+                    { "client_id": "old_client_id", "status": false }
+                ])))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .update_connection_clients(
+                    "con_0tK3QKvbSvXcXz7w",
+                    &[
+                        ConnectionClientStatus::enabled("new_client_id"),
+                        ConnectionClientStatus::disabled("old_client_id"),
+                    ],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Typed options of the common connection strategies.
+//!
+//! The options only model the most used properties: every other property is kept in `other`,
+//! so that options read from a connection can be sent back without losing anything.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The options of a connection strategy.
+pub trait ConnectionOptions: Serialize + DeserializeOwned {
+    /// The name of the strategy, e.g. `auth0` for database connections.
+    const STRATEGY: &'static str;
+}
+
+/// The options of a database connection, with the `auth0` strategy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseOptions {
+    /// `none`, `low`, `fair`, `good` or `excellent`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_username: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brute_force_protection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_signup: Option<bool>,
+    /// Whether users are imported from a custom database on their first login.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_database_customization: Option<bool>,
+    /// The scripts of the custom database, by action, e.g. `login`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scripts: Option<HashMap<String, String>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl ConnectionOptions for DatabaseOptions {
+    const STRATEGY: &'static str = "auth0";
+}
+
+/// The options of a Google connection, with the `google-oauth2` strategy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoogleOAuth2Options {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    /// The client IDs of the native apps allowed to log in with their own Google tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_audiences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl ConnectionOptions for GoogleOAuth2Options {
+    const STRATEGY: &'static str = "google-oauth2";
+}
+
+/// The options of a SAML enterprise connection, with the `samlp` strategy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SamlpOptions {
+    #[serde(rename = "signInEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_in_endpoint: Option<String>,
+    #[serde(rename = "signOutEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_out_endpoint: Option<String>,
+    /// The base64-encoded signing certificate of the identity provider.
+    #[serde(rename = "signingCert", skip_serializing_if = "Option::is_none")]
+    pub signing_cert: Option<String>,
+    #[serde(rename = "signSAMLRequest", skip_serializing_if = "Option::is_none")]
+    pub sign_saml_request: Option<bool>,
+    /// `rsa-sha256` or `rsa-sha1`.
+    #[serde(rename = "signatureAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub signature_algorithm: Option<String>,
+    #[serde(rename = "digestAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub digest_algorithm: Option<String>,
+    #[serde(rename = "protocolBinding", skip_serializing_if = "Option::is_none")]
+    pub protocol_binding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id_attribute: Option<String>,
+    /// The email domains of the users redirected to the connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl ConnectionOptions for SamlpOptions {
+    const STRATEGY: &'static str = "samlp";
+}
+
+/// The options of an OpenID Connect enterprise connection, with the `oidc` strategy.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OidcOptions {
+    /// `front_channel` or `back_channel`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discovery_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userinfo_endpoint: Option<String>,
+    /// The space-separated scopes requested to the identity provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// The email domains of the users redirected to the connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl ConnectionOptions for OidcOptions {
+    const STRATEGY: &'static str = "oidc";
+}
@@ -0,0 +1,670 @@
+//! Types, traits and functions relative to the email provider API.
+//!
+//! The email provider sends the emails of the tenant, e.g. the verification emails, instead of
+//! the test provider of Auth0.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::Error as _;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::ManagementError;
+use crate::Auth0Client;
+
+/// Trait for operating the email provider of the tenant.
+#[async_trait]
+pub trait OperateEmailProvider {
+    /// Gets the email provider of the tenant through the Auth0 email provider API.
+    async fn get_email_provider(&mut self) -> Auth0Result<EmailProvider>;
+
+    /// Configures the email provider of the tenant through the Auth0 email provider API.
+    ///
+    /// # Arguments
+    /// * `payload` - The credentials of the provider and the sender of the emails.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn configure(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::email_provider::{
+    /// #     CreateEmailProviderPayload, EmailProviderCredentials, OperateEmailProvider,
+    /// # };
+    /// let mut payload = CreateEmailProviderPayload::new(EmailProviderCredentials::SendGrid {
+    ///     api_key: Some("api_key".to_owned()),
+    /// });
+    /// payload.default_from_address = Some("no-reply@acme.com".to_owned());
+    ///
+    /// client.create_email_provider(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_email_provider(
+        &mut self,
+        payload: &CreateEmailProviderPayload,
+    ) -> Auth0Result<EmailProvider>;
+
+    /// Updates the email provider of the tenant through the Auth0 email provider API.
+    ///
+    /// # Arguments
+    /// * `payload` - The properties to update.
+    async fn update_email_provider(
+        &mut self,
+        payload: &UpdateEmailProviderPayload,
+    ) -> Auth0Result<EmailProvider>;
+
+    /// Deletes the email provider of the tenant through the Auth0 email provider API.
+    async fn delete_email_provider(&mut self) -> Auth0Result<()>;
+}
+
+/// The email provider of the tenant.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EmailProvider {
+    /// The provider, with the credentials returned by Auth0.
+    #[serde(flatten)]
+    pub credentials: EmailProviderCredentials,
+    #[serde(default)]
+    pub enabled: bool,
+    /// The sender of the emails whose template does not set one.
+    pub default_from_address: Option<String>,
+    /// The settings specific to the provider, e.g. the headers of the SMTP messages.
+    pub settings: Option<Value>,
+}
+
+/// The credentials of an email provider.
+///
+/// The secrets, e.g. API keys and passwords, are write-only: Auth0 never returns them, so
+/// they are always `None` in the credentials read from Auth0, and are left out of `Debug`.
+#[derive(Clone, PartialEq)]
+pub enum EmailProviderCredentials {
+    /// Amazon SES.
+    Ses {
+        access_key_id: Option<String>,
+        secret_access_key: Option<String>,
+        region: Option<String>,
+    },
+    SendGrid {
+        api_key: Option<String>,
+    },
+    Mandrill {
+        api_key: Option<String>,
+    },
+    SparkPost {
+        api_key: Option<String>,
+        /// `eu`, or the US region if not set.
+        region: Option<String>,
+    },
+    Mailgun {
+        api_key: Option<String>,
+        domain: Option<String>,
+        /// `eu`, or the US region if not set.
+        region: Option<String>,
+    },
+    Smtp {
+        smtp_host: Option<String>,
+        smtp_port: Option<u16>,
+        smtp_user: Option<String>,
+        smtp_pass: Option<String>,
+    },
+    /// Azure Communication Services.
+    AzureCs {
+        connection_string: Option<String>,
+    },
+    /// Microsoft 365.
+    Ms365 {
+        tenant_id: Option<String>,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    },
+    /// A provider this crate does not know about yet, e.g. `custom`.
+    Other {
+        name: String,
+        credentials: Value,
+    },
+}
+
+impl std::fmt::Debug for EmailProviderCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ses {
+                access_key_id,
+                region,
+                ..
+            } => f
+                .debug_struct("Ses")
+                .field("access_key_id", access_key_id)
+                .field("region", region)
+                .finish_non_exhaustive(),
+            Self::SendGrid { .. } => f.debug_struct("SendGrid").finish_non_exhaustive(),
+            Self::Mandrill { .. } => f.debug_struct("Mandrill").finish_non_exhaustive(),
+            Self::SparkPost { region, .. } => f
+                .debug_struct("SparkPost")
+                .field("region", region)
+                .finish_non_exhaustive(),
+            Self::Mailgun { domain, region, .. } => f
+                .debug_struct("Mailgun")
+                .field("domain", domain)
+                .field("region", region)
+                .finish_non_exhaustive(),
+            Self::Smtp {
+                smtp_host,
+                smtp_port,
+                smtp_user,
+                ..
+            } => f
+                .debug_struct("Smtp")
+                .field("smtp_host", smtp_host)
+                .field("smtp_port", smtp_port)
+                .field("smtp_user", smtp_user)
+                .finish_non_exhaustive(),
+            Self::AzureCs { .. } => f.debug_struct("AzureCs").finish_non_exhaustive(),
+            Self::Ms365 {
+                tenant_id,
+                client_id,
+                ..
+            } => f
+                .debug_struct("Ms365")
+                .field("tenant_id", tenant_id)
+                .field("client_id", client_id)
+                .finish_non_exhaustive(),
+            Self::Other { name, .. } => f
+                .debug_struct("Other")
+                .field("name", name)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl EmailProviderCredentials {
+    /// Returns the name of the provider, e.g. `sendgrid`.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Ses { .. } => "ses",
+            Self::SendGrid { .. } => "sendgrid",
+            Self::Mandrill { .. } => "mandrill",
+            Self::SparkPost { .. } => "sparkpost",
+            Self::Mailgun { .. } => "mailgun",
+            Self::Smtp { .. } => "smtp",
+            Self::AzureCs { .. } => "azure_cs",
+            Self::Ms365 { .. } => "ms365",
+            Self::Other { name, .. } => name,
+        }
+    }
+
+    /// Returns the credentials as sent to Auth0, without the name of the provider.
+    fn to_value(&self) -> Value {
+        let mut credentials = serde_json::Map::new();
+        let mut insert = |key: &str, value: &Option<String>| {
+            if let Some(value) = value {
+                credentials.insert(key.to_owned(), Value::String(value.clone()));
+            }
+        };
+
+        match self {
+            Self::Ses {
+                access_key_id,
+                secret_access_key,
+                region,
+            } => {
+                insert("accessKeyId", access_key_id);
+                insert("secretAccessKey", secret_access_key);
+                insert("region", region);
+            }
+            Self::SendGrid { api_key } | Self::Mandrill { api_key } => insert("api_key", api_key),
+            Self::SparkPost { api_key, region } => {
+                insert("api_key", api_key);
+                insert("region", region);
+            }
+            Self::Mailgun {
+                api_key,
+                domain,
+                region,
+            } => {
+                insert("api_key", api_key);
+                insert("domain", domain);
+                insert("region", region);
+            }
+            Self::Smtp {
+                smtp_host,
+                smtp_port,
+                smtp_user,
+                smtp_pass,
+            } => {
+                insert("smtp_host", smtp_host);
+                insert("smtp_user", smtp_user);
+                insert("smtp_pass", smtp_pass);
+                if let Some(smtp_port) = smtp_port {
+                    credentials.insert("smtp_port".to_owned(), Value::from(*smtp_port));
+                }
+            }
+            Self::AzureCs { connection_string } => insert("connectionString", connection_string),
+            Self::Ms365 {
+                tenant_id,
+                client_id,
+                client_secret,
+            } => {
+                insert("tenantId", tenant_id);
+                insert("clientId", client_id);
+                insert("clientSecret", client_secret);
+            }
+            Self::Other { credentials, .. } => return credentials.clone(),
+        }
+
+        Value::Object(credentials)
+    }
+}
+
+impl Serialize for EmailProviderCredentials {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("EmailProviderCredentials", 2)?;
+        state.serialize_field("name", self.name())?;
+        state.serialize_field("credentials", &self.to_value())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailProviderCredentials {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawCredentials {
+            name: String,
+            #[serde(default)]
+            credentials: Value,
+        }
+
+        // The credentials returned by Auth0, without the write-only secrets.
+        #[derive(Default, Deserialize)]
+        #[serde(default)]
+        struct Returned {
+            #[serde(rename = "accessKeyId")]
+            access_key_id: Option<String>,
+            region: Option<String>,
+            domain: Option<String>,
+            smtp_host: Option<String>,
+            smtp_port: Option<u16>,
+            smtp_user: Option<String>,
+            #[serde(rename = "tenantId")]
+            tenant_id: Option<String>,
+            #[serde(rename = "clientId")]
+            client_id: Option<String>,
+        }
+
+        let RawCredentials { name, credentials } = RawCredentials::deserialize(deserializer)?;
+        let returned: Returned = match &credentials {
+            Value::Null => Returned::default(),
+            credentials => serde_json::from_value(credentials.clone()).map_err(D::Error::custom)?,
+        };
+
+        Ok(match name.as_str() {
+            "ses" => Self::Ses {
+                access_key_id: returned.access_key_id,
+                secret_access_key: None,
+                region: returned.region,
+            },
+            "sendgrid" => Self::SendGrid { api_key: None },
+            "mandrill" => Self::Mandrill { api_key: None },
+            "sparkpost" => Self::SparkPost {
+                api_key: None,
+                region: returned.region,
+            },
+            "mailgun" => Self::Mailgun {
+                api_key: None,
+                domain: returned.domain,
+                region: returned.region,
+            },
+            "smtp" => Self::Smtp {
+                smtp_host: returned.smtp_host,
+                smtp_port: returned.smtp_port,
+                smtp_user: returned.smtp_user,
+                smtp_pass: None,
+            },
+            "azure_cs" => Self::AzureCs {
+                connection_string: None,
+            },
+            "ms365" => Self::Ms365 {
+                tenant_id: returned.tenant_id,
+                client_id: returned.client_id,
+                client_secret: None,
+            },
+            _ => Self::Other { name, credentials },
+        })
+    }
+}
+
+/// The payload for configuring the email provider.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateEmailProviderPayload {
+    #[serde(flatten)]
+    pub credentials: EmailProviderCredentials,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+impl CreateEmailProviderPayload {
+    pub fn new(credentials: EmailProviderCredentials) -> Self {
+        Self {
+            credentials,
+            enabled: None,
+            default_from_address: None,
+            settings: None,
+        }
+    }
+}
+
+/// The payload for updating the email provider.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UpdateEmailProviderPayload {
+    /// The new provider or the new credentials of the provider.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<EmailProviderCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+#[async_trait]
+impl OperateEmailProvider for Auth0Client {
+    async fn get_email_provider(&mut self) -> Auth0Result<EmailProvider> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::GET,
+            "/emails/provider",
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_email_provider(
+        &mut self,
+        payload: &CreateEmailProviderPayload,
+    ) -> Auth0Result<EmailProvider> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::POST,
+            "/emails/provider",
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_email_provider(
+        &mut self,
+        payload: &UpdateEmailProviderPayload,
+    ) -> Auth0Result<EmailProvider> {
+        self.sensitive_request::<_, _, ManagementError>(
+            Method::PATCH,
+            "/emails/provider",
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_email_provider(&mut self) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(Method::DELETE, "/emails/provider", None::<String>)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    mod email_provider_credentials {
+        use super::*;
+
+        #[test]
+        fn leave_the_secrets_out_of_debug() {
+            let credentials = EmailProviderCredentials::Smtp {
+                smtp_host: Some("smtp.example.com".to_owned()),
+                smtp_port: Some(587),
+                smtp_user: Some("mailer".to_owned()),
+                smtp_pass: Some("smtp_password".to_owned()),
+            };
+
+            let debug = format!("{credentials:?}");
+
+            assert!(debug.contains("smtp.example.com"));
+            assert!(!debug.contains("smtp_password"));
+        }
+
+        #[test]
+        fn keep_the_credentials_of_unknown_providers() {
+            let provider: EmailProvider = serde_json::from_value(json!({
+                "name": "custom",
+                "enabled": false,
+                "credentials": { "key": "value" }
+            }))
+            .unwrap();
+
+            assert_eq!(provider.credentials.name(), "custom");
+            assert_eq!(
+                serde_json::to_value(&provider.credentials).unwrap(),
+                json!({ "name": "custom", "credentials": { "key": "value" } })
+            );
+        }
+    }
+
+    mod get_email_provider {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/emails/provider")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "name": "smtp",
+                        "enabled": true,
+                        "default_from_address": "no-reply@acme.com",
+                        "credentials": {
+                            "smtp_host": "smtp.acme.com",
+                            "smtp_port": 587,
+                            "smtp_user": "mailer"
+                        }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_email_provider().await.unwrap();
+
+            assert_eq!(
+                resp.credentials,
+                EmailProviderCredentials::Smtp {
+                    smtp_host: Some("smtp.acme.com".to_owned()),
+                    smtp_port: Some(587),
+                    smtp_user: Some("mailer".to_owned()),
+                    smtp_pass: None,
+                }
+            );
+            assert_eq!(
+                resp.default_from_address.as_deref(),
+                Some("no-reply@acme.com")
+            );
+        }
+
+        #[tokio::test]
+        async fn errored_without_provider() {
+            let _m = mock("GET", "/emails/provider")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "There is not an email provider configured.",
+                        "errorCode": "inexistent_email_provider"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_email_provider().await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod create_email_provider {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_credentials() {
+            let _m = mock("POST", "/emails/provider")
+                .match_body(Matcher::Json(json!({
+                    "name": "ses",
+                    "credentials": {
+                        "accessKeyId": "AKIA0000",
+                        "secretAccessKey": "s3cr3t",
+                        "region": "eu-west-1"
+                    },
+                    "enabled": true
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "name": "ses",
+                        "enabled": true,
+                        "credentials": { "accessKeyId": "AKIA0000", "region": "eu-west-1" }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload = CreateEmailProviderPayload::new(EmailProviderCredentials::Ses {
+                access_key_id: Some("AKIA0000".to_owned()),
+                secret_access_key: Some("s3cr3t".to_owned()),
+                region: Some("eu-west-1".to_owned()),
+            });
+            payload.enabled = Some(true);
+            let resp = client.create_email_provider(&payload).await.unwrap();
+
+            match resp.credentials {
+                EmailProviderCredentials::Ses {
+                    secret_access_key, ..
+                } => assert_eq!(secret_access_key, None),
+                credentials => panic!("Expected Ses variant, got: {credentials:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_with_existing_provider() {
+            let _m = mock("POST", "/emails/provider")
+                .with_status(409)
+                .with_body(
+                    json!({
+                        "statusCode": 409,
+                        "error": "Conflict",
+                        "message": "An email provider is already configured."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = CreateEmailProviderPayload::new(EmailProviderCredentials::SendGrid {
+                api_key: Some("api_key".to_owned()),
+            });
+            let resp = client.create_email_provider(&payload).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::Conflict(_))) => (),
+                _ => panic!("Expected Conflict variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_email_provider {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let m = mock("PATCH", "/emails/provider")
+                .match_body(Matcher::Json(json!({
+                    "name": "sendgrid",
+                    "credentials": { "api_key": "new_api_key" }
+                })))
+                .with_status(200)
+                .with_body(json!({ "name": "sendgrid", "enabled": true }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateEmailProviderPayload {
+                credentials: Some(EmailProviderCredentials::SendGrid {
+                    api_key: Some("new_api_key".to_owned()),
+                }),
+                ..Default::default()
+            };
+            let resp = client.update_email_provider(&payload).await.unwrap();
+
+            assert_eq!(resp.credentials.name(), "sendgrid");
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_without_provider() {
+            let _m = mock("PATCH", "/emails/provider")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "There is not an email provider configured.",
+                        "errorCode": "inexistent_email_provider"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .update_email_provider(&UpdateEmailProviderPayload::default())
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod delete_email_provider {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/emails/provider").with_status(204).create();
+            let mut client = new_client();
+
+            client.delete_email_provider().await.unwrap();
+
+            m.assert();
+        }
+    }
+}
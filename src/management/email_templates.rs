@@ -0,0 +1,455 @@
+//! Types, traits and functions relative to the email templates API.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::ManagementError;
+use crate::Auth0Client;
+
+/// Trait for operating the email templates of the tenant.
+#[async_trait]
+pub trait OperateEmailTemplates {
+    /// Gets an email template through the Auth0 email templates API.
+    ///
+    /// # Arguments
+    /// * `template` - The name of the template to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_template(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::email_templates::{EmailTemplateName, OperateEmailTemplates};
+    /// let template = client.get_email_template(EmailTemplateName::VerifyEmail).await?;
+    ///
+    /// println!("Subject: {:?}", template.subject);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_email_template(
+        &mut self,
+        template: EmailTemplateName,
+    ) -> Auth0Result<EmailTemplate>;
+
+    /// Creates an email template through the Auth0 email templates API.
+    ///
+    /// It fails if the template was already created, in which case it should be updated instead.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create a template.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_template(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::email_templates::{
+    /// #     CreateEmailTemplatePayload, EmailTemplateName, OperateEmailTemplates,
+    /// # };
+    /// let body = std::fs::read_to_string("emails/welcome.liquid").unwrap();
+    /// let payload = CreateEmailTemplatePayload::new(
+    ///     EmailTemplateName::WelcomeEmail,
+    ///     "Acme <no-reply@acme.com>",
+    ///     "Welcome to Acme",
+    ///     &body,
+    /// );
+    ///
+    /// client.create_email_template(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_email_template(
+        &mut self,
+        payload: &CreateEmailTemplatePayload,
+    ) -> Auth0Result<EmailTemplate>;
+
+    /// Updates an email template through the Auth0 email templates API.
+    ///
+    /// # Arguments
+    /// * `template` - The name of the template to update.
+    /// * `payload` - The properties to update.
+    async fn update_email_template(
+        &mut self,
+        template: EmailTemplateName,
+        payload: &UpdateEmailTemplatePayload,
+    ) -> Auth0Result<EmailTemplate>;
+}
+
+/// The name of an email template, i.e. the email it is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateName {
+    /// The link to verify the email address of a user.
+    VerifyEmail,
+    /// The code to verify the email address of a user.
+    VerifyEmailByCode,
+    /// The link to reset the password of a user.
+    ResetEmail,
+    /// The code to reset the password of a user.
+    ResetEmailByCode,
+    /// Sent once the email address of a user is verified.
+    WelcomeEmail,
+    /// Sent when an account is blocked after too many failed logins.
+    BlockedAccount,
+    /// Sent when the credentials of a user were found in a breach.
+    StolenCredentials,
+    /// The link to enroll a user in multi-factor authentication.
+    EnrollmentEmail,
+    /// The code of multi-factor authentication by email.
+    MfaOobCode,
+    /// The invitation of a user to an organization.
+    UserInvitation,
+    /// The legacy link to change the password of a user.
+    ChangePassword,
+    /// The legacy link to reset the password of a user.
+    PasswordReset,
+}
+
+impl EmailTemplateName {
+    /// Returns the name of the template, e.g. `verify_email`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::VerifyEmail => "verify_email",
+            Self::VerifyEmailByCode => "verify_email_by_code",
+            Self::ResetEmail => "reset_email",
+            Self::ResetEmailByCode => "reset_email_by_code",
+            Self::WelcomeEmail => "welcome_email",
+            Self::BlockedAccount => "blocked_account",
+            Self::StolenCredentials => "stolen_credentials",
+            Self::EnrollmentEmail => "enrollment_email",
+            Self::MfaOobCode => "mfa_oob_code",
+            Self::UserInvitation => "user_invitation",
+            Self::ChangePassword => "change_password",
+            Self::PasswordReset => "password_reset",
+        }
+    }
+}
+
+/// An email template of the tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplate {
+    pub template: EmailTemplateName,
+    /// The body of the email, in the syntax of the template.
+    pub body: Option<String>,
+    /// The sender of the email, e.g. `Acme <no-reply@acme.com>`.
+    pub from: Option<String>,
+    pub subject: Option<String>,
+    /// The syntax of the body and the subject, always `liquid`.
+    pub syntax: Option<String>,
+    /// The URL the user is redirected to after following the link of the email.
+    pub result_url: Option<String>,
+    /// The lifetime of the link of the email, in seconds.
+    pub url_lifetime_in_seconds: Option<u64>,
+    /// Whether the email address of the user is added to the query of the `result_url`.
+    pub include_email_in_redirect: Option<bool>,
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// The payload for creating an email template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmailTemplatePayload {
+    pub template: EmailTemplateName,
+    pub from: String,
+    pub subject: String,
+    pub body: String,
+    pub syntax: String,
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+}
+
+impl CreateEmailTemplatePayload {
+    /// Returns the payload of an enabled template, with a body and a subject in Liquid.
+    pub fn new(template: EmailTemplateName, from: &str, subject: &str, body: &str) -> Self {
+        Self {
+            template,
+            from: from.to_owned(),
+            subject: subject.to_owned(),
+            body: body.to_owned(),
+            syntax: "liquid".to_owned(),
+            enabled: true,
+            result_url: None,
+            url_lifetime_in_seconds: None,
+            include_email_in_redirect: None,
+        }
+    }
+}
+
+/// The payload for updating an email template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailTemplatePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+}
+
+#[async_trait]
+impl OperateEmailTemplates for Auth0Client {
+    async fn get_email_template(
+        &mut self,
+        template: EmailTemplateName,
+    ) -> Auth0Result<EmailTemplate> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/email-templates/{}", template.as_str()),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_email_template(
+        &mut self,
+        payload: &CreateEmailTemplatePayload,
+    ) -> Auth0Result<EmailTemplate> {
+        self.request::<_, _, ManagementError>(Method::POST, "/email-templates", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_email_template(
+        &mut self,
+        template: EmailTemplateName,
+        payload: &UpdateEmailTemplatePayload,
+    ) -> Auth0Result<EmailTemplate> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/email-templates/{}", template.as_str()),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::{json, Value};
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn email_template() -> Value {
+        json!({
+            "template": "welcome_email",
+            "body": "<p>Welcome {{ user.name }}!</p>",
+            "from": "Acme <no-reply@acme.com>",
+            "subject": "Welcome to Acme",
+            "syntax": "liquid",
+            "resultUrl": "https://acme.com/welcome",
+            "urlLifetimeInSeconds": 432000,
+            "enabled": true
+        })
+    }
+
+    mod get_email_template {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/email-templates/welcome_email")
+                .with_status(200)
+                .with_body(email_template().to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_email_template(EmailTemplateName::WelcomeEmail)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.template, EmailTemplateName::WelcomeEmail);
+            assert_eq!(resp.url_lifetime_in_seconds, Some(432000));
+            assert_eq!(resp.result_url.as_deref(), Some("https://acme.com/welcome"));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_template() {
+            let _m = mock("GET", "/email-templates/blocked_account")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "Template not found"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_email_template(EmailTemplateName::BlockedAccount)
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod create_email_template {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/email-templates")
+                .match_body(Matcher::Json(json!({
+                    "template": "welcome_email",
+                    "from": "Acme <no-reply@acme.com>",
+                    "subject": "Welcome to Acme",
+                    "body": "<p>Welcome {{ user.name }}!</p>",
+                    "syntax": "liquid",
+                    "enabled": true,
+                    "resultUrl": "https://acme.com/welcome"
+                })))
+                .with_status(200)
+                .with_body(email_template().to_string())
+                .create();
+            let mut client = new_client();
+
+            let mut payload = CreateEmailTemplatePayload::new(
+                EmailTemplateName::WelcomeEmail,
+                "Acme <no-reply@acme.com>",
+                "Welcome to Acme",
+                "<p>Welcome {{ user.name }}!</p>",
+            );
+            payload.result_url = Some("https://acme.com/welcome".to_owned());
+            let resp = client.create_email_template(&payload).await.unwrap();
+
+            assert!(resp.enabled);
+        }
+
+        #[tokio::test]
+        async fn errored_with_existing_template() {
+            let _m = mock("POST", "/email-templates")
+                .with_status(409)
+                .with_body(
+                    json!({
+                        "statusCode": 409,
+                        "error": "Conflict",
+                        "message": "Template welcome_email already exists."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = CreateEmailTemplatePayload::new(
+                EmailTemplateName::WelcomeEmail,
+                "Acme <no-reply@acme.com>",
+                "Welcome to Acme",
+                "<p>Welcome {{ user.name }}!</p>",
+            );
+            let resp = client.create_email_template(&payload).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::Conflict(_))) => (),
+                _ => panic!("Expected Conflict variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_email_template {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let m = mock("PATCH", "/email-templates/verify_email_by_code")
+                .match_body(Matcher::Json(json!({
+                    "subject": "Your code",
+                    "urlLifetimeInSeconds": 3600
+                })))
+                .with_status(200)
+                .with_body({
+                    let mut template = email_template();
+                    template["template"] = json!("verify_email_by_code");
+                    template.to_string()
+                })
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateEmailTemplatePayload {
+                subject: Some("Your code".to_owned()),
+                url_lifetime_in_seconds: Some(3600),
+                ..Default::default()
+            };
+            client
+                .update_email_template(EmailTemplateName::VerifyEmailByCode, &payload)
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_invalid_body() {
+            let _m = mock("PATCH", "/email-templates/verify_email_by_code")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "statusCode": 400,
+                        "error": "Bad Request",
+                        "message": "Payload validation error: 'Expected type integer but found type string' on property urlLifetimeInSeconds.",
+                        "errorCode": "invalid_body"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .update_email_template(
+                    EmailTemplateName::VerifyEmailByCode,
+                    &UpdateEmailTemplatePayload::default(),
+                )
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::InvalidRequestBody(_))) => (),
+                _ => panic!("Expected InvalidRequestBody variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod email_template_name {
+        use super::*;
+
+        #[test]
+        fn serialize_the_template_names() {
+            for template in [
+                EmailTemplateName::VerifyEmail,
+                EmailTemplateName::MfaOobCode,
+                EmailTemplateName::PasswordReset,
+            ] {
+                assert_eq!(json!(template), template.as_str());
+            }
+        }
+    }
+}
@@ -0,0 +1,575 @@
+//! Types, traits and functions relative to the hooks API.
+//!
+//! Hooks are the legacy extension points of Auth0, replaced by the actions.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the hooks of the tenant.
+#[async_trait]
+pub trait OperateHooks {
+    /// Lists the hooks of the tenant through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the hooks to list.
+    /// * `filter` - Only lists the matching hooks, if set.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_hooks(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::hooks::{HookFilter, HookTrigger, OperateHooks};
+    /// let filter = HookFilter {
+    ///     trigger_id: Some(HookTrigger::PreUserRegistration),
+    ///     enabled: Some(true),
+    /// };
+    ///
+    /// for hook in client.list_hooks(&Default::default(), &filter).await? {
+    ///     println!("{} still needs a migration", hook.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_hooks(
+        &mut self,
+        params: &ListParams,
+        filter: &HookFilter,
+    ) -> Auth0Result<Page<Hook>>;
+
+    /// Gets a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook to get.
+    async fn get_hook(&mut self, hook_id: &str) -> Auth0Result<Hook>;
+
+    /// Creates a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create a hook.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_hook(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::hooks::{CreateHookPayload, HookTrigger, OperateHooks};
+    /// let script = std::fs::read_to_string("hooks/client-credentials.js").unwrap();
+    /// let mut payload = CreateHookPayload::new("Add scopes", HookTrigger::CredentialsExchange, &script);
+    /// payload.enabled = Some(true);
+    ///
+    /// let hook = client.create_hook(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_hook(&mut self, payload: &CreateHookPayload) -> Auth0Result<Hook>;
+
+    /// Updates a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook to update.
+    /// * `payload` - The properties to update.
+    async fn update_hook(
+        &mut self,
+        hook_id: &str,
+        payload: &UpdateHookPayload,
+    ) -> Auth0Result<Hook>;
+
+    /// Deletes a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook to delete.
+    async fn delete_hook(&mut self, hook_id: &str) -> Auth0Result<()>;
+
+    /// Enables a hook through the Auth0 hooks API.
+    ///
+    /// Only one hook of a trigger can be enabled, except for `post-user-registration` hooks.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook to enable.
+    async fn enable_hook(&mut self, hook_id: &str) -> Auth0Result<Hook>;
+
+    /// Disables a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook to disable.
+    async fn disable_hook(&mut self, hook_id: &str) -> Auth0Result<Hook>;
+
+    /// Gets the secrets of a hook through the Auth0 hooks API.
+    ///
+    /// The values are never returned: every secret is mapped to [`HIDDEN_SECRET_VALUE`].
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook.
+    async fn get_hook_secrets(&mut self, hook_id: &str) -> Auth0Result<HashMap<String, String>>;
+
+    /// Adds secrets to a hook through the Auth0 hooks API.
+    ///
+    /// It fails if one of the secrets already exists.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook.
+    /// * `secrets` - The values of the secrets, by name.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn add_secrets(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::hooks::OperateHooks;
+    /// # use std::collections::HashMap;
+    /// let secrets = HashMap::from([("API_KEY".to_owned(), "s3cr3t".to_owned())]);
+    ///
+    /// client.add_hook_secrets("hook_id", &secrets).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn add_hook_secrets(
+        &mut self,
+        hook_id: &str,
+        secrets: &HashMap<String, String>,
+    ) -> Auth0Result<()>;
+
+    /// Updates existing secrets of a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook.
+    /// * `secrets` - The new values of the secrets, by name.
+    async fn update_hook_secrets(
+        &mut self,
+        hook_id: &str,
+        secrets: &HashMap<String, String>,
+    ) -> Auth0Result<()>;
+
+    /// Deletes secrets of a hook through the Auth0 hooks API.
+    ///
+    /// # Arguments
+    /// * `hook_id` - The ID of the hook.
+    /// * `names` - The names of the secrets to delete.
+    async fn delete_hook_secrets(&mut self, hook_id: &str, names: &[&str]) -> Auth0Result<()>;
+}
+
+/// The value of every secret returned by [`OperateHooks::get_hook_secrets`].
+pub const HIDDEN_SECRET_VALUE: &str = "_VALUE_NOT_SHOWN_";
+
+/// A hook of the tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Hook {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "triggerId")]
+    pub trigger_id: HookTrigger,
+    /// The Node.js code of the hook.
+    pub script: Option<String>,
+    #[serde(default)]
+    pub enabled: bool,
+    /// The versions of the npm packages required by the hook, by name.
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
+}
+
+/// The extension point a hook runs at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HookTrigger {
+    /// Before an access token is issued with the client credentials grant.
+    CredentialsExchange,
+    PreUserRegistration,
+    PostUserRegistration,
+    PostChangePassword,
+    /// When a code is sent by SMS with a custom provider.
+    SendPhoneMessage,
+    /// A trigger this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl HookTrigger {
+    /// Returns the ID of the trigger, e.g. `credentials-exchange`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CredentialsExchange => "credentials-exchange",
+            Self::PreUserRegistration => "pre-user-registration",
+            Self::PostUserRegistration => "post-user-registration",
+            Self::PostChangePassword => "post-change-password",
+            Self::SendPhoneMessage => "send-phone-message",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// The filters of [`OperateHooks::list_hooks`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookFilter {
+    pub trigger_id: Option<HookTrigger>,
+    pub enabled: Option<bool>,
+}
+
+/// The payload for creating a hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreateHookPayload {
+    pub name: String,
+    #[serde(rename = "triggerId")]
+    pub trigger_id: HookTrigger,
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub dependencies: HashMap<String, String>,
+}
+
+impl CreateHookPayload {
+    pub fn new(name: &str, trigger_id: HookTrigger, script: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            trigger_id,
+            script: script.to_owned(),
+            enabled: None,
+            dependencies: HashMap::new(),
+        }
+    }
+}
+
+/// The payload for updating a hook.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateHookPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Replaces the npm packages of the hook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<HashMap<String, String>>,
+}
+
+#[async_trait]
+impl OperateHooks for Auth0Client {
+    async fn list_hooks(
+        &mut self,
+        params: &ListParams,
+        filter: &HookFilter,
+    ) -> Auth0Result<Page<Hook>> {
+        let query = [
+            filter
+                .trigger_id
+                .map(|trigger_id| format!("triggerId={}", trigger_id.as_str())),
+            filter.enabled.map(|enabled| format!("enabled={enabled}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        let path = match query.is_empty() {
+            true => "/hooks".to_owned(),
+            false => format!("/hooks?{}", query.join("&")),
+        };
+
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_hook(&mut self, hook_id: &str) -> Auth0Result<Hook> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/hooks/{hook_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_hook(&mut self, payload: &CreateHookPayload) -> Auth0Result<Hook> {
+        self.request::<_, _, ManagementError>(Method::POST, "/hooks", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_hook(
+        &mut self,
+        hook_id: &str,
+        payload: &UpdateHookPayload,
+    ) -> Auth0Result<Hook> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/hooks/{hook_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_hook(&mut self, hook_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/hooks/{hook_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn enable_hook(&mut self, hook_id: &str) -> Auth0Result<Hook> {
+        let payload = UpdateHookPayload {
+            enabled: Some(true),
+            ..Default::default()
+        };
+
+        self.update_hook(hook_id, &payload).await
+    }
+
+    async fn disable_hook(&mut self, hook_id: &str) -> Auth0Result<Hook> {
+        let payload = UpdateHookPayload {
+            enabled: Some(false),
+            ..Default::default()
+        };
+
+        self.update_hook(hook_id, &payload).await
+    }
+
+    async fn get_hook_secrets(&mut self, hook_id: &str) -> Auth0Result<HashMap<String, String>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/hooks/{hook_id}/secrets"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn add_hook_secrets(
+        &mut self,
+        hook_id: &str,
+        secrets: &HashMap<String, String>,
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::POST,
+            &format!("/hooks/{hook_id}/secrets"),
+            Some(secrets),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_hook_secrets(
+        &mut self,
+        hook_id: &str,
+        secrets: &HashMap<String, String>,
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::PATCH,
+            &format!("/hooks/{hook_id}/secrets"),
+            Some(secrets),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_hook_secrets(&mut self, hook_id: &str, names: &[&str]) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/hooks/{hook_id}/secrets"),
+            Some(names),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::{json, Value};
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn hook() -> Value {
+        json!({
+            "id": "01234",
+            "name": "Add scopes",
+            "triggerId": "credentials-exchange",
+            "script": "module.exports = function(client, scope, audience, context, cb) {};",
+            "enabled": true,
+            "dependencies": { "axios": "1.2.0" }
+        })
+    }
+
+    #[tokio::test]
+    async fn list_hooks() {
+        let _m = mock("GET", "/hooks")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("triggerId".to_owned(), "credentials-exchange".to_owned()),
+                Matcher::UrlEncoded("enabled".to_owned(), "true".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(json!([hook()]).to_string())
+            .create();
+        let mut client = new_client();
+
+        let filter = HookFilter {
+            trigger_id: Some(HookTrigger::CredentialsExchange),
+            enabled: Some(true),
+        };
+        let resp = client
+            .list_hooks(&Default::default(), &filter)
+            .await
+            .unwrap();
+
+        assert_eq!(resp[0].trigger_id, HookTrigger::CredentialsExchange);
+        assert_eq!(resp[0].dependencies["axios"], "1.2.0");
+    }
+
+    #[tokio::test]
+    async fn create_hook() {
+        let _m = mock("POST", "/hooks")
+            .match_body(Matcher::Json(json!({
+                "name": "Add scopes",
+                "triggerId": "credentials-exchange",
+                "script": "module.exports = function(client, scope, audience, context, cb) {};",
+                "dependencies": { "axios": "1.2.0" }
+            })))
+            .with_status(201)
+            .with_body(hook().to_string())
+            .create();
+        let mut client = new_client();
+
+        let mut payload = CreateHookPayload::new(
+            "Add scopes",
+            HookTrigger::CredentialsExchange,
+            "module.exports = function(client, scope, audience, context, cb) {};",
+        );
+        payload
+            .dependencies
+            .insert("axios".to_owned(), "1.2.0".to_owned());
+        let resp = client.create_hook(&payload).await.unwrap();
+
+        assert_eq!(resp.id, "01234");
+    }
+
+    #[tokio::test]
+    async fn disable_hook() {
+        let m = mock("PATCH", "/hooks/01234")
+            .match_body(Matcher::Json(json!({ "enabled": false })))
+            .with_status(200)
+            .with_body(hook().to_string())
+            .create();
+        let mut client = new_client();
+
+        client.disable_hook("01234").await.unwrap();
+
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn delete_hook() {
+        let m = mock("DELETE", "/hooks/01234").with_status(204).create();
+        let mut client = new_client();
+
+        client.delete_hook("01234").await.unwrap();
+
+        m.assert();
+    }
+
+    mod secrets {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_hook_secrets() {
+            let _m = mock("GET", "/hooks/01234/secrets")
+                .with_status(200)
+                .with_body(json!({ "API_KEY": "_VALUE_NOT_SHOWN_" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_hook_secrets("01234").await.unwrap();
+
+            assert_eq!(resp["API_KEY"], HIDDEN_SECRET_VALUE);
+        }
+
+        #[tokio::test]
+        async fn add_hook_secrets() {
+            let m = mock("POST", "/hooks/01234/secrets")
+                .match_body(Matcher::Json(json!({ "API_KEY": "s3cr3t" })))
+                .with_status(201)
+                .create();
+            let mut client = new_client();
+
+            let secrets = HashMap::from([("API_KEY".to_owned(), "s3cr3t".to_owned())]);
+            client.add_hook_secrets("01234", &secrets).await.unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn update_existing_secrets_only() {
+            let _m = mock("PATCH", "/hooks/01234/secrets")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "Secret not found: OTHER_KEY"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let secrets = HashMap::from([("OTHER_KEY".to_owned(), "s3cr3t".to_owned())]);
+            let resp = client.update_hook_secrets("01234", &secrets).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn delete_hook_secrets() {
+            let m = mock("DELETE", "/hooks/01234/secrets")
+                .match_body(Matcher::Json(json!(["API_KEY"])))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_hook_secrets("01234", &["API_KEY"])
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn get_hook_not_found() {
+        let _m = mock("GET", "/hooks/unknown_hook")
+            .with_status(404)
+            .with_body(
+                json!({
+                    "statusCode": 404,
+                    "error": "Not Found",
+                    "message": "Hook not found"
+                })
+                .to_string(),
+            )
+            .create();
+        let mut client = new_client();
+
+        let resp = client.get_hook("unknown_hook").await;
+
+        match resp {
+            Err(Error::Management(ManagementError::NotFound(_))) => (),
+            _ => panic!("Expected NotFound variant, got: {resp:?}"),
+        }
+    }
+}
@@ -0,0 +1,806 @@
+//! Types, traits and functions relative to the log streams API.
+//!
+//! A log stream exports the logs of the tenant to an external service as they happen.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::Error as _;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::ManagementError;
+use crate::Auth0Client;
+
+/// Trait for operating the log streams of the tenant.
+#[async_trait]
+pub trait OperateLogStreams {
+    /// Lists the log streams of the tenant through the Auth0 log streams API.
+    async fn list_log_streams(&mut self) -> Auth0Result<Vec<LogStream>>;
+
+    /// Gets a log stream through the Auth0 log streams API.
+    ///
+    /// # Arguments
+    /// * `stream_id` - The ID of the log stream to get.
+    async fn get_log_stream(&mut self, stream_id: &str) -> Auth0Result<LogStream>;
+
+    /// Creates a log stream through the Auth0 log streams API.
+    ///
+    /// # Arguments
+    /// * `payload` - The name and the sink of the log stream.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_stream(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::log_streams::{
+    /// #     CreateLogStreamPayload, DatadogRegion, DatadogSink, LogStreamSink, OperateLogStreams,
+    /// # };
+    /// let sink = LogStreamSink::Datadog(DatadogSink {
+    ///     datadog_region: DatadogRegion::Eu,
+    ///     datadog_api_key: Some("api_key".to_owned()),
+    /// });
+    ///
+    /// let stream = client
+    ///     .create_log_stream(&CreateLogStreamPayload::new("Datadog", sink))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_log_stream(
+        &mut self,
+        payload: &CreateLogStreamPayload,
+    ) -> Auth0Result<LogStream>;
+
+    /// Updates a log stream through the Auth0 log streams API.
+    ///
+    /// The type of a log stream cannot change, so the sink must be of the same type.
+    ///
+    /// # Arguments
+    /// * `stream_id` - The ID of the log stream to update.
+    /// * `payload` - The properties to update.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn pause_stream(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::log_streams::{
+    /// #     LogStreamStatus, OperateLogStreams, UpdateLogStreamPayload,
+    /// # };
+    /// let payload = UpdateLogStreamPayload {
+    ///     status: Some(LogStreamStatus::Paused),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// client.update_log_stream("lst_0000000000012345", &payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn update_log_stream(
+        &mut self,
+        stream_id: &str,
+        payload: &UpdateLogStreamPayload,
+    ) -> Auth0Result<LogStream>;
+
+    /// Deletes a log stream through the Auth0 log streams API.
+    ///
+    /// # Arguments
+    /// * `stream_id` - The ID of the log stream to delete.
+    async fn delete_log_stream(&mut self, stream_id: &str) -> Auth0Result<()>;
+}
+
+/// A log stream of the tenant.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LogStream {
+    pub id: String,
+    pub name: String,
+    pub status: LogStreamStatus,
+    /// The service the logs are exported to, with its configuration.
+    #[serde(flatten)]
+    pub sink: LogStreamSink,
+    /// The categories of the exported events, all of them if empty.
+    #[serde(default)]
+    pub filters: Vec<LogStreamFilter>,
+}
+
+/// The status of a log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogStreamStatus {
+    Active,
+    Paused,
+    /// The log stream was suspended by Auth0 after too many delivery failures.
+    Suspended,
+    /// A status this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A category of the events exported by a log stream, e.g. `auth.login.fail`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogStreamFilter {
+    /// Always `category`.
+    #[serde(rename = "type")]
+    pub filter_type: String,
+    pub name: String,
+}
+
+impl LogStreamFilter {
+    /// Returns the filter of a category of events.
+    pub fn category(name: &str) -> Self {
+        Self {
+            filter_type: "category".to_owned(),
+            name: name.to_owned(),
+        }
+    }
+}
+
+/// The service a log stream exports the logs to, with its configuration.
+///
+/// The secrets of the sinks, e.g. API keys, are write-only and never returned by Auth0. They
+/// are left out of `Debug`, along with the configuration of the sinks of unknown types.
+#[derive(Clone, PartialEq)]
+pub enum LogStreamSink {
+    Http(HttpSink),
+    Datadog(DatadogSink),
+    Splunk(SplunkSink),
+    /// Amazon EventBridge.
+    EventBridge(EventBridgeSink),
+    /// Azure Event Grid.
+    EventGrid(EventGridSink),
+    /// A type of log stream this crate does not know about yet, e.g. `sumo`.
+    Other {
+        stream_type: String,
+        sink: Value,
+    },
+}
+
+impl std::fmt::Debug for LogStreamSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(sink) => f.debug_tuple("Http").field(sink).finish(),
+            Self::Datadog(sink) => f.debug_tuple("Datadog").field(sink).finish(),
+            Self::Splunk(sink) => f.debug_tuple("Splunk").field(sink).finish(),
+            Self::EventBridge(sink) => f.debug_tuple("EventBridge").field(sink).finish(),
+            Self::EventGrid(sink) => f.debug_tuple("EventGrid").field(sink).finish(),
+            Self::Other { stream_type, .. } => f
+                .debug_struct("Other")
+                .field("stream_type", stream_type)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+impl LogStreamSink {
+    /// Returns the type of the log stream, e.g. `datadog`.
+    pub fn stream_type(&self) -> &str {
+        match self {
+            Self::Http(_) => "http",
+            Self::Datadog(_) => "datadog",
+            Self::Splunk(_) => "splunk",
+            Self::EventBridge(_) => "eventbridge",
+            Self::EventGrid(_) => "eventgrid",
+            Self::Other { stream_type, .. } => stream_type,
+        }
+    }
+}
+
+/// The configuration of a sink, without its type.
+struct SinkConfig<'a>(&'a LogStreamSink);
+
+impl Serialize for SinkConfig<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            LogStreamSink::Http(sink) => sink.serialize(serializer),
+            LogStreamSink::Datadog(sink) => sink.serialize(serializer),
+            LogStreamSink::Splunk(sink) => sink.serialize(serializer),
+            LogStreamSink::EventBridge(sink) => sink.serialize(serializer),
+            LogStreamSink::EventGrid(sink) => sink.serialize(serializer),
+            LogStreamSink::Other { sink, .. } => sink.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for LogStreamSink {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LogStreamSink", 2)?;
+        state.serialize_field("type", self.stream_type())?;
+        state.serialize_field("sink", &SinkConfig(self))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LogStreamSink {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawSink {
+            #[serde(rename = "type")]
+            stream_type: String,
+            #[serde(default)]
+            sink: Value,
+        }
+
+        let RawSink { stream_type, sink } = RawSink::deserialize(deserializer)?;
+        let typed = match stream_type.as_str() {
+            "http" => serde_json::from_value(sink).map(Self::Http),
+            "datadog" => serde_json::from_value(sink).map(Self::Datadog),
+            "splunk" => serde_json::from_value(sink).map(Self::Splunk),
+            "eventbridge" => serde_json::from_value(sink).map(Self::EventBridge),
+            "eventgrid" => serde_json::from_value(sink).map(Self::EventGrid),
+            _ => return Ok(Self::Other { stream_type, sink }),
+        };
+
+        typed.map_err(D::Error::custom)
+    }
+}
+
+/// The configuration of a log stream sending the logs to an HTTP endpoint.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpSink {
+    pub http_endpoint: String,
+    /// The content type of the requests, `application/json` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_format: Option<HttpContentFormat>,
+    /// The `Authorization` header of the requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_authorization: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub http_custom_headers: Vec<HttpHeader>,
+}
+
+impl std::fmt::Debug for HttpSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpSink")
+            .field("http_endpoint", &self.http_endpoint)
+            .field("http_content_type", &self.http_content_type)
+            .field("http_content_format", &self.http_content_format)
+            .field("http_custom_headers", &self.http_custom_headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpSink {
+    /// Returns the configuration of a log stream sending the logs to an endpoint.
+    pub fn new(http_endpoint: &str) -> Self {
+        Self {
+            http_endpoint: http_endpoint.to_owned(),
+            http_content_type: None,
+            http_content_format: None,
+            http_authorization: None,
+            http_custom_headers: vec![],
+        }
+    }
+}
+
+/// How the events are batched in the requests of an HTTP log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpContentFormat {
+    /// An array of events.
+    JsonArray,
+    /// An event per line.
+    JsonLines,
+    /// A single event per request.
+    JsonObject,
+}
+
+/// A custom header of the requests of an HTTP log stream, whose value is left out of `Debug`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpHeader {
+    pub header: String,
+    pub value: String,
+}
+
+impl std::fmt::Debug for HttpHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpHeader")
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The configuration of a log stream sending the logs to Datadog.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatadogSink {
+    pub datadog_region: DatadogRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datadog_api_key: Option<String>,
+}
+
+impl std::fmt::Debug for DatadogSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatadogSink")
+            .field("datadog_region", &self.datadog_region)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The site of a Datadog account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatadogRegion {
+    Us,
+    Us3,
+    Us5,
+    Eu,
+    /// A region this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The configuration of a log stream sending the logs to Splunk.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplunkSink {
+    pub splunk_domain: String,
+    /// The port of the HTTP event collector, as a string.
+    pub splunk_port: String,
+    /// Whether the certificate of the collector is verified.
+    pub splunk_secure: bool,
+    /// The token of the HTTP event collector.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_token: Option<String>,
+}
+
+impl std::fmt::Debug for SplunkSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SplunkSink")
+            .field("splunk_domain", &self.splunk_domain)
+            .field("splunk_port", &self.splunk_port)
+            .field("splunk_secure", &self.splunk_secure)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The configuration of a log stream sending the logs to Amazon EventBridge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventBridgeSink {
+    pub aws_account_id: String,
+    pub aws_region: String,
+    /// The partner event source created by Auth0, which cannot be set.
+    #[serde(skip_serializing)]
+    pub aws_partner_event_source: Option<String>,
+}
+
+impl EventBridgeSink {
+    /// Returns the configuration of a log stream sending the logs to an AWS account.
+    pub fn new(aws_account_id: &str, aws_region: &str) -> Self {
+        Self {
+            aws_account_id: aws_account_id.to_owned(),
+            aws_region: aws_region.to_owned(),
+            aws_partner_event_source: None,
+        }
+    }
+}
+
+/// The configuration of a log stream sending the logs to Azure Event Grid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventGridSink {
+    pub azure_subscription_id: String,
+    pub azure_resource_group: String,
+    pub azure_region: String,
+    /// The partner topic created by Auth0, which cannot be set.
+    #[serde(skip_serializing)]
+    pub azure_partner_topic: Option<String>,
+}
+
+impl EventGridSink {
+    /// Returns the configuration of a log stream sending the logs to an Azure resource group.
+    pub fn new(
+        azure_subscription_id: &str,
+        azure_resource_group: &str,
+        azure_region: &str,
+    ) -> Self {
+        Self {
+            azure_subscription_id: azure_subscription_id.to_owned(),
+            azure_resource_group: azure_resource_group.to_owned(),
+            azure_region: azure_region.to_owned(),
+            azure_partner_topic: None,
+        }
+    }
+}
+
+/// The payload for creating a log stream.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateLogStreamPayload {
+    pub name: String,
+    #[serde(flatten)]
+    pub sink: LogStreamSink,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<LogStreamFilter>,
+    /// The ID of the log event to start streaming from, instead of the creation of the stream.
+    #[serde(rename = "startFrom", skip_serializing_if = "Option::is_none")]
+    pub start_from: Option<String>,
+}
+
+impl CreateLogStreamPayload {
+    /// Returns the payload of a log stream exporting every event to a sink.
+    pub fn new(name: &str, sink: LogStreamSink) -> Self {
+        Self {
+            name: name.to_owned(),
+            sink,
+            filters: vec![],
+            start_from: None,
+        }
+    }
+}
+
+/// The payload for updating a log stream.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UpdateLogStreamPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LogStreamStatus>,
+    /// The new configuration of the sink, whose type must be the one of the log stream.
+    #[serde(
+        serialize_with = "serialize_sink_config",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sink: Option<LogStreamSink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<LogStreamFilter>>,
+}
+
+/// Serializes the configuration of a sink without its type, which cannot be updated.
+fn serialize_sink_config<S: Serializer>(
+    sink: &Option<LogStreamSink>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    sink.as_ref().map(SinkConfig).serialize(serializer)
+}
+
+#[async_trait]
+impl OperateLogStreams for Auth0Client {
+    async fn list_log_streams(&mut self) -> Auth0Result<Vec<LogStream>> {
+        self.request::<_, _, ManagementError>(Method::GET, "/log-streams", None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_log_stream(&mut self, stream_id: &str) -> Auth0Result<LogStream> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/log-streams/{stream_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_log_stream(
+        &mut self,
+        payload: &CreateLogStreamPayload,
+    ) -> Auth0Result<LogStream> {
+        self.request::<_, _, ManagementError>(Method::POST, "/log-streams", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_log_stream(
+        &mut self,
+        stream_id: &str,
+        payload: &UpdateLogStreamPayload,
+    ) -> Auth0Result<LogStream> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/log-streams/{stream_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_log_stream(&mut self, stream_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/log-streams/{stream_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    mod log_stream_sink {
+        use super::*;
+
+        #[test]
+        fn leave_the_secrets_out_of_debug() {
+            let mut http = HttpSink::new("https://logs.example.com/auth0");
+            http.http_authorization = Some("Bearer http_secret".to_owned());
+            http.http_custom_headers = vec![HttpHeader {
+                header: "X-Api-Key".to_owned(),
+                value: "header_secret".to_owned(),
+            }];
+            let sinks = [
+                LogStreamSink::Http(http),
+                LogStreamSink::Datadog(DatadogSink {
+                    datadog_region: DatadogRegion::Eu,
+                    datadog_api_key: Some("datadog_secret".to_owned()),
+                }),
+                LogStreamSink::Splunk(SplunkSink {
+                    splunk_domain: "splunk.example.com".to_owned(),
+                    splunk_port: "8088".to_owned(),
+                    splunk_secure: true,
+                    splunk_token: Some("splunk_secret".to_owned()),
+                }),
+                LogStreamSink::Other {
+                    stream_type: "sumo".to_owned(),
+                    sink: json!({ "sumoSourceAddress": "https://sumo.example.com/sumo_secret" }),
+                },
+            ];
+
+            for sink in sinks {
+                let debug = format!("{sink:?}");
+
+                assert!(!debug.contains("secret"), "{debug}");
+            }
+        }
+    }
+
+    fn datadog_stream() -> Value {
+        json!({
+            "id": "lst_0000000000012345",
+            "name": "Datadog",
+            "type": "datadog",
+            "status": "active",
+            "sink": { "datadogRegion": "eu" },
+            "filters": [{ "type": "category", "name": "auth.login.fail" }]
+        })
+    }
+
+    mod list_log_streams {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_every_sink_type() {
+            let _m = mock("GET", "/log-streams")
+                .with_status(200)
+                .with_body(
+                    json!([
+                        datadog_stream(),
+                        {
+                            "id": "lst_0000000000067890",
+                            "name": "Sumo Logic",
+                            "type": "sumo",
+                            "status": "suspended",
+                            "sink": { "sumoSourceAddress": "https://sumo.example.com" }
+                        }
+                    ])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.list_log_streams().await.unwrap();
+
+            assert_eq!(
+                resp[0].sink,
+                LogStreamSink::Datadog(DatadogSink {
+                    datadog_region: DatadogRegion::Eu,
+                    datadog_api_key: None,
+                })
+            );
+            assert_eq!(
+                resp[0].filters,
+                vec![LogStreamFilter::category("auth.login.fail")]
+            );
+            assert_eq!(resp[1].status, LogStreamStatus::Suspended);
+            assert_eq!(resp[1].sink.stream_type(), "sumo");
+        }
+    }
+
+    mod get_log_stream {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/log-streams/lst_0000000000024680")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "id": "lst_0000000000024680",
+                        "name": "EventBridge",
+                        "type": "eventbridge",
+                        "status": "active",
+                        "sink": {
+                            "awsAccountId": "123456789012",
+                            "awsRegion": "eu-west-1",
+                            "awsPartnerEventSource": "aws.partner/auth0.com/tenant/source"
+                        }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_log_stream("lst_0000000000024680").await.unwrap();
+
+            match resp.sink {
+                LogStreamSink::EventBridge(sink) => assert_eq!(
+                    sink.aws_partner_event_source.as_deref(),
+                    Some("aws.partner/auth0.com/tenant/source")
+                ),
+                sink => panic!("Expected EventBridge variant, got: {sink:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_stream() {
+            let _m = mock("GET", "/log-streams/lst_unknown")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The log stream does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_log_stream("lst_unknown").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod create_log_stream {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/log-streams")
+                .match_body(Matcher::Json(json!({
+                    "name": "Datadog",
+                    "type": "datadog",
+                    "sink": { "datadogRegion": "eu", "datadogApiKey": "api_key" },
+                    "filters": [{ "type": "category", "name": "auth.login.fail" }]
+                })))
+                .with_status(200)
+                .with_body(datadog_stream().to_string())
+                .create();
+            let mut client = new_client();
+
+            let mut payload = CreateLogStreamPayload::new(
+                "Datadog",
+                LogStreamSink::Datadog(DatadogSink {
+                    datadog_region: DatadogRegion::Eu,
+                    datadog_api_key: Some("api_key".to_owned()),
+                }),
+            );
+            payload.filters = vec![LogStreamFilter::category("auth.login.fail")];
+            let resp = client.create_log_stream(&payload).await.unwrap();
+
+            assert_eq!(resp.id, "lst_0000000000012345");
+        }
+
+        #[tokio::test]
+        async fn errored_with_invalid_sink() {
+            let _m = mock("POST", "/log-streams")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "statusCode": 400,
+                        "error": "Bad Request",
+                        "message": "Payload validation error: 'Missing required property: httpEndpoint' on property sink.",
+                        "errorCode": "invalid_body"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload =
+                CreateLogStreamPayload::new("Webhook", LogStreamSink::Http(HttpSink::new("")));
+            let resp = client.create_log_stream(&payload).await;
+
+            match resp {
+                Err(Error::Management(ManagementError::InvalidRequestBody(_))) => (),
+                _ => panic!("Expected InvalidRequestBody variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod update_log_stream {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let m = mock("PATCH", "/log-streams/lst_0000000000012345")
+                .match_body(Matcher::Json(json!({
+                    "status": "paused",
+                    "sink": {
+                        "httpEndpoint": "https://logs.example.com",
+                        "httpContentFormat": "JSONLINES"
+                    }
+                })))
+                .with_status(200)
+                .with_body(datadog_stream().to_string())
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateLogStreamPayload {
+                status: Some(LogStreamStatus::Paused),
+                sink: Some(LogStreamSink::Http(HttpSink {
+                    http_content_format: Some(HttpContentFormat::JsonLines),
+                    ..HttpSink::new("https://logs.example.com")
+                })),
+                ..Default::default()
+            };
+            client
+                .update_log_stream("lst_0000000000012345", &payload)
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod delete_log_stream {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/log-streams/lst_0000000000012345")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_log_stream("lst_0000000000012345")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_stream() {
+            let _m = mock("DELETE", "/log-streams/lst_unknown")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The log stream does not exist."
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.delete_log_stream("lst_unknown").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,654 @@
+//! Types, traits and functions relative to the logs API.
+
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::stream::{self, Stream};
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::logs::event_type::LogEventType;
+use crate::management::ManagementError;
+use crate::Auth0Client;
+
+pub mod event_type;
+
+/// Trait for reading the logs of the tenant.
+#[async_trait]
+pub trait OperateLogs {
+    /// Searches the logs of the tenant through the Auth0 logs API.
+    ///
+    /// # Arguments
+    /// * `query` - The search and the page of the logs to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_logs(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::logs::{LogQuery, OperateLogs};
+    /// let query = LogQuery::search("type:f")
+    ///     .date_range(Some(chrono::Utc::now() - chrono::Duration::days(1)), None)
+    ///     .per_page(100);
+    ///
+    /// for log in client.list_logs(&query).await? {
+    ///     println!("{}: {:?}", log.date, log.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_logs(&mut self, query: &LogQuery) -> Auth0Result<Vec<LogEvent>>;
+
+    /// Gets a log event through the Auth0 logs API.
+    ///
+    /// # Arguments
+    /// * `log_id` - The ID of the log event to get.
+    async fn get_log(&mut self, log_id: &str) -> Auth0Result<LogEvent>;
+}
+
+/// A log event of the tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogEvent {
+    pub log_id: String,
+    pub date: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub event_type: LogEventType,
+    pub description: Option<String>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub hostname: Option<String>,
+    pub user_id: Option<String>,
+    pub user_name: Option<String>,
+    pub client_id: Option<String>,
+    pub client_name: Option<String>,
+    pub connection: Option<String>,
+    pub connection_id: Option<String>,
+    pub strategy: Option<String>,
+    /// The details of the event, depending on its type.
+    pub details: Option<Value>,
+    /// Every other field of the event, depending on its type.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// The search and the page of [`OperateLogs::list_logs`].
+///
+/// Setting `from` or `take` switches to checkpoint pagination, returning the events
+/// following the `from` event in chronological order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogQuery {
+    q: Option<String>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+    from: Option<String>,
+    take: Option<u32>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl LogQuery {
+    /// Returns a query of the logs matching a Lucene query, e.g. `type:f AND user_id:"auth0|1"`.
+    pub fn search(q: &str) -> Self {
+        Self {
+            q: Some(q.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Only returns the events between the given dates, both included.
+    pub fn date_range(mut self, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> Self {
+        self.date_from = from;
+        self.date_to = to;
+        self
+    }
+
+    /// Returns the events following the event with the given ID.
+    pub fn from(mut self, log_id: &str) -> Self {
+        self.from = Some(log_id.to_owned());
+        self
+    }
+
+    /// Sets the number of events returned with checkpoint pagination.
+    pub fn take(mut self, take: u32) -> Self {
+        self.take = Some(take);
+        self
+    }
+
+    /// Sets the index of the page to return, starting at 0.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the number of events per page.
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Returns the Lucene query, including the date range.
+    fn lucene(&self) -> Option<String> {
+        let date = |date: &Option<DateTime<Utc>>| match date {
+            Some(date) => format!("\"{}\"", date.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            None => "*".to_owned(),
+        };
+        let range = match (self.date_from, self.date_to) {
+            (None, None) => None,
+            _ => Some(format!(
+                "date:[{} TO {}]",
+                date(&self.date_from),
+                date(&self.date_to)
+            )),
+        };
+
+        match (&self.q, range) {
+            (Some(q), Some(range)) => Some(format!("({q}) AND {range}")),
+            (Some(q), None) => Some(q.clone()),
+            (None, range) => range,
+        }
+    }
+
+    /// Returns the path of the logs endpoint with the query parameters.
+    pub(crate) fn path(&self) -> String {
+        let mut query = vec![];
+
+        if let Some(q) = self.lucene() {
+            query.push(format!("q={}", urlencoding::encode(&q)));
+        }
+        if let Some(from) = &self.from {
+            query.push(format!("from={}", urlencoding::encode(from)));
+        }
+        if let Some(take) = self.take {
+            query.push(format!("take={take}"));
+        }
+        if let Some(page) = self.page {
+            query.push(format!("page={page}"));
+        }
+        if let Some(per_page) = self.per_page {
+            query.push(format!("per_page={per_page}"));
+        }
+
+        match query.is_empty() {
+            true => "/logs".to_owned(),
+            false => format!("/logs?{}", query.join("&")),
+        }
+    }
+}
+
+/// The number of events requested by each poll of [`tail`].
+const TAIL_TAKE: u32 = 100;
+/// The number of log IDs remembered by [`tail`] to skip the events it already returned.
+const TAIL_SEEN_CAPACITY: usize = 1000;
+/// The longest wait of [`tail`] when the logs API is rate limited or unavailable.
+const TAIL_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Follows the logs of the tenant, like `tail -f`.
+///
+/// The stream polls the logs following the last returned event with checkpoint pagination,
+/// waiting `poll_interval` whenever there is no new event. Without a `from` event in the
+/// query, it starts after the most recent event of the tenant.
+///
+/// When the logs API is rate limited, unreachable or fails with a server error, the poll is
+/// retried after an exponential backoff. Other errors are yielded, and end the stream. The
+/// pagination of the query is ignored, and events are never returned twice.
+///
+/// # Arguments
+/// * `client` - The client polling the logs.
+/// * `query` - The search of the events to follow, and the event to follow from.
+/// * `poll_interval` - The time to wait between polls returning no event.
+///
+/// # Example
+/// ```
+/// # async fn tail(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+/// # use auth0_client::management::logs::{tail, LogQuery};
+/// use futures_util::StreamExt;
+///
+/// let logs = tail(&mut client, LogQuery::search("type:f"), std::time::Duration::from_secs(5));
+/// let mut logs = std::pin::pin!(logs);
+///
+/// while let Some(log) = logs.next().await {
+///     let log = log?;
+///     println!("{}: {:?}", log.date, log.description);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn tail(
+    client: &mut Auth0Client,
+    query: LogQuery,
+    poll_interval: Duration,
+) -> impl Stream<Item = Auth0Result<LogEvent>> + '_ {
+    let started = query.from.is_some();
+    let tail = Tail {
+        client,
+        query: LogQuery {
+            page: None,
+            per_page: None,
+            take: Some(query.take.unwrap_or(TAIL_TAKE)),
+            ..query
+        },
+        poll_interval,
+        backoff: poll_interval,
+        pending: VecDeque::new(),
+        seen: HashSet::new(),
+        seen_order: VecDeque::new(),
+        started,
+        ended: false,
+    };
+
+    stream::unfold(tail, |mut tail| async move {
+        if tail.ended {
+            return None;
+        }
+
+        let event = tail.next().await;
+        tail.ended = event.is_err();
+        Some((event, tail))
+    })
+}
+
+/// Returns whether a poll failing with the error may succeed later, e.g. once the rate limit resets.
+fn is_transient(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::TooManyRequests | Error::Http(_) | Error::ServerError(_)
+    )
+}
+
+/// The state of [`tail`] between two events.
+struct Tail<'a> {
+    client: &'a mut Auth0Client,
+    query: LogQuery,
+    poll_interval: Duration,
+    backoff: Duration,
+    pending: VecDeque<LogEvent>,
+    seen: HashSet<String>,
+    seen_order: VecDeque<String>,
+    started: bool,
+    /// Whether an error which can not be retried was returned.
+    ended: bool,
+}
+
+impl Tail<'_> {
+    /// Returns the next event, polling the logs until there is one.
+    async fn next(&mut self) -> Auth0Result<LogEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+
+            let events = match self.poll().await {
+                Ok(events) => events,
+                Err(err) if is_transient(&err) => {
+                    tracing::debug!("Polling the logs failed ({err}), retrying...");
+                    tokio::time::sleep(self.backoff).await;
+                    self.backoff = (self.backoff * 2).min(TAIL_MAX_BACKOFF);
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            self.backoff = self.poll_interval;
+
+            for event in events {
+                self.query.from = Some(event.log_id.clone());
+                if self.remember(&event.log_id) {
+                    self.pending.push_back(event);
+                }
+            }
+            if self.pending.is_empty() {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+    }
+
+    /// Returns the events following the last one, or the most recent event when starting.
+    ///
+    /// The most recent event of the tenant is skipped by the first poll, as it precedes the tail.
+    /// If the tenant had no event yet, the following polls return its oldest events until one
+    /// is returned to follow.
+    async fn poll(&mut self) -> Auth0Result<Vec<LogEvent>> {
+        if self.query.from.is_some() {
+            return self.client.list_logs(&self.query).await;
+        }
+
+        let path = match self.started {
+            true => self.oldest_events_path(),
+            false => "/logs?sort=date%3A-1&per_page=1".to_owned(),
+        };
+        let events: Vec<LogEvent> = self
+            .client
+            .request::<_, _, ManagementError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+        if !self.started {
+            self.started = true;
+            for event in &events {
+                self.query.from = Some(event.log_id.clone());
+                self.remember(&event.log_id);
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Returns the path listing the oldest events matching the query, by pages of `take` events.
+    fn oldest_events_path(&self) -> String {
+        let mut path = format!(
+            "/logs?sort=date%3A1&per_page={}",
+            self.query.take.unwrap_or(TAIL_TAKE)
+        );
+
+        if let Some(q) = self.query.lucene() {
+            path.push_str(&format!("&q={}", urlencoding::encode(&q)));
+        }
+        path
+    }
+
+    /// Records a returned event, returning `false` if it was returned already.
+    fn remember(&mut self, log_id: &str) -> bool {
+        if !self.seen.insert(log_id.to_owned()) {
+            return false;
+        }
+        self.seen_order.push_back(log_id.to_owned());
+        if self.seen_order.len() > TAIL_SEEN_CAPACITY {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[async_trait]
+impl OperateLogs for Auth0Client {
+    async fn list_logs(&mut self, query: &LogQuery) -> Auth0Result<Vec<LogEvent>> {
+        self.request::<_, _, ManagementError>(Method::GET, &query.path(), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_log(&mut self, log_id: &str) -> Auth0Result<LogEvent> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/logs/{log_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn log_event() -> Value {
+        json!({
+            "log_id": "90020230112092445761000000000000001223372036854775807",
+            "date": "2023-01-12T09:24:45.761Z",
+            "type": "f",
+            "description": "Wrong email or password.",
+            "user_id": "auth0|63bfd5cdbd7f1c642dd83768",
+            "ip": "10.0.0.1",
+            "user_agent": "Chrome 108.0.0 / Mac OS X 10.15.7",
+            "client_id": "client_id",
+            "connection": "Username-Password-Authentication",
+            "details": { "error": { "message": "Wrong email or password." } },
+            "isMobile": false
+        })
+    }
+
+    mod log_query {
+        use super::*;
+
+        #[test]
+        fn combine_the_search_and_the_date_range() {
+            let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+            let query = LogQuery::search("type:f OR type:fp").date_range(Some(from), None);
+
+            assert_eq!(
+                query.lucene().unwrap(),
+                r#"(type:f OR type:fp) AND date:["2023-01-01T00:00:00.000Z" TO *]"#
+            );
+        }
+
+        #[test]
+        fn build_the_checkpoint_path() {
+            let query = LogQuery::default().from("900").take(100);
+
+            assert_eq!(query.path(), "/logs?from=900&take=100");
+            assert_eq!(LogQuery::default().path(), "/logs");
+        }
+    }
+
+    mod tail {
+        use super::*;
+        use futures_util::StreamExt;
+
+        fn events(log_ids: &[&str]) -> String {
+            let events: Vec<Value> = log_ids
+                .iter()
+                .map(|log_id| {
+                    let mut event = log_event();
+                    event["log_id"] = json!(log_id);
+                    event
+                })
+                .collect();
+
+            json!(events).to_string()
+        }
+
+        fn logs_mock(from: &str, log_ids: &[&str]) -> mockito::Mock {
+            mock("GET", "/logs")
+                .match_query(Matcher::UrlEncoded("from".to_owned(), from.to_owned()))
+                .with_status(200)
+                .with_body(events(log_ids))
+        }
+
+        async fn tail_ids(client: &mut Auth0Client, query: LogQuery, count: usize) -> Vec<String> {
+            tail(client, query, Duration::from_millis(10))
+                .take(count)
+                .map(|event| event.unwrap().log_id)
+                .collect()
+                .await
+        }
+
+        #[tokio::test]
+        async fn follow_the_last_event_without_duplicates() {
+            let _m1 = logs_mock("tail_1", &["tail_2", "tail_3"]).create();
+            let _m2 = logs_mock("tail_3", &["tail_3", "tail_4"]).create();
+            let _m3 = logs_mock("tail_4", &[]).create();
+            let mut client = new_client();
+
+            let ids = tail_ids(&mut client, LogQuery::default().from("tail_1"), 3).await;
+
+            assert_eq!(ids, vec!["tail_2", "tail_3", "tail_4"]);
+        }
+
+        #[tokio::test]
+        async fn start_after_the_most_recent_event() {
+            let _m1 = mock("GET", "/logs")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("sort".to_owned(), "date:-1".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "1".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(json!([log_event()]).to_string())
+                .create();
+            let _m2 = logs_mock(
+                "90020230112092445761000000000000001223372036854775807",
+                &["latest_1"],
+            )
+            .create();
+            let _m3 = logs_mock("latest_1", &[]).create();
+            let mut client = new_client();
+
+            let ids = tail_ids(&mut client, LogQuery::default(), 1).await;
+
+            assert_eq!(ids, vec!["latest_1"]);
+        }
+
+        #[tokio::test]
+        async fn return_every_event_after_an_empty_start() {
+            let _m1 = mock("GET", "/logs")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("sort".to_owned(), "date:-1".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "1".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body("[]")
+                .create();
+            let _m2 = mock("GET", "/logs")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("sort".to_owned(), "date:1".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "100".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(events(&["empty_1", "empty_2"]))
+                .create();
+            let _m3 = logs_mock("empty_2", &[]).create();
+            let mut client = new_client();
+
+            let ids = tail_ids(&mut client, LogQuery::default(), 2).await;
+
+            assert_eq!(ids, vec!["empty_1", "empty_2"]);
+        }
+
+        #[tokio::test]
+        async fn retry_when_rate_limited() {
+            let m1 = mock("GET", "/logs")
+                .match_query(Matcher::UrlEncoded(
+                    "from".to_owned(),
+                    "limited_1".to_owned(),
+                ))
+                .with_status(429)
+                .expect(1)
+                .create();
+            let _m2 = logs_mock("limited_1", &["limited_2"]).create();
+            let mut client = new_client();
+
+            let ids = tail_ids(&mut client, LogQuery::default().from("limited_1"), 1).await;
+
+            assert_eq!(ids, vec!["limited_2"]);
+            m1.assert();
+        }
+
+        #[tokio::test]
+        async fn retry_on_server_errors() {
+            let m1 = mock("GET", "/logs")
+                .match_query(Matcher::UrlEncoded(
+                    "from".to_owned(),
+                    "unavailable_1".to_owned(),
+                ))
+                .with_status(503)
+                .with_body("Service Unavailable")
+                .expect(1)
+                .create();
+            let _m2 = logs_mock("unavailable_1", &["unavailable_2"]).create();
+            let mut client = new_client();
+
+            let ids = tail_ids(&mut client, LogQuery::default().from("unavailable_1"), 1).await;
+
+            assert_eq!(ids, vec!["unavailable_2"]);
+            m1.assert();
+        }
+
+        #[tokio::test]
+        async fn end_after_an_error_which_can_not_be_retried() {
+            let _m = mock("GET", "/logs")
+                .match_query(Matcher::UrlEncoded(
+                    "from".to_owned(),
+                    "forbidden_1".to_owned(),
+                ))
+                .with_status(403)
+                .with_body(
+                    json!({ "statusCode": 403, "error": "Forbidden", "message": "Insufficient scope" })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let events = tail(
+                &mut client,
+                LogQuery::default().from("forbidden_1"),
+                Duration::from_millis(10),
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+            match events.as_slice() {
+                [Err(Error::Management(ManagementError::Unknown(_)))] => (),
+                _ => panic!("Expected a single Unknown variant, got: {events:?}"),
+            }
+        }
+    }
+
+    mod list_logs {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/logs")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("q".to_owned(), "type:f".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "10".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(json!([log_event()]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_logs(&LogQuery::search("type:f").per_page(10))
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].event_type, LogEventType::FailedLogin);
+            assert_eq!(resp[0].ip.as_deref(), Some("10.0.0.1"));
+            assert_eq!(
+                resp[0].connection.as_deref(),
+                Some("Username-Password-Authentication")
+            );
+            assert_eq!(resp[0].other["isMobile"], false);
+        }
+    }
+
+    mod get_log {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock(
+                "GET",
+                "/logs/90020230112092445761000000000000001223372036854775807",
+            )
+            .with_status(200)
+            .with_body(log_event().to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_log("90020230112092445761000000000000001223372036854775807")
+                .await
+                .unwrap();
+
+            assert_eq!(
+                resp.user_id.as_deref(),
+                Some("auth0|63bfd5cdbd7f1c642dd83768")
+            );
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! The types of the log events, decoded from their codes.
+
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Display, Formatter};
+
+/// The type of a log event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LogEventType {
+    SuccessLogin,
+    FailedLogin,
+    FailedLoginIncorrectPassword,
+    FailedLoginInvalidEmailOrUsername,
+    SuccessSilentAuth,
+    FailedSilentAuth,
+    SuccessLogout,
+    FailedLogout,
+    SuccessSignup,
+    FailedSignup,
+    SuccessExchangeAuthorizationCode,
+    FailedExchangeAuthorizationCode,
+    SuccessExchangeClientCredentials,
+    FailedExchangeClientCredentials,
+    SuccessExchangePassword,
+    FailedExchangePassword,
+    SuccessExchangeRefreshToken,
+    FailedExchangeRefreshToken,
+    SuccessExchangeDeviceCode,
+    FailedExchangeDeviceCode,
+    SuccessChangePassword,
+    FailedChangePassword,
+    SuccessChangePasswordRequest,
+    FailedChangePasswordRequest,
+    SuccessVerificationEmail,
+    FailedVerificationEmail,
+    SuccessVerificationEmailRequest,
+    FailedVerificationEmailRequest,
+    SuccessCrossOriginAuthentication,
+    FailedCrossOriginAuthentication,
+    CodeLinkSent,
+    MfaRequired,
+    SuccessMfaAuthentication,
+    FailedMfaAuthentication,
+    BlockedAccount,
+    BlockedIpAddress,
+    BreachedPassword,
+    SuccessApiOperation,
+    FailedApiOperation,
+    ApiRateLimit,
+    DeletedUser,
+    SuccessUserDeletion,
+    FailedUserDeletion,
+    WarningDuringLogin,
+    DeprecationNotice,
+    /// A type this crate does not know about yet, with its code.
+    Unknown(String),
+}
+
+/// The code and description of each known type.
+const LOG_EVENT_TYPES: &[(&str, LogEventType, &str)] = &[
+    ("s", LogEventType::SuccessLogin, "Success Login"),
+    ("f", LogEventType::FailedLogin, "Failed Login"),
+    (
+        "fp",
+        LogEventType::FailedLoginIncorrectPassword,
+        "Failed Login (Incorrect Password)",
+    ),
+    (
+        "fu",
+        LogEventType::FailedLoginInvalidEmailOrUsername,
+        "Failed Login (Invalid Email/Username)",
+    ),
+    (
+        "ssa",
+        LogEventType::SuccessSilentAuth,
+        "Success Silent Auth",
+    ),
+    ("fsa", LogEventType::FailedSilentAuth, "Failed Silent Auth"),
+    ("slo", LogEventType::SuccessLogout, "Success Logout"),
+    ("flo", LogEventType::FailedLogout, "Failed Logout"),
+    ("ss", LogEventType::SuccessSignup, "Success Signup"),
+    ("fs", LogEventType::FailedSignup, "Failed Signup"),
+    (
+        "seacft",
+        LogEventType::SuccessExchangeAuthorizationCode,
+        "Success Exchange (Authorization Code for Access Token)",
+    ),
+    (
+        "feacft",
+        LogEventType::FailedExchangeAuthorizationCode,
+        "Failed Exchange (Authorization Code for Access Token)",
+    ),
+    (
+        "seccft",
+        LogEventType::SuccessExchangeClientCredentials,
+        "Success Exchange (Client Credentials for Access Token)",
+    ),
+    (
+        "feccft",
+        LogEventType::FailedExchangeClientCredentials,
+        "Failed Exchange (Client Credentials for Access Token)",
+    ),
+    (
+        "sepft",
+        LogEventType::SuccessExchangePassword,
+        "Success Exchange (Password for Access Token)",
+    ),
+    (
+        "fepft",
+        LogEventType::FailedExchangePassword,
+        "Failed Exchange (Password for Access Token)",
+    ),
+    (
+        "sertft",
+        LogEventType::SuccessExchangeRefreshToken,
+        "Success Exchange (Refresh Token for Access Token)",
+    ),
+    (
+        "fertft",
+        LogEventType::FailedExchangeRefreshToken,
+        "Failed Exchange (Refresh Token for Access Token)",
+    ),
+    (
+        "sede",
+        LogEventType::SuccessExchangeDeviceCode,
+        "Success Exchange (Device Code for Access Token)",
+    ),
+    (
+        "fede",
+        LogEventType::FailedExchangeDeviceCode,
+        "Failed Exchange (Device Code for Access Token)",
+    ),
+    (
+        "scp",
+        LogEventType::SuccessChangePassword,
+        "Success Change Password",
+    ),
+    (
+        "fcp",
+        LogEventType::FailedChangePassword,
+        "Failed Change Password",
+    ),
+    (
+        "scpr",
+        LogEventType::SuccessChangePasswordRequest,
+        "Success Change Password Request",
+    ),
+    (
+        "fcpr",
+        LogEventType::FailedChangePasswordRequest,
+        "Failed Change Password Request",
+    ),
+    (
+        "sv",
+        LogEventType::SuccessVerificationEmail,
+        "Success Verification Email",
+    ),
+    (
+        "fv",
+        LogEventType::FailedVerificationEmail,
+        "Failed Verification Email",
+    ),
+    (
+        "svr",
+        LogEventType::SuccessVerificationEmailRequest,
+        "Success Verification Email Request",
+    ),
+    (
+        "fvr",
+        LogEventType::FailedVerificationEmailRequest,
+        "Failed Verification Email Request",
+    ),
+    (
+        "scoa",
+        LogEventType::SuccessCrossOriginAuthentication,
+        "Success Cross Origin Authentication",
+    ),
+    (
+        "fcoa",
+        LogEventType::FailedCrossOriginAuthentication,
+        "Failed Cross Origin Authentication",
+    ),
+    ("cls", LogEventType::CodeLinkSent, "Code/Link Sent"),
+    ("mfar", LogEventType::MfaRequired, "MFA Required"),
+    (
+        "gd_auth_succeed",
+        LogEventType::SuccessMfaAuthentication,
+        "MFA Authentication Success",
+    ),
+    (
+        "gd_auth_failed",
+        LogEventType::FailedMfaAuthentication,
+        "MFA Authentication Failed",
+    ),
+    ("limit_wc", LogEventType::BlockedAccount, "Blocked Account"),
+    (
+        "limit_mu",
+        LogEventType::BlockedIpAddress,
+        "Blocked IP Address",
+    ),
+    (
+        "pwd_leak",
+        LogEventType::BreachedPassword,
+        "Breached Password",
+    ),
+    (
+        "sapi",
+        LogEventType::SuccessApiOperation,
+        "Success API Operation",
+    ),
+    (
+        "fapi",
+        LogEventType::FailedApiOperation,
+        "Failed API Operation",
+    ),
+    (
+        "api_limit",
+        LogEventType::ApiRateLimit,
+        "Rate Limit on the Authentication or Management APIs",
+    ),
+    ("du", LogEventType::DeletedUser, "Deleted User"),
+    (
+        "sdu",
+        LogEventType::SuccessUserDeletion,
+        "Success User Deletion",
+    ),
+    (
+        "fdu",
+        LogEventType::FailedUserDeletion,
+        "Failed User Deletion",
+    ),
+    (
+        "w",
+        LogEventType::WarningDuringLogin,
+        "Warnings During Login",
+    ),
+    (
+        "depnote",
+        LogEventType::DeprecationNotice,
+        "Deprecation Notice",
+    ),
+];
+
+impl LogEventType {
+    /// Decodes the code of a log event type, e.g. `fp`.
+    pub fn from_code(code: &str) -> Self {
+        LOG_EVENT_TYPES
+            .iter()
+            .find(|(c, _, _)| *c == code)
+            .map(|(_, event_type, _)| event_type.clone())
+            .unwrap_or_else(|| Self::Unknown(code.to_owned()))
+    }
+
+    /// Returns the code of the type, as used in the `type` field of the log search.
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Unknown(code) => code,
+            _ => self.entry().map_or("", |(code, _, _)| code),
+        }
+    }
+
+    /// Returns the description of the type, as shown in the Auth0 dashboard.
+    pub fn description(&self) -> Option<&'static str> {
+        self.entry().map(|(_, _, description)| *description)
+    }
+
+    /// Returns whether the type is a failure, such as a failed login or exchange.
+    pub fn is_failure(&self) -> bool {
+        let code = self.code();
+
+        code.starts_with('f') || code.starts_with("limit_") || code == "gd_auth_failed"
+    }
+
+    fn entry(&self) -> Option<&'static (&'static str, LogEventType, &'static str)> {
+        LOG_EVENT_TYPES
+            .iter()
+            .find(|(_, event_type, _)| event_type == self)
+    }
+}
+
+impl Display for LogEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.description().unwrap_or_else(|| self.code()))
+    }
+}
+
+impl<'de> Deserialize<'de> for LogEventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_code(&String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_the_codes() {
+        let event_type = LogEventType::from_code("seacft");
+
+        assert_eq!(event_type, LogEventType::SuccessExchangeAuthorizationCode);
+        assert_eq!(event_type.code(), "seacft");
+        assert_eq!(
+            event_type.to_string(),
+            "Success Exchange (Authorization Code for Access Token)"
+        );
+        assert!(!event_type.is_failure());
+        assert!(LogEventType::from_code("fp").is_failure());
+    }
+
+    #[test]
+    fn keep_unknown_codes() {
+        let event_type: LogEventType = serde_json::from_str(r#""new_type""#).unwrap();
+
+        assert_eq!(event_type, LogEventType::Unknown("new_type".to_owned()));
+        assert_eq!(event_type.code(), "new_type");
+        assert_eq!(event_type.description(), None);
+    }
+
+    #[test]
+    fn have_unique_codes() {
+        for (code, event_type, _) in LOG_EVENT_TYPES {
+            assert_eq!(LogEventType::from_code(code), *event_type);
+        }
+    }
+}
@@ -0,0 +1,987 @@
+//! Types, traits and functions relative to the organizations API.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::roles::Role;
+use crate::management::{checkpoint_path, ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+pub mod invitations;
+
+/// Trait for operating the organizations of the tenant.
+#[async_trait]
+pub trait OperateOrganizations {
+    /// Lists the organizations of the tenant through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the organizations to list.
+    async fn list_organizations(&mut self, params: &ListParams) -> Auth0Result<Page<Organization>>;
+
+    /// Gets an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization to get.
+    async fn get_organization(&mut self, organization_id: &str) -> Auth0Result<Organization>;
+
+    /// Gets an organization by its name through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the organization to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_organization(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::OperateOrganizations;
+    /// let organization = client.get_organization_by_name("acme").await?;
+    ///
+    /// println!("{}: {:?}", organization.id, organization.display_name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_organization_by_name(&mut self, name: &str) -> Auth0Result<Organization>;
+
+    /// Creates an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create an organization.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_organization(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::{CreateOrganizationPayload, OperateOrganizations};
+    /// let mut payload = CreateOrganizationPayload::new("acme");
+    /// payload.display_name = Some("Acme Inc.".to_owned());
+    /// payload.metadata = Some([("plan".to_owned(), "enterprise".to_owned())].into());
+    ///
+    /// let organization = client.create_organization(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_organization(
+        &mut self,
+        payload: &CreateOrganizationPayload,
+    ) -> Auth0Result<Organization>;
+
+    /// Updates an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization to update.
+    /// * `payload` - The fields of the organization to update.
+    async fn update_organization(
+        &mut self,
+        organization_id: &str,
+        payload: &UpdateOrganizationPayload,
+    ) -> Auth0Result<Organization>;
+
+    /// Deletes an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization to delete.
+    async fn delete_organization(&mut self, organization_id: &str) -> Auth0Result<()>;
+
+    /// Lists the members of an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `from` - The `next` checkpoint of the previous page, if any.
+    /// * `take` - The number of members per page, 50 by default.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_members(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::OperateOrganizations;
+    /// let mut page = client
+    ///     .list_organization_members("org_W2pqeQg6pL6Ar3sk", None, None)
+    ///     .await?;
+    /// let mut members = page.members;
+    ///
+    /// while let Some(next) = page.next {
+    ///     page = client
+    ///         .list_organization_members("org_W2pqeQg6pL6Ar3sk", Some(&next), None)
+    ///         .await?;
+    ///     members.append(&mut page.members);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_organization_members(
+        &mut self,
+        organization_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<MembersPage>;
+
+    /// Adds users to the members of an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_ids` - The user IDs of the users to add.
+    async fn add_organization_members(
+        &mut self,
+        organization_id: &str,
+        user_ids: &[&str],
+    ) -> Auth0Result<()>;
+
+    /// Removes users from the members of an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_ids` - The user IDs of the members to remove.
+    async fn remove_organization_members(
+        &mut self,
+        organization_id: &str,
+        user_ids: &[&str],
+    ) -> Auth0Result<()>;
+
+    /// Lists the roles of a member within an organization through the Auth0 organizations API.
+    ///
+    /// These roles only apply when the member logs in to the organization, unlike the roles
+    /// assigned to the user with [`crate::users::OperateUsers::assign_roles`].
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_id` - The user ID of the member.
+    /// * `params` - The page of the roles to list.
+    async fn list_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Role>>;
+
+    /// Assigns roles to a member within an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_id` - The user ID of the member.
+    /// * `role_ids` - The IDs of the roles to assign.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn assign_member_roles(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::OperateOrganizations;
+    /// client
+    ///     .assign_member_roles(
+    ///         "org_W2pqeQg6pL6Ar3sk",
+    ///         "auth0|63bfd5cdbd7f1c642dd83768",
+    ///         &["rol_0tK3QKvbSvXcXz7w"],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn assign_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        role_ids: &[&str],
+    ) -> Auth0Result<()>;
+
+    /// Removes roles from a member within an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_id` - The user ID of the member.
+    /// * `role_ids` - The IDs of the roles to remove.
+    async fn remove_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        role_ids: &[&str],
+    ) -> Auth0Result<()>;
+
+    /// Lists the connections the members of an organization can log in with through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `params` - The page of the connections to list.
+    async fn list_organization_connections(
+        &mut self,
+        organization_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<OrganizationConnection>>;
+
+    /// Enables a connection for an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `connection_id` - The ID of the connection to enable.
+    /// * `assign_membership_on_login` - Whether the users logging in with the connection
+    ///   automatically become members of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn enable_connection(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::OperateOrganizations;
+    /// client
+    ///     .add_organization_connection("org_W2pqeQg6pL6Ar3sk", "con_0tK3QKvbSvXcXz7w", true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn add_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+        assign_membership_on_login: bool,
+    ) -> Auth0Result<OrganizationConnection>;
+
+    /// Updates a connection enabled for an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `connection_id` - The ID of the enabled connection.
+    /// * `assign_membership_on_login` - Whether the users logging in with the connection
+    ///   automatically become members of the organization.
+    async fn update_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+        assign_membership_on_login: bool,
+    ) -> Auth0Result<OrganizationConnection>;
+
+    /// Disables a connection for an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `connection_id` - The ID of the connection to disable.
+    async fn remove_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+    ) -> Auth0Result<()>;
+}
+
+/// An organization of the tenant, e.g. a customer of a B2B application.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    pub display_name: Option<String>,
+    pub branding: Option<OrganizationBranding>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// The branding of the pages shown to the members of an organization.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizationBranding {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<BrandingColors>,
+}
+
+/// The colors of the branding of an organization, as hex codes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrandingColors {
+    pub primary: String,
+    pub page_background: String,
+}
+
+/// A page of the members of an organization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MembersPage {
+    pub members: Vec<Member>,
+    /// The checkpoint of the next page, if there are more members.
+    pub next: Option<String>,
+}
+
+/// A member of an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub user_id: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+}
+
+/// A connection the members of an organization can log in with.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationConnection {
+    pub connection_id: String,
+    /// Whether the users logging in with the connection automatically become members.
+    #[serde(default)]
+    pub assign_membership_on_login: bool,
+    pub connection: Option<OrganizationConnectionDetails>,
+}
+
+/// The name and strategy of a connection enabled for an organization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganizationConnectionDetails {
+    pub name: String,
+    pub strategy: String,
+}
+
+/// The payload for enabling a connection for an organization, or updating it.
+#[derive(Serialize)]
+struct OrganizationConnectionPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connection_id: Option<&'a str>,
+    assign_membership_on_login: bool,
+}
+
+/// The payload for adding members to, or removing members from, an organization.
+#[derive(Serialize)]
+struct MembersPayload<'a> {
+    members: &'a [&'a str],
+}
+
+/// The payload for assigning roles to, or removing roles from, a member.
+#[derive(Serialize)]
+struct MemberRolesPayload<'a> {
+    roles: &'a [&'a str],
+}
+
+/// A struct containing the payload for creating an organization.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateOrganizationPayload {
+    /// The name of the organization, lowercase and without spaces.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding: Option<OrganizationBranding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateOrganizationPayload {
+    /// Returns a payload for creating an organization with only `name` set.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            display_name: None,
+            branding: None,
+            metadata: None,
+        }
+    }
+}
+
+/// A struct containing the payload for updating an organization.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateOrganizationPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding: Option<OrganizationBranding>,
+    /// The metadata of the organization, which replace the existing ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[async_trait]
+impl OperateOrganizations for Auth0Client {
+    async fn list_organizations(&mut self, params: &ListParams) -> Auth0Result<Page<Organization>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply("/organizations"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_organization(&mut self, organization_id: &str) -> Auth0Result<Organization> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/organizations/{organization_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_organization_by_name(&mut self, name: &str) -> Auth0Result<Organization> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/organizations/name/{}", urlencoding::encode(name)),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_organization(
+        &mut self,
+        payload: &CreateOrganizationPayload,
+    ) -> Auth0Result<Organization> {
+        self.request::<_, _, ManagementError>(Method::POST, "/organizations", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_organization(
+        &mut self,
+        organization_id: &str,
+        payload: &UpdateOrganizationPayload,
+    ) -> Auth0Result<Organization> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/organizations/{organization_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_organization(&mut self, organization_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/organizations/{organization_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_organization_members(
+        &mut self,
+        organization_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<MembersPage> {
+        let path = checkpoint_path(
+            &format!("/organizations/{organization_id}/members"),
+            from,
+            take,
+        );
+
+        self.request::<_, _, ManagementError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn add_organization_members(
+        &mut self,
+        organization_id: &str,
+        user_ids: &[&str],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::POST,
+            &format!("/organizations/{organization_id}/members"),
+            Some(MembersPayload { members: user_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_organization_members(
+        &mut self,
+        organization_id: &str,
+        user_ids: &[&str],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/organizations/{organization_id}/members"),
+            Some(MembersPayload { members: user_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Role>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!(
+                "/organizations/{organization_id}/members/{user_id}/roles"
+            )),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn assign_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        role_ids: &[&str],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::POST,
+            &format!("/organizations/{organization_id}/members/{user_id}/roles"),
+            Some(MemberRolesPayload { roles: role_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_member_roles(
+        &mut self,
+        organization_id: &str,
+        user_id: &str,
+        role_ids: &[&str],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/organizations/{organization_id}/members/{user_id}/roles"),
+            Some(MemberRolesPayload { roles: role_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_organization_connections(
+        &mut self,
+        organization_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<OrganizationConnection>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!(
+                "/organizations/{organization_id}/enabled_connections"
+            )),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn add_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+        assign_membership_on_login: bool,
+    ) -> Auth0Result<OrganizationConnection> {
+        self.request::<_, _, ManagementError>(
+            Method::POST,
+            &format!("/organizations/{organization_id}/enabled_connections"),
+            Some(OrganizationConnectionPayload {
+                connection_id: Some(connection_id),
+                assign_membership_on_login,
+            }),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+        assign_membership_on_login: bool,
+    ) -> Auth0Result<OrganizationConnection> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/organizations/{organization_id}/enabled_connections/{connection_id}"),
+            Some(OrganizationConnectionPayload {
+                connection_id: None,
+                assign_membership_on_login,
+            }),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn remove_organization_connection(
+        &mut self,
+        organization_id: &str,
+        connection_id: &str,
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/organizations/{organization_id}/enabled_connections/{connection_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn organization() -> String {
+        json!({
+            "id": "org_W2pqeQg6pL6Ar3sk",
+            "name": "acme",
+            "display_name": "Acme Inc.",
+            "branding": {
+                "logo_url": "https://acme.example.com/logo.png",
+                "colors": { "primary": "#0059d6", "page_background": "#000000" }
+            },
+            "metadata": { "plan": "enterprise" }
+        })
+        .to_string()
+    }
+
+    mod organizations {
+        use super::*;
+
+        #[tokio::test]
+        async fn list_organizations() {
+            let _m = mock("GET", "/organizations")
+                .match_query(Matcher::UrlEncoded(
+                    "include_totals".to_owned(),
+                    "true".to_owned(),
+                ))
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"start": 0, "limit": 50, "total": 1, "organizations": [{}]}}"#,
+                    organization()
+                ))
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_organizations(&ListParams::default().include_totals(true))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.total, Some(1));
+            assert_eq!(resp[0].metadata["plan"], "enterprise");
+        }
+
+        #[tokio::test]
+        async fn get_organization() {
+            let _m = mock("GET", "/organizations/org_W2pqeQg6pL6Ar3sk")
+                .with_status(200)
+                .with_body(organization())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_organization("org_W2pqeQg6pL6Ar3sk")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.branding.unwrap().colors.unwrap().primary, "#0059d6");
+        }
+
+        #[tokio::test]
+        async fn get_organization_by_name() {
+            let _m = mock("GET", "/organizations/name/acme")
+                .with_status(200)
+                .with_body(organization())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_organization_by_name("acme").await.unwrap();
+
+            assert_eq!(resp.id, "org_W2pqeQg6pL6Ar3sk");
+        }
+
+        #[tokio::test]
+        async fn create_organization() {
+            let _m = mock("POST", "/organizations")
+                .match_body(Matcher::Json(json!({
+                    "name": "acme",
+                    "branding": { "logo_url": "https://acme.example.com/logo.png" }
+                })))
+                .with_status(201)
+                .with_body(organization())
+                .create();
+            let mut client = new_client();
+
+            let mut payload = CreateOrganizationPayload::new("acme");
+            payload.branding = Some(OrganizationBranding {
+                logo_url: Some("https://acme.example.com/logo.png".to_owned()),
+                ..Default::default()
+            });
+            let resp = client.create_organization(&payload).await.unwrap();
+
+            assert_eq!(resp.display_name.as_deref(), Some("Acme Inc."));
+        }
+
+        #[tokio::test]
+        async fn update_organization() {
+            let m = mock("PATCH", "/organizations/org_W2pqeQg6pL6Ar3sk")
+                .match_body(Matcher::Json(json!({ "display_name": "Acme Inc." })))
+                .with_status(200)
+                .with_body(organization())
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateOrganizationPayload {
+                display_name: Some("Acme Inc.".to_owned()),
+                ..Default::default()
+            };
+            client
+                .update_organization("org_W2pqeQg6pL6Ar3sk", &payload)
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn delete_organization() {
+            let m = mock("DELETE", "/organizations/org_W2pqeQg6pL6Ar3sk")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_organization("org_W2pqeQg6pL6Ar3sk")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod members {
+        use super::*;
+
+        #[tokio::test]
+        async fn list_organization_members() {
+            let _m = mock("GET", "/organizations/org_W2pqeQg6pL6Ar3sk/members")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("take".to_owned(), "2".to_owned()),
+                    Matcher::UrlEncoded("from".to_owned(), "MQ==".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "members": [{
+                            "user_id": "auth0|63bfd5cdbd7f1c642dd83768",
+                            "email": "test@example.com"
+                        }]
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_organization_members("org_W2pqeQg6pL6Ar3sk", Some("MQ=="), Some(2))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.members[0].user_id, "auth0|63bfd5cdbd7f1c642dd83768");
+            assert_eq!(resp.next, None);
+        }
+
+        #[tokio::test]
+        async fn add_organization_members() {
+            let m = mock("POST", "/organizations/org_W2pqeQg6pL6Ar3sk/members")
+                .match_body(Matcher::Json(json!({
+                    "members": ["auth0|63bfd5cdbd7f1c642dd83768"]
+                })))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .add_organization_members(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    &["auth0|63bfd5cdbd7f1c642dd83768"],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_organization_members() {
+            let m = mock("DELETE", "/organizations/org_W2pqeQg6pL6Ar3sk/members")
+                .match_body(Matcher::Json(json!({
+                    "members": ["auth0|63bfd5cdbd7f1c642dd83768"]
+                })))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .remove_organization_members(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    &["auth0|63bfd5cdbd7f1c642dd83768"],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod member_roles {
+        use super::*;
+
+        const PATH: &str =
+            "/organizations/org_W2pqeQg6pL6Ar3sk/members/auth0|63bfd5cdbd7f1c642dd83768/roles";
+
+        #[tokio::test]
+        async fn list_member_roles() {
+            let _m = mock("GET", PATH)
+                .with_status(200)
+                .with_body(json!([{ "id": "rol_0tK3QKvbSvXcXz7w", "name": "admin" }]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_member_roles(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &Default::default(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].name, "admin");
+        }
+
+        #[tokio::test]
+        async fn assign_member_roles() {
+            let m = mock("POST", PATH)
+                .match_body(Matcher::Json(json!({ "roles": ["rol_0tK3QKvbSvXcXz7w"] })))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .assign_member_roles(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &["rol_0tK3QKvbSvXcXz7w"],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_member_roles() {
+            let m = mock("DELETE", PATH)
+                .match_body(Matcher::Json(json!({ "roles": ["rol_0tK3QKvbSvXcXz7w"] })))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .remove_member_roles(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &["rol_0tK3QKvbSvXcXz7w"],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod enabled_connections {
+        use super::*;
+
+        fn enabled_connection() -> serde_json::Value {
+            json!({
+                "connection_id": "con_0tK3QKvbSvXcXz7w",
+                "assign_membership_on_login": true,
+                "connection": { "name": "acme-oidc", "strategy": "oidc" }
+            })
+        }
+
+        #[tokio::test]
+        async fn list_organization_connections() {
+            let _m = mock(
+                "GET",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/enabled_connections",
+            )
+            .with_status(200)
+            .with_body(json!([enabled_connection()]).to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_organization_connections("org_W2pqeQg6pL6Ar3sk", &Default::default())
+                .await
+                .unwrap();
+
+            assert!(resp[0].assign_membership_on_login);
+            assert_eq!(resp[0].connection.as_ref().unwrap().strategy, "oidc");
+        }
+
+        #[tokio::test]
+        async fn add_organization_connection() {
+            let _m = mock(
+                "POST",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/enabled_connections",
+            )
+            .match_body(Matcher::Json(json!({
+                "connection_id": "con_0tK3QKvbSvXcXz7w",
+                "assign_membership_on_login": true
+            })))
+            .with_status(201)
+            .with_body(enabled_connection().to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .add_organization_connection("org_W2pqeQg6pL6Ar3sk", "con_0tK3QKvbSvXcXz7w", true)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.connection_id, "con_0tK3QKvbSvXcXz7w");
+        }
+
+        #[tokio::test]
+        async fn update_organization_connection() {
+            let m = mock(
+                "PATCH",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/enabled_connections/con_0tK3QKvbSvXcXz7w",
+            )
+            .match_body(Matcher::Json(
+                json!({ "assign_membership_on_login": false }),
+            ))
+            .with_status(200)
+            .with_body(enabled_connection().to_string())
+            .create();
+            let mut client = new_client();
+
+            client
+                .update_organization_connection(
+                    "org_W2pqeQg6pL6Ar3sk",
+                    "con_0tK3QKvbSvXcXz7w",
+                    false,
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_organization_connection() {
+            let m = mock(
+                "DELETE",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/enabled_connections/con_0tK3QKvbSvXcXz7w",
+            )
+            .with_status(204)
+            .create();
+            let mut client = new_client();
+
+            client
+                .remove_organization_connection("org_W2pqeQg6pL6Ar3sk", "con_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! Types, traits and functions relative to the invitations of organizations.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for inviting users to organizations.
+#[async_trait]
+pub trait OperateInvitations {
+    /// Invites a user to an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `payload` - A struct containing the necessary information to invite a user.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn invite(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::organizations::invitations::{CreateInvitationPayload, OperateInvitations};
+    /// let mut payload = CreateInvitationPayload::new("Jane Doe", "john@example.com", "app_client_id");
+    /// payload.roles = Some(vec!["rol_0tK3QKvbSvXcXz7w".to_owned()]);
+    ///
+    /// let invitation = client
+    ///     .create_invitation("org_W2pqeQg6pL6Ar3sk", &payload)
+    ///     .await?;
+    ///
+    /// println!("Invitation sent: {}", invitation.invitation_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_invitation(
+        &mut self,
+        organization_id: &str,
+        payload: &CreateInvitationPayload,
+    ) -> Auth0Result<Invitation>;
+
+    /// Lists the pending invitations of an organization through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `params` - The page of the invitations to list.
+    async fn list_invitations(
+        &mut self,
+        organization_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Invitation>>;
+
+    /// Gets an invitation through the Auth0 organizations API.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `invitation_id` - The ID of the invitation to get.
+    async fn get_invitation(
+        &mut self,
+        organization_id: &str,
+        invitation_id: &str,
+    ) -> Auth0Result<Invitation>;
+
+    /// Deletes an invitation through the Auth0 organizations API, which can no longer be accepted.
+    ///
+    /// # Arguments
+    /// * `organization_id` - The ID of the organization.
+    /// * `invitation_id` - The ID of the invitation to delete.
+    async fn delete_invitation(
+        &mut self,
+        organization_id: &str,
+        invitation_id: &str,
+    ) -> Auth0Result<()>;
+}
+
+/// The user sending an invitation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inviter {
+    /// The name of the inviter, shown in the invitation email.
+    pub name: String,
+}
+
+/// The user receiving an invitation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Invitee {
+    pub email: String,
+}
+
+/// An invitation of a user to an organization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Invitation {
+    pub id: String,
+    pub organization_id: String,
+    pub inviter: Inviter,
+    pub invitee: Invitee,
+    /// The URL the invitee accepts the invitation at.
+    pub invitation_url: String,
+    /// The ID of the ticket of the invitation, see [`Invitation::ticket`].
+    pub ticket_id: Option<String>,
+    /// The client ID of the application the invitee is redirected to.
+    pub client_id: String,
+    pub connection_id: Option<String>,
+    /// The IDs of the roles the invitee is assigned in the organization.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    pub app_metadata: Option<Value>,
+    pub user_metadata: Option<Value>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    /// Returns the ticket of the invitation, to send to the authorize endpoint as `invitation`.
+    ///
+    /// It falls back to the `invitation` parameter of the invitation URL when `ticket_id` is missing.
+    pub fn ticket(&self) -> Option<&str> {
+        self.ticket_id.as_deref().or_else(|| {
+            let (_, query) = self.invitation_url.split_once('?')?;
+
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("invitation="))
+        })
+    }
+}
+
+/// A struct containing the payload for inviting a user to an organization.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateInvitationPayload {
+    pub inviter: Inviter,
+    pub invitee: Invitee,
+    /// The client ID of the application the invitee is redirected to.
+    pub client_id: String,
+    /// The connection the invitee signs up with, if not the one they choose.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// The IDs of the roles to assign to the invitee in the organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_metadata: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<Value>,
+    /// The lifetime of the invitation in seconds, 7 days by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl_sec: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_invitation_email: Option<bool>,
+}
+
+impl CreateInvitationPayload {
+    /// Returns a payload for inviting a user to the given application.
+    ///
+    /// # Arguments
+    ///
+    /// * `inviter` - The name of the user sending the invitation.
+    /// * `invitee` - The email of the user receiving the invitation.
+    /// * `client_id` - The client ID of the application the invitee is redirected to.
+    pub fn new(inviter: &str, invitee: &str, client_id: &str) -> Self {
+        Self {
+            inviter: Inviter {
+                name: inviter.to_owned(),
+            },
+            invitee: Invitee {
+                email: invitee.to_owned(),
+            },
+            client_id: client_id.to_owned(),
+            connection_id: None,
+            roles: None,
+            app_metadata: None,
+            user_metadata: None,
+            ttl_sec: None,
+            send_invitation_email: None,
+        }
+    }
+}
+
+#[async_trait]
+impl OperateInvitations for Auth0Client {
+    async fn create_invitation(
+        &mut self,
+        organization_id: &str,
+        payload: &CreateInvitationPayload,
+    ) -> Auth0Result<Invitation> {
+        self.request::<_, _, ManagementError>(
+            Method::POST,
+            &format!("/organizations/{organization_id}/invitations"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_invitations(
+        &mut self,
+        organization_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Invitation>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!("/organizations/{organization_id}/invitations")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_invitation(
+        &mut self,
+        organization_id: &str,
+        invitation_id: &str,
+    ) -> Auth0Result<Invitation> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/organizations/{organization_id}/invitations/{invitation_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_invitation(
+        &mut self,
+        organization_id: &str,
+        invitation_id: &str,
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/organizations/{organization_id}/invitations/{invitation_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn invitation() -> Value {
+        json!({
+            "id": "uinv_0tK3QKvbSvXcXz7w",
+            "organization_id": "org_W2pqeQg6pL6Ar3sk",
+            "inviter": { "name": "Jane Doe" },
+            "invitee": { "email": "john@example.com" },
+            "invitation_url": "https://app.example.com/login?invitation=Fd2E5Tp8JWz1&organization=org_W2pqeQg6pL6Ar3sk&organization_name=acme",
+            "ticket_id": "Fd2E5Tp8JWz1",
+            "client_id": "app_client_id",
+            "roles": ["rol_0tK3QKvbSvXcXz7w"],
+            "created_at": "2023-01-12T09:24:45.761Z",
+            "expires_at": "2023-01-19T09:24:45.761Z"
+        })
+    }
+
+    mod ticket {
+        use super::*;
+
+        #[test]
+        fn fall_back_to_the_ticket_of_the_url() {
+            let mut value = invitation();
+            value["ticket_id"] = Value::Null;
+
+            let invitation: Invitation = serde_json::from_value(value).unwrap();
+
+            assert_eq!(invitation.ticket(), Some("Fd2E5Tp8JWz1"));
+        }
+    }
+
+    mod create_invitation {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/organizations/org_W2pqeQg6pL6Ar3sk/invitations")
+                .match_body(Matcher::Json(json!({
+                    "inviter": { "name": "Jane Doe" },
+                    "invitee": { "email": "john@example.com" },
+                    "client_id": "app_client_id",
+                    "roles": ["rol_0tK3QKvbSvXcXz7w"],
+                    "send_invitation_email": false
+                })))
+                .with_status(201)
+                .with_body(invitation().to_string())
+                .create();
+            let mut client = new_client();
+
+            let mut payload =
+                CreateInvitationPayload::new("Jane Doe", "john@example.com", "app_client_id");
+            payload.roles = Some(vec!["rol_0tK3QKvbSvXcXz7w".to_owned()]);
+            payload.send_invitation_email = Some(false);
+            let resp = client
+                .create_invitation("org_W2pqeQg6pL6Ar3sk", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.ticket(), Some("Fd2E5Tp8JWz1"));
+            assert_eq!(resp.invitee.email, "john@example.com");
+        }
+
+        #[tokio::test]
+        async fn errored_with_invalid_invitee() {
+            let _m = mock("POST", "/organizations/org_W2pqeQg6pL6Ar3sk/invitations")
+                .with_status(400)
+                .with_body(
+                    json!({
+                        "statusCode": 400,
+                        "error": "Bad Request",
+                        "message": "Payload validation error: 'Object didn't pass validation for format email: not-an-email' on property invitee.email.",
+                        "errorCode": "invalid_body"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = CreateInvitationPayload::new("Jane Doe", "not-an-email", "app_client_id");
+            let resp = client
+                .create_invitation("org_W2pqeQg6pL6Ar3sk", &payload)
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::InvalidRequestBody(_))) => (),
+                _ => panic!("Expected InvalidRequestBody variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod list_invitations {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_page() {
+            let _m = mock("GET", "/organizations/org_W2pqeQg6pL6Ar3sk/invitations")
+                .match_query(Matcher::UrlEncoded("per_page".to_owned(), "10".to_owned()))
+                .with_status(200)
+                .with_body(json!([invitation()]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_invitations("org_W2pqeQg6pL6Ar3sk", &ListParams::default().per_page(10))
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].roles, vec!["rol_0tK3QKvbSvXcXz7w"]);
+        }
+    }
+
+    mod get_invitation {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock(
+                "GET",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/invitations/uinv_0tK3QKvbSvXcXz7w",
+            )
+            .with_status(200)
+            .with_body(invitation().to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_invitation("org_W2pqeQg6pL6Ar3sk", "uinv_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.inviter.name, "Jane Doe");
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_invitation() {
+            let _m = mock(
+                "GET",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/invitations/uinv_unknown",
+            )
+            .with_status(404)
+            .with_body(
+                json!({
+                    "statusCode": 404,
+                    "error": "Not Found",
+                    "message": "The invitation does not exist"
+                })
+                .to_string(),
+            )
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_invitation("org_W2pqeQg6pL6Ar3sk", "uinv_unknown")
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod delete_invitation {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock(
+                "DELETE",
+                "/organizations/org_W2pqeQg6pL6Ar3sk/invitations/uinv_0tK3QKvbSvXcXz7w",
+            )
+            .with_status(204)
+            .create();
+            let mut client = new_client();
+
+            client
+                .delete_invitation("org_W2pqeQg6pL6Ar3sk", "uinv_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Types, traits and functions relative to the refresh tokens API.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::sessions::SessionDevice;
+use crate::management::users::UserError;
+use crate::Auth0Client;
+
+/// Trait for listing and revoking the refresh tokens issued to users.
+///
+/// Unlike the revocation endpoint of the authentication API, it does not require the
+/// refresh token itself, e.g. to clean up the tokens of a user from an admin panel.
+#[async_trait]
+pub trait OperateRefreshTokens {
+    /// Lists the refresh tokens of a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to list the refresh tokens of.
+    /// * `from` - The `next` checkpoint of the previous page, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_tokens(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::refresh_tokens::OperateRefreshTokens;
+    /// let page = client
+    ///     .list_user_refresh_tokens("auth0|63bfd5cdbd7f1c642dd83768", None)
+    ///     .await?;
+    ///
+    /// for token in page.tokens {
+    ///     println!("{} issued to {}", token.id, token.client_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_user_refresh_tokens(
+        &mut self,
+        user_id: &str,
+        from: Option<&str>,
+    ) -> Auth0Result<RefreshTokenPage>;
+
+    /// Gets a refresh token through the Auth0 refresh tokens API.
+    ///
+    /// # Arguments
+    /// * `token_id` - The ID of the refresh token to get.
+    async fn get_refresh_token(&mut self, token_id: &str) -> Auth0Result<RefreshToken>;
+
+    /// Revokes a refresh token through the Auth0 refresh tokens API.
+    ///
+    /// # Arguments
+    /// * `token_id` - The ID of the refresh token to revoke.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn revoke(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::refresh_tokens::OperateRefreshTokens;
+    /// client.delete_refresh_token("rt_0tK3QKvbSvXcXz7w").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn delete_refresh_token(&mut self, token_id: &str) -> Auth0Result<()>;
+}
+
+/// A page of the refresh tokens of a user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenPage {
+    pub tokens: Vec<RefreshToken>,
+    /// The checkpoint of the next page, if there are more refresh tokens.
+    pub next: Option<String>,
+}
+
+/// A refresh token issued to a user, without its value.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshToken {
+    pub id: String,
+    pub user_id: String,
+    pub client_id: String,
+    /// The session the refresh token was issued in, if any.
+    pub session_id: Option<String>,
+    #[serde(default)]
+    pub rotating: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_exchanged_at: Option<DateTime<Utc>>,
+    pub idle_expires_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub device: Option<SessionDevice>,
+    /// The APIs the refresh token grants access to.
+    #[serde(default)]
+    pub resource_servers: Vec<RefreshTokenResourceServer>,
+}
+
+/// An API a refresh token grants access to.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenResourceServer {
+    pub audience: String,
+    /// The space-separated scopes granted on the API.
+    pub scopes: Option<String>,
+}
+
+#[async_trait]
+impl OperateRefreshTokens for Auth0Client {
+    async fn list_user_refresh_tokens(
+        &mut self,
+        user_id: &str,
+        from: Option<&str>,
+    ) -> Auth0Result<RefreshTokenPage> {
+        let mut path = format!("/users/{user_id}/refresh-tokens");
+        if let Some(from) = from {
+            path.push_str(&format!("?from={}", urlencoding::encode(from)));
+        }
+
+        self.request::<_, _, UserError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_refresh_token(&mut self, token_id: &str) -> Auth0Result<RefreshToken> {
+        self.request::<_, _, UserError>(
+            Method::GET,
+            &format!("/refresh-tokens/{token_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_refresh_token(&mut self, token_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/refresh-tokens/{token_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn refresh_token() -> serde_json::Value {
+        json!({
+            "id": "rt_0tK3QKvbSvXcXz7w",
+            "user_id": "auth0|63bfd5cdbd7f1c642dd83768",
+            "client_id": "client_id",
+            "session_id": "sess_0tK3QKvbSvXcXz7w",
+            "rotating": true,
+            "created_at": "2023-01-12T09:24:45.761Z",
+            "device": { "initial_ip": "10.0.0.1" },
+            "resource_servers": [{
+                "audience": "https://api.example.com",
+                "scopes": "offline_access read:orders"
+            }]
+        })
+    }
+
+    fn not_found() -> String {
+        json!({
+            "statusCode": 404,
+            "error": "Not Found",
+            "message": "The refresh token does not exist.",
+            "errorCode": "inexistent_refresh_token"
+        })
+        .to_string()
+    }
+
+    mod list_user_refresh_tokens {
+        use super::*;
+        use mockito::Matcher;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock(
+                "GET",
+                "/users/auth0|63bfd5cdbd7f1c642dd83768/refresh-tokens",
+            )
+            .with_status(200)
+            .with_body(json!({ "tokens": [refresh_token()] }).to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_user_refresh_tokens("auth0|63bfd5cdbd7f1c642dd83768", None)
+                .await
+                .unwrap();
+
+            assert!(resp.tokens[0].rotating);
+            assert_eq!(
+                resp.tokens[0].resource_servers[0].audience,
+                "https://api.example.com"
+            );
+            assert_eq!(resp.next, None);
+        }
+
+        #[tokio::test]
+        async fn send_the_checkpoint() {
+            let _m = mock(
+                "GET",
+                "/users/auth0|63bfd5cdbd7f1c642dd83768/refresh-tokens",
+            )
+            .match_query(Matcher::UrlEncoded(
+                "from".to_owned(),
+                "rt_0tK3QKvbSvXcXz7w".to_owned(),
+            ))
+            .with_status(200)
+            .with_body(json!({ "tokens": [], "next": "rt_1tK3QKvbSvXcXz7w" }).to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_user_refresh_tokens(
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    Some("rt_0tK3QKvbSvXcXz7w"),
+                )
+                .await
+                .unwrap();
+
+            assert!(resp.tokens.is_empty());
+            assert_eq!(resp.next.as_deref(), Some("rt_1tK3QKvbSvXcXz7w"));
+        }
+    }
+
+    mod get_refresh_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/refresh-tokens/rt_0tK3QKvbSvXcXz7w")
+                .with_status(200)
+                .with_body(refresh_token().to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_refresh_token("rt_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            assert_eq!(resp.session_id.as_deref(), Some("sess_0tK3QKvbSvXcXz7w"));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_token() {
+            let _m = mock("GET", "/refresh-tokens/rt_unknown")
+                .with_status(404)
+                .with_body(not_found())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_refresh_token("rt_unknown").await;
+
+            match resp {
+                Err(Error::User(UserError::Unknown(_))) => (),
+                _ => panic!("Expected Unknown variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod delete_refresh_token {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/refresh-tokens/rt_0tK3QKvbSvXcXz7w")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .delete_refresh_token("rt_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_token() {
+            let _m = mock("DELETE", "/refresh-tokens/rt_unknown")
+                .with_status(404)
+                .with_body(not_found())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.delete_refresh_token("rt_unknown").await;
+
+            match resp {
+                Err(Error::User(UserError::Unknown(_))) => (),
+                _ => panic!("Expected Unknown variant, got: {resp:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,602 @@
+//! Types, traits and functions relative to the resource servers API, i.e. the APIs of the tenant.
+
+use async_trait::async_trait;
+use jsonwebtoken::Algorithm;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the APIs of the tenant.
+#[async_trait]
+pub trait OperateResourceServers {
+    /// Lists the APIs of the tenant through the Auth0 resource servers API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the APIs to list.
+    async fn list_resource_servers(
+        &mut self,
+        params: &ListParams,
+    ) -> Auth0Result<Page<ResourceServer>>;
+
+    /// Gets an API through the Auth0 resource servers API.
+    ///
+    /// # Arguments
+    /// * `id` - The ID or the identifier (audience) of the API to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_api(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::resource_servers::OperateResourceServers;
+    /// let api = client.get_resource_server("https://api.example.com").await?;
+    ///
+    /// println!("RBAC enabled: {:?}", api.enforce_policies);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_resource_server(&mut self, id: &str) -> Auth0Result<ResourceServer>;
+
+    /// Creates an API through the Auth0 resource servers API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create an API.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_api(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::resource_servers::{CreateResourceServerPayload, OperateResourceServers, ResourceServerScope};
+    /// let mut payload = CreateResourceServerPayload::new("Orders", "https://api.example.com");
+    /// payload.scopes = Some(vec![ResourceServerScope::new("read:orders", "Read orders")]);
+    /// payload.enforce_policies = Some(true);
+    /// payload.token_lifetime = Some(3600);
+    ///
+    /// let api = client.create_resource_server(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_resource_server(
+        &mut self,
+        payload: &CreateResourceServerPayload,
+    ) -> Auth0Result<ResourceServer>;
+
+    /// Updates an API through the Auth0 resource servers API.
+    ///
+    /// # Arguments
+    /// * `id` - The ID or the identifier (audience) of the API to update.
+    /// * `payload` - The fields of the API to update.
+    async fn update_resource_server(
+        &mut self,
+        id: &str,
+        payload: &UpdateResourceServerPayload,
+    ) -> Auth0Result<ResourceServer>;
+
+    /// Deletes an API through the Auth0 resource servers API.
+    ///
+    /// # Arguments
+    /// * `id` - The ID or the identifier (audience) of the API to delete.
+    async fn delete_resource_server(&mut self, id: &str) -> Auth0Result<()>;
+
+    /// Adds a scope to an API, or updates its description if the API already has it.
+    ///
+    /// The API only allows replacing its scopes as a whole and has no conditional writes, so
+    /// they are read, modified and written back on a best-effort basis: a concurrent update
+    /// of the scopes may be lost. They are read again after the write, which fails with
+    /// [`ManagementError::Conflict`] if they no longer are the written ones.
+    ///
+    /// # Arguments
+    /// * `id` - The ID or the identifier (audience) of the API.
+    /// * `scope` - The scope to add.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn add_scope(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::resource_servers::{OperateResourceServers, ResourceServerScope};
+    /// client
+    ///     .add_scope(
+    ///         "63bfd5cdbd7f1c642dd83768",
+    ///         ResourceServerScope::new("write:orders", "Create orders"),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn add_scope(
+        &mut self,
+        id: &str,
+        scope: ResourceServerScope,
+    ) -> Auth0Result<ResourceServer>;
+
+    /// Removes a scope from an API, the same way [`OperateResourceServers::add_scope`] adds one.
+    ///
+    /// # Arguments
+    /// * `id` - The ID or the identifier (audience) of the API.
+    /// * `value` - The value of the scope to remove.
+    async fn remove_scope(&mut self, id: &str, value: &str) -> Auth0Result<ResourceServer>;
+}
+
+/// An API of the tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResourceServer {
+    pub id: String,
+    pub name: String,
+    /// The audience of the access tokens of the API.
+    pub identifier: String,
+    #[serde(default)]
+    pub scopes: Vec<ResourceServerScope>,
+    pub signing_alg: Option<Algorithm>,
+    pub signing_secret: Option<String>,
+    pub allow_offline_access: Option<bool>,
+    /// The lifetime of the access tokens, in seconds.
+    pub token_lifetime: Option<u64>,
+    /// The lifetime of the access tokens issued to browsers, in seconds.
+    pub token_lifetime_for_web: Option<u64>,
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+    /// Whether RBAC is enabled for the API.
+    pub enforce_policies: Option<bool>,
+    /// `access_token`, or `access_token_authz` to add the permissions to the access tokens.
+    pub token_dialect: Option<String>,
+    /// Whether the API is defined by Auth0, such as the Management API.
+    #[serde(default)]
+    pub is_system: bool,
+}
+
+/// A scope of an API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceServerScope {
+    pub value: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl ResourceServerScope {
+    /// Creates a scope with the given value and description.
+    pub fn new(value: &str, description: &str) -> Self {
+        Self {
+            value: value.to_owned(),
+            description: description.to_owned(),
+        }
+    }
+}
+
+/// A struct containing the payload for creating an API.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateResourceServerPayload {
+    pub name: String,
+    pub identifier: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ResourceServerScope>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_alg: Option<Algorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_offline_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime_for_web: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_policies: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_dialect: Option<String>,
+}
+
+impl CreateResourceServerPayload {
+    /// Returns a payload for creating an API with only `name` and `identifier` set.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the API.
+    /// * `identifier` - The audience of the access tokens of the API, which cannot be changed.
+    pub fn new(name: &str, identifier: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            identifier: identifier.to_owned(),
+            scopes: None,
+            signing_alg: None,
+            signing_secret: None,
+            allow_offline_access: None,
+            token_lifetime: None,
+            token_lifetime_for_web: None,
+            skip_consent_for_verifiable_first_party_clients: None,
+            enforce_policies: None,
+            token_dialect: None,
+        }
+    }
+}
+
+/// A struct containing the payload for updating an API.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateResourceServerPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The scopes of the API, which replace the existing ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ResourceServerScope>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_alg: Option<Algorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_offline_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime_for_web: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_consent_for_verifiable_first_party_clients: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_policies: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_dialect: Option<String>,
+}
+
+/// Reads, modifies and writes back the scopes of an API, then reads them again to detect lost updates.
+async fn update_scopes<F>(
+    client: &mut Auth0Client,
+    id: &str,
+    update: F,
+) -> Auth0Result<ResourceServer>
+where
+    F: Fn(&mut Vec<ResourceServerScope>) + Send,
+{
+    let resource_server = client.get_resource_server(id).await?;
+    let mut scopes = resource_server.scopes.clone();
+    update(&mut scopes);
+
+    if scopes == resource_server.scopes {
+        return Ok(resource_server);
+    }
+
+    let payload = UpdateResourceServerPayload {
+        scopes: Some(scopes),
+        ..Default::default()
+    };
+    client.update_resource_server(id, &payload).await?;
+
+    let resource_server = client.get_resource_server(id).await?;
+    if Some(&resource_server.scopes) != payload.scopes.as_ref() {
+        return Err(ManagementError::Conflict(format!(
+            "The scopes of {id} were updated concurrently"
+        ))
+        .into());
+    }
+    Ok(resource_server)
+}
+
+#[async_trait]
+impl OperateResourceServers for Auth0Client {
+    async fn list_resource_servers(
+        &mut self,
+        params: &ListParams,
+    ) -> Auth0Result<Page<ResourceServer>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply("/resource-servers"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_resource_server(&mut self, id: &str) -> Auth0Result<ResourceServer> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/resource-servers/{}", urlencoding::encode(id)),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_resource_server(
+        &mut self,
+        payload: &CreateResourceServerPayload,
+    ) -> Auth0Result<ResourceServer> {
+        self.request::<_, _, ManagementError>(Method::POST, "/resource-servers", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_resource_server(
+        &mut self,
+        id: &str,
+        payload: &UpdateResourceServerPayload,
+    ) -> Auth0Result<ResourceServer> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/resource-servers/{}", urlencoding::encode(id)),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_resource_server(&mut self, id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/resource-servers/{}", urlencoding::encode(id)),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn add_scope(
+        &mut self,
+        id: &str,
+        scope: ResourceServerScope,
+    ) -> Auth0Result<ResourceServer> {
+        update_scopes(self, id, |scopes| {
+            match scopes.iter_mut().find(|s| s.value == scope.value) {
+                Some(existing) => existing.description = scope.description.clone(),
+                None => scopes.push(scope.clone()),
+            }
+        })
+        .await
+    }
+
+    async fn remove_scope(&mut self, id: &str, value: &str) -> Auth0Result<ResourceServer> {
+        update_scopes(self, id, |scopes| scopes.retain(|s| s.value != value)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn resource_server() -> String {
+        json!({
+            "id": "63bfd5cdbd7f1c642dd83768",
+            "name": "Orders",
+            "identifier": "https://api.example.com",
+            "scopes": [{ "value": "read:orders", "description": "Read orders" }],
+            "signing_alg": "RS256",
+            "allow_offline_access": true,
+            "token_lifetime": 86400,
+            "token_lifetime_for_web": 7200,
+            "enforce_policies": true,
+            "token_dialect": "access_token_authz"
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn list_resource_servers() {
+        let _m = mock("GET", "/resource-servers")
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "0".to_owned()))
+            .with_status(200)
+            .with_body(format!("[{}]", resource_server()))
+            .create();
+        let mut client = new_client();
+
+        let resp = client
+            .list_resource_servers(&ListParams::default().page(0))
+            .await
+            .unwrap();
+
+        assert_eq!(resp[0].signing_alg, Some(Algorithm::RS256));
+        assert!(!resp[0].is_system);
+    }
+
+    #[tokio::test]
+    async fn get_resource_server() {
+        let _m = mock("GET", "/resource-servers/63bfd5cdbd7f1c642dd83768")
+            .with_status(200)
+            .with_body(resource_server())
+            .create();
+        let mut client = new_client();
+
+        let resp = client
+            .get_resource_server("63bfd5cdbd7f1c642dd83768")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resp.scopes,
+            vec![ResourceServerScope::new("read:orders", "Read orders")]
+        );
+        assert_eq!(resp.enforce_policies, Some(true));
+    }
+
+    #[tokio::test]
+    async fn get_resource_server_by_identifier() {
+        let _m = mock(
+            "GET",
+            "/resource-servers/https%3A%2F%2Fapi.example.com%2Fv1",
+        )
+        .with_status(200)
+        .with_body(resource_server())
+        .create();
+        let mut client = new_client();
+
+        let resp = client
+            .get_resource_server("https://api.example.com/v1")
+            .await
+            .unwrap();
+
+        assert_eq!(resp.id, "63bfd5cdbd7f1c642dd83768");
+    }
+
+    #[tokio::test]
+    async fn create_resource_server() {
+        let _m = mock("POST", "/resource-servers")
+            .match_body(Matcher::Json(json!({
+                "name": "Orders",
+                "identifier": "https://api.example.com",
+                "signing_alg": "RS256",
+                "token_lifetime": 86400
+            })))
+            .with_status(201)
+            .with_body(resource_server())
+            .create();
+        let mut client = new_client();
+
+        let mut payload = CreateResourceServerPayload::new("Orders", "https://api.example.com");
+        payload.signing_alg = Some(Algorithm::RS256);
+        payload.token_lifetime = Some(86400);
+        let resp = client.create_resource_server(&payload).await.unwrap();
+
+        assert_eq!(resp.id, "63bfd5cdbd7f1c642dd83768");
+    }
+
+    #[tokio::test]
+    async fn update_resource_server() {
+        let m = mock("PATCH", "/resource-servers/63bfd5cdbd7f1c642dd83768")
+            .match_body(Matcher::Json(json!({ "enforce_policies": true })))
+            .with_status(200)
+            .with_body(resource_server())
+            .create();
+        let mut client = new_client();
+
+        let payload = UpdateResourceServerPayload {
+            enforce_policies: Some(true),
+            ..Default::default()
+        };
+        client
+            .update_resource_server("63bfd5cdbd7f1c642dd83768", &payload)
+            .await
+            .unwrap();
+
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn delete_resource_server() {
+        let m = mock("DELETE", "/resource-servers/63bfd5cdbd7f1c642dd83768")
+            .with_status(204)
+            .create();
+        let mut client = new_client();
+
+        client
+            .delete_resource_server("63bfd5cdbd7f1c642dd83768")
+            .await
+            .unwrap();
+
+        m.assert();
+    }
+
+    mod scopes {
+        use super::*;
+
+        fn with_scopes(id: &str, scopes: serde_json::Value) -> String {
+            json!({
+                "id": id,
+                "name": "Orders",
+                "identifier": "https://api.example.com",
+                "scopes": scopes
+            })
+            .to_string()
+        }
+
+        #[tokio::test]
+        async fn add_a_scope() {
+            let _before = mock("GET", "/resource-servers/rs_add")
+                .with_status(200)
+                .with_body(with_scopes("rs_add", json!([{ "value": "read:orders" }])))
+                .expect(1)
+                .create();
+            let _after = mock("GET", "/resource-servers/rs_add")
+                .with_status(200)
+                .with_body(with_scopes(
+                    "rs_add",
+                    json!([
+                        { "value": "read:orders", "description": "" },
+                        { "value": "write:orders", "description": "Create orders" }
+                    ]),
+                ))
+                .create();
+            let patch = mock("PATCH", "/resource-servers/rs_add")
+                .match_body(Matcher::Json(json!({
+                    "scopes": [
+                        { "value": "read:orders", "description": "" },
+                        { "value": "write:orders", "description": "Create orders" }
+                    ]
+                })))
+                .with_status(200)
+                .with_body(with_scopes("rs_add", json!([])))
+                .create();
+            let mut client = new_client();
+
+            client
+                .add_scope(
+                    "rs_add",
+                    ResourceServerScope::new("write:orders", "Create orders"),
+                )
+                .await
+                .unwrap();
+
+            patch.assert();
+        }
+
+        #[tokio::test]
+        async fn skip_the_write_without_changes() {
+            let _get = mock("GET", "/resource-servers/rs_noop")
+                .with_status(200)
+                .with_body(with_scopes("rs_noop", json!([{ "value": "read:orders" }])))
+                .create();
+            let patch = mock("PATCH", "/resource-servers/rs_noop")
+                .with_status(200)
+                .expect(0)
+                .create();
+            let mut client = new_client();
+
+            client
+                .remove_scope("rs_noop", "write:orders")
+                .await
+                .unwrap();
+
+            patch.assert();
+        }
+
+        #[tokio::test]
+        async fn detect_concurrent_updates() {
+            let _before = mock("GET", "/resource-servers/rs_conflict")
+                .with_status(200)
+                .with_body(with_scopes(
+                    "rs_conflict",
+                    json!([{ "value": "read:orders" }, { "value": "write:orders" }]),
+                ))
+                .expect(1)
+                .create();
+            let _after = mock("GET", "/resource-servers/rs_conflict")
+                .with_status(200)
+                .with_body(with_scopes(
+                    "rs_conflict",
+                    json!([{ "value": "read:orders" }, { "value": "delete:orders" }]),
+                ))
+                .create();
+            let patch = mock("PATCH", "/resource-servers/rs_conflict")
+                .match_body(Matcher::Json(json!({
+                    "scopes": [{ "value": "read:orders", "description": "" }]
+                })))
+                .with_status(200)
+                .with_body(with_scopes("rs_conflict", json!([])))
+                .create();
+            let mut client = new_client();
+
+            let resp = client.remove_scope("rs_conflict", "write:orders").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::Conflict(_))) => (),
+                _ => panic!("Expected Conflict variant, got: {resp:?}"),
+            }
+            patch.assert();
+        }
+    }
+}
@@ -0,0 +1,643 @@
+//! Types, traits and functions relative to the roles API.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{
+    checkpoint_path, ListParams, ManagementError, Page, Permission, PermissionRef,
+};
+use crate::Auth0Client;
+
+/// Trait for operating the roles of the tenant.
+#[async_trait]
+pub trait OperateRoles {
+    /// Creates a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `payload` - The name and description of the role.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_role(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::roles::{CreateRolePayload, OperateRoles};
+    /// let mut payload = CreateRolePayload::new("admin");
+    /// payload.description = Some("Administrators".to_owned());
+    ///
+    /// let role = client.create_role(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_role(&mut self, payload: &CreateRolePayload) -> Auth0Result<Role>;
+
+    /// Gets a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to get.
+    async fn get_role(&mut self, role_id: &str) -> Auth0Result<Role>;
+
+    /// Lists the roles of the tenant through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the roles to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_roles(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::roles::OperateRoles;
+    /// # use auth0_client::management::ListParams;
+    /// let roles = client
+    ///     .list_roles(&ListParams::default().per_page(100))
+    ///     .await?;
+    ///
+    /// for role in roles {
+    ///     println!("{}: {:?}", role.name, role.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_roles(&mut self, params: &ListParams) -> Auth0Result<Page<Role>>;
+
+    /// Updates a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to update.
+    /// * `payload` - The fields of the role to update.
+    async fn update_role(
+        &mut self,
+        role_id: &str,
+        payload: &UpdateRolePayload,
+    ) -> Auth0Result<Role>;
+
+    /// Deletes a role through the Auth0 roles API, removing it from its users.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to delete.
+    async fn delete_role(&mut self, role_id: &str) -> Auth0Result<()>;
+
+    /// Lists the permissions of a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to list the permissions of.
+    /// * `params` - The page of the permissions to list.
+    async fn list_role_permissions(
+        &mut self,
+        role_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Permission>>;
+
+    /// Adds permissions to a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to add the permissions to.
+    /// * `permissions` - The permissions to add.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn add_permissions(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::roles::OperateRoles;
+    /// # use auth0_client::management::PermissionRef;
+    /// let permissions = [PermissionRef::new("https://api.example.com", "orders:write")];
+    ///
+    /// client
+    ///     .add_permissions_to_role("rol_0tK3QKvbSvXcXz7w", &permissions)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn add_permissions_to_role(
+        &mut self,
+        role_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()>;
+
+    /// Removes permissions from a role through the Auth0 roles API.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to remove the permissions from.
+    /// * `permissions` - The permissions to remove.
+    async fn remove_permissions_from_role(
+        &mut self,
+        role_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()>;
+
+    /// Lists the users a role is assigned to through the Auth0 roles API.
+    ///
+    /// The endpoint uses checkpoint pagination, as offset pagination is limited for large roles.
+    ///
+    /// # Arguments
+    /// * `role_id` - The ID of the role to list the users of.
+    /// * `from` - The `next` checkpoint of the previous page, if any.
+    /// * `take` - The number of users per page, 50 by default.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_role_users(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::roles::OperateRoles;
+    /// let mut page = client
+    ///     .list_role_users("rol_0tK3QKvbSvXcXz7w", None, Some(100))
+    ///     .await?;
+    /// let mut users = page.users;
+    ///
+    /// while let Some(next) = page.next {
+    ///     page = client
+    ///         .list_role_users("rol_0tK3QKvbSvXcXz7w", Some(&next), Some(100))
+    ///         .await?;
+    ///     users.append(&mut page.users);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_role_users(
+        &mut self,
+        role_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<RoleUsersPage>;
+}
+
+/// A role of the tenant, grouping permissions assigned to users together.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Role {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// A page of the users a role is assigned to.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoleUsersPage {
+    pub users: Vec<RoleUser>,
+    /// The checkpoint of the next page, if there are more users.
+    pub next: Option<String>,
+}
+
+/// A user a role is assigned to.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RoleUser {
+    pub user_id: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+}
+
+/// A struct containing the payload for creating a role.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRolePayload {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl CreateRolePayload {
+    /// Returns a payload for creating a role with the given name and no description.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            description: None,
+        }
+    }
+}
+
+/// A struct containing the payload for updating a role.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateRolePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// The payload for adding permissions to, or removing permissions from, a role.
+#[derive(Serialize)]
+struct RolePermissionsPayload<'a> {
+    permissions: &'a [PermissionRef],
+}
+
+#[async_trait]
+impl OperateRoles for Auth0Client {
+    async fn create_role(&mut self, payload: &CreateRolePayload) -> Auth0Result<Role> {
+        self.request::<_, _, ManagementError>(Method::POST, "/roles", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_role(&mut self, role_id: &str) -> Auth0Result<Role> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/roles/{role_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_roles(&mut self, params: &ListParams) -> Auth0Result<Page<Role>> {
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply("/roles"), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_role(
+        &mut self,
+        role_id: &str,
+        payload: &UpdateRolePayload,
+    ) -> Auth0Result<Role> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/roles/{role_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_role(&mut self, role_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/roles/{role_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_role_permissions(
+        &mut self,
+        role_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Permission>> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &params.apply(&format!("/roles/{role_id}/permissions")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn add_permissions_to_role(
+        &mut self,
+        role_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::POST,
+            &format!("/roles/{role_id}/permissions"),
+            Some(RolePermissionsPayload { permissions }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_permissions_from_role(
+        &mut self,
+        role_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/roles/{role_id}/permissions"),
+            Some(RolePermissionsPayload { permissions }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_role_users(
+        &mut self,
+        role_id: &str,
+        from: Option<&str>,
+        take: Option<u32>,
+    ) -> Auth0Result<RoleUsersPage> {
+        let path = checkpoint_path(&format!("/roles/{role_id}/users"), from, take);
+
+        self.request::<_, _, ManagementError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn role() -> String {
+        json!({
+            "id": "rol_0tK3QKvbSvXcXz7w",
+            "name": "admin",
+            "description": "Administrators"
+        })
+        .to_string()
+    }
+
+    mod create_role {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("POST", "/roles")
+                .match_body(Matcher::Json(json!({ "name": "admin" })))
+                .with_status(200)
+                .with_body(role())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .create_role(&CreateRolePayload::new("admin"))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.id, "rol_0tK3QKvbSvXcXz7w");
+        }
+
+        #[tokio::test]
+        async fn errored_with_existing_role() {
+            let _m = mock("POST", "/roles")
+                .match_body(Matcher::Json(json!({ "name": "existing" })))
+                .with_status(409)
+                .with_body(
+                    json!({
+                        "statusCode": 409,
+                        "error": "Conflict",
+                        "message": "Role with name existing already exists"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .create_role(&CreateRolePayload::new("existing"))
+                .await;
+
+            match resp {
+                Err(Error::Management(ManagementError::Conflict(_))) => (),
+                _ => panic!("Expected Conflict variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod get_role {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/roles/rol_0tK3QKvbSvXcXz7w")
+                .with_status(200)
+                .with_body(role())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_role("rol_0tK3QKvbSvXcXz7w").await.unwrap();
+
+            assert_eq!(resp.description.as_deref(), Some("Administrators"));
+        }
+
+        #[tokio::test]
+        async fn errored_with_unknown_role() {
+            let _m = mock("GET", "/roles/rol_unknown")
+                .with_status(404)
+                .with_body(
+                    json!({
+                        "statusCode": 404,
+                        "error": "Not Found",
+                        "message": "The role does not exist.",
+                        "errorCode": "inexistent_role"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_role("rol_unknown").await;
+
+            match resp {
+                Err(Error::Management(ManagementError::NotFound(_))) => (),
+                _ => panic!("Expected NotFound variant, got: {resp:?}"),
+            }
+        }
+    }
+
+    mod list_roles {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_totals() {
+            let _m = mock("GET", "/roles")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("per_page".to_owned(), "1".to_owned()),
+                    Matcher::UrlEncoded("include_totals".to_owned(), "true".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "start": 0,
+                        "limit": 1,
+                        "total": 3,
+                        "roles": [{ "id": "rol_0tK3QKvbSvXcXz7w", "name": "admin" }]
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_roles(&ListParams::default().per_page(1).include_totals(true))
+                .await
+                .unwrap();
+
+            assert_eq!(resp.total, Some(3));
+            assert_eq!(resp[0].description, None);
+        }
+    }
+
+    mod update_role {
+        use super::*;
+
+        #[tokio::test]
+        async fn only_send_the_updated_fields() {
+            let _m = mock("PATCH", "/roles/rol_0tK3QKvbSvXcXz7w")
+                .match_body(Matcher::Json(json!({ "description": "Administrators" })))
+                .with_status(200)
+                .with_body(role())
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateRolePayload {
+                description: Some("Administrators".to_owned()),
+                ..Default::default()
+            };
+            let resp = client
+                .update_role("rol_0tK3QKvbSvXcXz7w", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.name, "admin");
+        }
+    }
+
+    mod delete_role {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let m = mock("DELETE", "/roles/rol_0tK3QKvbSvXcXz7w")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client.delete_role("rol_0tK3QKvbSvXcXz7w").await.unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod permissions {
+        use super::*;
+
+        fn permissions_body() -> serde_json::Value {
+            json!({
+                "permissions": [{
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "orders:write"
+                }]
+            })
+        }
+
+        #[tokio::test]
+        async fn list_role_permissions() {
+            let _m = mock("GET", "/roles/rol_0tK3QKvbSvXcXz7w/permissions")
+                .match_query(Matcher::UrlEncoded("page".to_owned(), "1".to_owned()))
+                .with_status(200)
+                .with_body(
+                    json!([{
+                        "resource_server_identifier": "https://api.example.com",
+                        "permission_name": "orders:write",
+                        "resource_server_name": "Orders",
+                        "description": "Create orders"
+                    }])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_role_permissions("rol_0tK3QKvbSvXcXz7w", &ListParams::default().page(1))
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].resource_server_name.as_deref(), Some("Orders"));
+            assert!(resp[0].sources.is_empty());
+        }
+
+        #[tokio::test]
+        async fn add_permissions_to_role() {
+            let m = mock("POST", "/roles/rol_0tK3QKvbSvXcXz7w/permissions")
+                .match_body(Matcher::Json(permissions_body()))
+                .with_status(201)
+                .create();
+            let mut client = new_client();
+
+            client
+                .add_permissions_to_role(
+                    "rol_0tK3QKvbSvXcXz7w",
+                    &[PermissionRef::new(
+                        "https://api.example.com",
+                        "orders:write",
+                    )],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_permissions_from_role() {
+            let m = mock("DELETE", "/roles/rol_0tK3QKvbSvXcXz7w/permissions")
+                .match_body(Matcher::Json(permissions_body()))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .remove_permissions_from_role(
+                    "rol_0tK3QKvbSvXcXz7w",
+                    &[PermissionRef::new(
+                        "https://api.example.com",
+                        "orders:write",
+                    )],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod list_role_users {
+        use super::*;
+
+        #[tokio::test]
+        async fn send_the_checkpoint() {
+            let _m = mock("GET", "/roles/rol_0tK3QKvbSvXcXz7w/users")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("take".to_owned(), "50".to_owned()),
+                    Matcher::UrlEncoded("from".to_owned(), "MjAyMy0wMQ==".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "users": [{
+                            "user_id": "auth0|63bfd5cdbd7f1c642dd83768",
+                            "email": "test@example.com"
+                        }],
+                        "next": "MjAyMy0wMg=="
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_role_users("rol_0tK3QKvbSvXcXz7w", Some("MjAyMy0wMQ=="), None)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.users[0].email.as_deref(), Some("test@example.com"));
+            assert_eq!(resp.next.as_deref(), Some("MjAyMy0wMg=="));
+        }
+
+        #[tokio::test]
+        async fn work_on_the_last_page() {
+            let _m = mock("GET", "/roles/rol_1tK3QKvbSvXcXz7w/users")
+                .match_query(Matcher::UrlEncoded("take".to_owned(), "10".to_owned()))
+                .with_status(200)
+                .with_body(json!({ "users": [] }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_role_users("rol_1tK3QKvbSvXcXz7w", None, Some(10))
+                .await
+                .unwrap();
+
+            assert!(resp.users.is_empty());
+            assert_eq!(resp.next, None);
+        }
+    }
+}
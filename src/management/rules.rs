@@ -0,0 +1,446 @@
+//! Types, traits and functions relative to the rules API.
+//!
+//! Rules are the legacy JavaScript functions run on login, replaced by the actions.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Auth0Result, Error};
+use crate::management::{ListParams, ManagementError, Page};
+use crate::Auth0Client;
+
+/// Trait for operating the rules of the tenant.
+#[async_trait]
+pub trait OperateRules {
+    /// Lists the rules of the tenant through the Auth0 rules API.
+    ///
+    /// # Arguments
+    /// * `params` - The page of the rules to list.
+    /// * `enabled` - Only lists the enabled or the disabled rules, if set.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_rules(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::rules::OperateRules;
+    /// # use auth0_client::management::{ListParams, SortOrder};
+    /// // The rules in their order of execution.
+    /// let params = ListParams::default().sort("order", SortOrder::Ascending);
+    ///
+    /// for rule in client.list_rules(&params, Some(true)).await? {
+    ///     println!("{:?}: {}", rule.order, rule.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_rules(
+        &mut self,
+        params: &ListParams,
+        enabled: Option<bool>,
+    ) -> Auth0Result<Page<Rule>>;
+
+    /// Gets a rule through the Auth0 rules API.
+    ///
+    /// # Arguments
+    /// * `rule_id` - The ID of the rule to get.
+    async fn get_rule(&mut self, rule_id: &str) -> Auth0Result<Rule>;
+
+    /// Creates a rule through the Auth0 rules API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create a rule.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_rule(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::rules::{CreateRulePayload, OperateRules};
+    /// let script = std::fs::read_to_string("rules/add-roles.js").unwrap();
+    /// let mut payload = CreateRulePayload::new("Add roles", &script);
+    /// payload.order = Some(2);
+    ///
+    /// let rule = client.create_rule(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_rule(&mut self, payload: &CreateRulePayload) -> Auth0Result<Rule>;
+
+    /// Updates a rule through the Auth0 rules API.
+    ///
+    /// # Arguments
+    /// * `rule_id` - The ID of the rule to update.
+    /// * `payload` - The properties to update.
+    async fn update_rule(
+        &mut self,
+        rule_id: &str,
+        payload: &UpdateRulePayload,
+    ) -> Auth0Result<Rule>;
+
+    /// Deletes a rule through the Auth0 rules API.
+    ///
+    /// # Arguments
+    /// * `rule_id` - The ID of the rule to delete.
+    async fn delete_rule(&mut self, rule_id: &str) -> Auth0Result<()>;
+
+    /// Lists the keys of the rules configs through the Auth0 rules configs API.
+    ///
+    /// The values are never returned: they are only available to the rules, as `configuration.<key>`.
+    async fn list_rules_configs(&mut self) -> Auth0Result<Vec<String>>;
+
+    /// Sets the value of a rules config through the Auth0 rules configs API.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the config, made of letters, digits and `_-@*+:`.
+    /// * `value` - The value of the config, e.g. a secret used by the rules.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn set_config(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::rules::OperateRules;
+    /// client.set_rules_config("SLACK_WEBHOOK", "https://hooks.slack.com/...").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn set_rules_config(&mut self, key: &str, value: &str) -> Auth0Result<()>;
+
+    /// Deletes a rules config through the Auth0 rules configs API.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the config to delete.
+    async fn delete_rules_config(&mut self, key: &str) -> Auth0Result<()>;
+}
+
+/// A rule of the tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Rule {
+    pub id: String,
+    pub name: String,
+    /// The JavaScript code of the rule.
+    pub script: Option<String>,
+    /// The position of the rule in the order of execution, the lowest first.
+    pub order: Option<i64>,
+    #[serde(default)]
+    pub enabled: bool,
+    /// The stage the rule runs at, always `login_success`.
+    pub stage: Option<String>,
+}
+
+/// The payload for creating a rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreateRulePayload {
+    pub name: String,
+    pub script: String,
+    /// The position of the rule, after every other rule by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl CreateRulePayload {
+    pub fn new(name: &str, script: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            script: script.to_owned(),
+            order: None,
+            enabled: None,
+        }
+    }
+}
+
+/// The payload for updating a rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateRulePayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// A rules config, as returned by Auth0 without its value.
+#[derive(Deserialize)]
+struct RulesConfig {
+    key: String,
+}
+
+/// The payload for setting a rules config.
+#[derive(Serialize)]
+struct SetRulesConfigPayload<'a> {
+    value: &'a str,
+}
+
+#[async_trait]
+impl OperateRules for Auth0Client {
+    async fn list_rules(
+        &mut self,
+        params: &ListParams,
+        enabled: Option<bool>,
+    ) -> Auth0Result<Page<Rule>> {
+        let path = match enabled {
+            Some(enabled) => format!("/rules?enabled={enabled}"),
+            None => "/rules".to_owned(),
+        };
+
+        self.request::<_, _, ManagementError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_rule(&mut self, rule_id: &str) -> Auth0Result<Rule> {
+        self.request::<_, _, ManagementError>(
+            Method::GET,
+            &format!("/rules/{rule_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_rule(&mut self, payload: &CreateRulePayload) -> Auth0Result<Rule> {
+        self.request::<_, _, ManagementError>(Method::POST, "/rules", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_rule(
+        &mut self,
+        rule_id: &str,
+        payload: &UpdateRulePayload,
+    ) -> Auth0Result<Rule> {
+        self.request::<_, _, ManagementError>(
+            Method::PATCH,
+            &format!("/rules/{rule_id}"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_rule(&mut self, rule_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/rules/{rule_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_rules_configs(&mut self) -> Auth0Result<Vec<String>> {
+        let configs: Vec<RulesConfig> = self
+            .request::<_, _, ManagementError>(Method::GET, "/rules-configs", None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        Ok(configs.into_iter().map(|config| config.key).collect())
+    }
+
+    async fn set_rules_config(&mut self, key: &str, value: &str) -> Auth0Result<()> {
+        // The response echoes the value, which is neither read nor logged as it is write-only.
+        self.sensitive_request::<_, IgnoredAny, ManagementError>(
+            Method::PUT,
+            &format!("/rules-configs/{}", urlencoding::encode(key)),
+            Some(SetRulesConfigPayload { value }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn delete_rules_config(&mut self, key: &str) -> Auth0Result<()> {
+        self.request::<_, (), ManagementError>(
+            Method::DELETE,
+            &format!("/rules-configs/{}", urlencoding::encode(key)),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::SortOrder;
+    use mockito::{mock, Matcher};
+    use serde_json::{json, Value};
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn rule() -> Value {
+        json!({
+            "id": "rul_0000000000000001",
+            "name": "Add roles",
+            "script": "function (user, context, callback) { callback(null, user, context); }",
+            "order": 2,
+            "enabled": true,
+            "stage": "login_success"
+        })
+    }
+
+    #[tokio::test]
+    async fn list_rules() {
+        let _m = mock("GET", "/rules")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("enabled".to_owned(), "true".to_owned()),
+                Matcher::UrlEncoded("sort".to_owned(), "order:1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(json!([rule()]).to_string())
+            .create();
+        let mut client = new_client();
+
+        let resp = client
+            .list_rules(
+                &ListParams::default().sort("order", SortOrder::Ascending),
+                Some(true),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp[0].order, Some(2));
+        assert!(resp[0].enabled);
+    }
+
+    #[tokio::test]
+    async fn create_rule() {
+        let _m = mock("POST", "/rules")
+            .match_body(Matcher::Json(json!({
+                "name": "Add roles",
+                "script": "function (user, context, callback) { callback(null, user, context); }",
+                "order": 2
+            })))
+            .with_status(201)
+            .with_body(rule().to_string())
+            .create();
+        let mut client = new_client();
+
+        let mut payload = CreateRulePayload::new(
+            "Add roles",
+            "function (user, context, callback) { callback(null, user, context); }",
+        );
+        payload.order = Some(2);
+        let resp = client.create_rule(&payload).await.unwrap();
+
+        assert_eq!(resp.id, "rul_0000000000000001");
+    }
+
+    #[tokio::test]
+    async fn update_rule() {
+        let m = mock("PATCH", "/rules/rul_0000000000000001")
+            .match_body(Matcher::Json(json!({ "order": 1, "enabled": false })))
+            .with_status(200)
+            .with_body(rule().to_string())
+            .create();
+        let mut client = new_client();
+
+        let payload = UpdateRulePayload {
+            order: Some(1),
+            enabled: Some(false),
+            ..Default::default()
+        };
+        client
+            .update_rule("rul_0000000000000001", &payload)
+            .await
+            .unwrap();
+
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn delete_rule() {
+        let m = mock("DELETE", "/rules/rul_0000000000000001")
+            .with_status(204)
+            .create();
+        let mut client = new_client();
+
+        client.delete_rule("rul_0000000000000001").await.unwrap();
+
+        m.assert();
+    }
+
+    mod rules_configs {
+        use super::*;
+
+        #[tokio::test]
+        async fn list_rules_configs() {
+            let _m = mock("GET", "/rules-configs")
+                .with_status(200)
+                .with_body(json!([{ "key": "SLACK_WEBHOOK" }, { "key": "API_KEY" }]).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.list_rules_configs().await.unwrap();
+
+            assert_eq!(resp, vec!["SLACK_WEBHOOK", "API_KEY"]);
+        }
+
+        #[tokio::test]
+        async fn set_rules_config() {
+            let m = mock("PUT", "/rules-configs/SLACK_WEBHOOK")
+                .match_body(Matcher::Json(json!({ "value": "https://hooks.slack.com" })))
+                .with_status(200)
+                .with_body(
+                    json!({ "key": "SLACK_WEBHOOK", "value": "https://hooks.slack.com" })
+                        .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            client
+                .set_rules_config("SLACK_WEBHOOK", "https://hooks.slack.com")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn delete_rules_config() {
+            let m = mock("DELETE", "/rules-configs/API_KEY")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client.delete_rules_config("API_KEY").await.unwrap();
+
+            m.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rule_with_an_existing_order() {
+        let _m = mock("POST", "/rules")
+            .match_body(Matcher::PartialJson(json!({ "name": "Duplicated order" })))
+            .with_status(409)
+            .with_body(
+                json!({
+                    "statusCode": 409,
+                    "error": "Conflict",
+                    "message": "A rule with the same order already exists"
+                })
+                .to_string(),
+            )
+            .create();
+        let mut client = new_client();
+
+        let mut payload = CreateRulePayload::new("Duplicated order", "function () {}");
+        payload.order = Some(2);
+        let resp = client.create_rule(&payload).await;
+
+        match resp {
+            Err(Error::Management(ManagementError::Conflict(_))) => (),
+            _ => panic!("Expected Conflict variant, got: {resp:?}"),
+        }
+    }
+}
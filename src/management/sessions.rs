@@ -0,0 +1,271 @@
+//! Types, traits and functions relative to the sessions API.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::users::UserError;
+use crate::Auth0Client;
+
+/// Trait for listing and revoking the sessions of users.
+#[async_trait]
+pub trait OperateSessions {
+    /// Lists the sessions of a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to list the sessions of.
+    /// * `from` - The `next` checkpoint of the previous page, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_sessions(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::sessions::OperateSessions;
+    /// let mut page = client
+    ///     .list_user_sessions("auth0|63bfd5cdbd7f1c642dd83768", None)
+    ///     .await?;
+    ///
+    /// while let Some(next) = page.next.take() {
+    ///     page = client
+    ///         .list_user_sessions("auth0|63bfd5cdbd7f1c642dd83768", Some(&next))
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_user_sessions(
+        &mut self,
+        user_id: &str,
+        from: Option<&str>,
+    ) -> Auth0Result<SessionPage>;
+
+    /// Gets a session through the Auth0 sessions API.
+    ///
+    /// # Arguments
+    /// * `session_id` - The ID of the session to get.
+    async fn get_session(&mut self, session_id: &str) -> Auth0Result<Session>;
+
+    /// Revokes a session through the Auth0 sessions API, logging the user out of it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The ID of the session to revoke.
+    async fn revoke_session(&mut self, session_id: &str) -> Auth0Result<()>;
+
+    /// Revokes every session of a user through the Auth0 users API, logging them out everywhere.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to log out.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn log_out(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::sessions::OperateSessions;
+    /// client
+    ///     .revoke_user_sessions("auth0|63bfd5cdbd7f1c642dd83768")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn revoke_user_sessions(&mut self, user_id: &str) -> Auth0Result<()>;
+}
+
+/// A page of the sessions of a user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionPage {
+    pub sessions: Vec<Session>,
+    /// The checkpoint of the next page, if there are more sessions.
+    pub next: Option<String>,
+}
+
+/// A session of a user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Session {
+    pub id: String,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub authenticated_at: Option<DateTime<Utc>>,
+    pub last_interacted_at: Option<DateTime<Utc>>,
+    pub idle_expires_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub device: Option<SessionDevice>,
+    /// The applications the user logged in to during the session.
+    #[serde(default)]
+    pub clients: Vec<SessionClient>,
+}
+
+/// The device of a session, when it started and when it was last used.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionDevice {
+    pub initial_user_agent: Option<String>,
+    pub initial_ip: Option<String>,
+    pub initial_asn: Option<String>,
+    pub last_user_agent: Option<String>,
+    pub last_ip: Option<String>,
+    pub last_asn: Option<String>,
+}
+
+/// An application the user logged in to during a session.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionClient {
+    pub client_id: String,
+}
+
+#[async_trait]
+impl OperateSessions for Auth0Client {
+    async fn list_user_sessions(
+        &mut self,
+        user_id: &str,
+        from: Option<&str>,
+    ) -> Auth0Result<SessionPage> {
+        let mut path = format!("/users/{user_id}/sessions");
+        if let Some(from) = from {
+            path.push_str(&format!("?from={}", urlencoding::encode(from)));
+        }
+
+        self.request::<_, _, UserError>(Method::GET, &path, None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_session(&mut self, session_id: &str) -> Auth0Result<Session> {
+        self.request::<_, _, UserError>(
+            Method::GET,
+            &format!("/sessions/{session_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn revoke_session(&mut self, session_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/sessions/{session_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn revoke_user_sessions(&mut self, user_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/users/{user_id}/sessions"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn session() -> serde_json::Value {
+        json!({
+            "id": "sess_0tK3QKvbSvXcXz7w",
+            "user_id": "auth0|63bfd5cdbd7f1c642dd83768",
+            "created_at": "2023-01-12T09:24:45.761Z",
+            "updated_at": "2023-01-12T10:24:45.761Z",
+            "authenticated_at": "2023-01-12T09:24:45.761Z",
+            "expires_at": null,
+            "device": {
+                "initial_user_agent": "Mozilla/5.0",
+                "initial_ip": "10.0.0.1",
+                "last_ip": "10.0.0.2"
+            },
+            "clients": [{ "client_id": "client_id" }]
+        })
+    }
+
+    mod list_user_sessions {
+        use super::*;
+
+        #[tokio::test]
+        async fn follow_the_checkpoint() {
+            let _m = mock("GET", "/users/auth0|63bfd5cdbd7f1c642dd83768/sessions")
+                .match_query(Matcher::UrlEncoded("from".to_owned(), "a+b".to_owned()))
+                .with_status(200)
+                .with_body(json!({ "sessions": [session()], "next": "c" }).to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_user_sessions("auth0|63bfd5cdbd7f1c642dd83768", Some("a+b"))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                resp.sessions[0].device.as_ref().unwrap().last_ip.as_deref(),
+                Some("10.0.0.2")
+            );
+            assert_eq!(resp.next.as_deref(), Some("c"));
+        }
+    }
+
+    mod get_session {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/sessions/sess_0tK3QKvbSvXcXz7w")
+                .with_status(200)
+                .with_body(session().to_string())
+                .create();
+            let mut client = new_client();
+
+            let resp = client.get_session("sess_0tK3QKvbSvXcXz7w").await.unwrap();
+
+            assert_eq!(resp.clients[0].client_id, "client_id");
+            assert_eq!(resp.expires_at, None);
+        }
+    }
+
+    mod revoke {
+        use super::*;
+
+        #[tokio::test]
+        async fn revoke_a_session() {
+            let m = mock("DELETE", "/sessions/sess_0tK3QKvbSvXcXz7w")
+                .with_status(202)
+                .create();
+            let mut client = new_client();
+
+            client
+                .revoke_session("sess_0tK3QKvbSvXcXz7w")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn revoke_every_session_of_a_user() {
+            let m = mock("DELETE", "/users/auth0|63bfd5cdbd7f1c642dd83768/sessions")
+                .with_status(202)
+                .create();
+            let mut client = new_client();
+
+            client
+                .revoke_user_sessions("auth0|63bfd5cdbd7f1c642dd83768")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Types, traits and functions relative to the user blocks API.
+//!
+//! Users are blocked after too many failed login attempts from the same IP address.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::error::{Auth0Result, Error};
+use crate::management::users::UserError;
+use crate::Auth0Client;
+
+/// Trait for inspecting and removing the brute-force blocks of users.
+#[async_trait]
+pub trait OperateUserBlocks {
+    /// Gets the blocks of the users with the given identifier through the Auth0 user blocks API.
+    ///
+    /// # Arguments
+    /// * `identifier` - The username, phone number or email of the users.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_blocks(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::user_blocks::OperateUserBlocks;
+    /// let blocks = client.get_user_blocks_by_identifier("test@example.com").await?;
+    ///
+    /// for block in blocks {
+    ///     println!("Blocked from {:?}", block.ip);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_user_blocks_by_identifier(
+        &mut self,
+        identifier: &str,
+    ) -> Auth0Result<Vec<UserBlock>>;
+
+    /// Removes the blocks of the users with the given identifier through the Auth0 user blocks API.
+    ///
+    /// # Arguments
+    /// * `identifier` - The username, phone number or email of the users.
+    async fn unblock_by_identifier(&mut self, identifier: &str) -> Auth0Result<()>;
+
+    /// Gets the blocks of a user through the Auth0 user blocks API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user.
+    async fn get_user_blocks(&mut self, user_id: &str) -> Auth0Result<Vec<UserBlock>>;
+
+    /// Removes the blocks of a user through the Auth0 user blocks API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn unblock(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use auth0_client::management::user_blocks::OperateUserBlocks;
+    /// client.unblock_user("auth0|63bfd5cdbd7f1c642dd83768").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn unblock_user(&mut self, user_id: &str) -> Auth0Result<()>;
+}
+
+/// A block of a user after too many failed login attempts.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UserBlock {
+    pub identifier: String,
+    /// The IP address the attempts were made from.
+    pub ip: Option<String>,
+    pub connection: Option<String>,
+}
+
+/// The response of the user blocks API.
+#[derive(Deserialize)]
+struct UserBlocksResponse {
+    blocked_for: Vec<UserBlock>,
+}
+
+#[async_trait]
+impl OperateUserBlocks for Auth0Client {
+    async fn get_user_blocks_by_identifier(
+        &mut self,
+        identifier: &str,
+    ) -> Auth0Result<Vec<UserBlock>> {
+        let res: UserBlocksResponse = self
+            .request::<_, _, UserError>(
+                Method::GET,
+                &format!(
+                    "/user-blocks?identifier={}",
+                    urlencoding::encode(identifier)
+                ),
+                None::<String>,
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        Ok(res.blocked_for)
+    }
+
+    async fn unblock_by_identifier(&mut self, identifier: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!(
+                "/user-blocks?identifier={}",
+                urlencoding::encode(identifier)
+            ),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_user_blocks(&mut self, user_id: &str) -> Auth0Result<Vec<UserBlock>> {
+        let res: UserBlocksResponse = self
+            .request::<_, _, UserError>(
+                Method::GET,
+                &format!("/user-blocks/{user_id}"),
+                None::<String>,
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        Ok(res.blocked_for)
+    }
+
+    async fn unblock_user(&mut self, user_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/user-blocks/{user_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    fn blocks_body() -> String {
+        json!({
+            "blocked_for": [{
+                "identifier": "test@example.com",
+                "ip": "10.0.0.1",
+                "connection": "Username-Password-Authentication"
+            }]
+        })
+        .to_string()
+    }
+
+    mod get_user_blocks {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_by_identifier() {
+            let _m = mock("GET", "/user-blocks")
+                .match_query(Matcher::UrlEncoded(
+                    "identifier".to_owned(),
+                    "test+blocks@example.com".to_owned(),
+                ))
+                .with_status(200)
+                .with_body(blocks_body())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_user_blocks_by_identifier("test+blocks@example.com")
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].ip.as_deref(), Some("10.0.0.1"));
+        }
+
+        #[tokio::test]
+        async fn get_by_user_id() {
+            let _m = mock("GET", "/user-blocks/auth0|63bfd5cdbd7f1c642dd83768")
+                .with_status(200)
+                .with_body(blocks_body())
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_user_blocks("auth0|63bfd5cdbd7f1c642dd83768")
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].identifier, "test@example.com");
+        }
+    }
+
+    mod unblock {
+        use super::*;
+
+        #[tokio::test]
+        async fn unblock_by_identifier() {
+            let m = mock("DELETE", "/user-blocks")
+                .match_query(Matcher::UrlEncoded(
+                    "identifier".to_owned(),
+                    "test@example.com".to_owned(),
+                ))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .unblock_by_identifier("test@example.com")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn unblock_user() {
+            let m = mock("DELETE", "/user-blocks/auth0|63bfd5cdbd7f1c642dd83768")
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .unblock_user("auth0|63bfd5cdbd7f1c642dd83768")
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+}
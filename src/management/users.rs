@@ -0,0 +1,1599 @@
+//! Types, traits and functions relative to the users API.
+//!
+//! The module is also exported as `auth0_client::users`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error as ThisError;
+
+use crate::authorization::Authenticatable;
+use crate::error::{Auth0ApiError, Auth0Result, Error};
+use crate::management::roles::Role;
+use crate::management::users::search::UserQuery;
+use crate::management::{ListParams, Page, Permission, PermissionRef};
+use crate::{Auth0Client, GrantType};
+
+pub mod search;
+
+/// A struct that can interact with the Auth0 users API.
+#[async_trait]
+pub trait OperateUsers {
+    /// Gets a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let user = client.get_user("auth0|63dadcecb564285db4445a75").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_user(&mut self, user_id: &str) -> Auth0Result<UserResponse>;
+
+    /// Lists the users of the tenant through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `params` - The page, sort and fields of the users to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_users(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// # use auth0_client::management::{ListParams, SortOrder};
+    /// let params = ListParams::default().sort("created_at", SortOrder::Descending);
+    ///
+    /// for user in client.list_users(&params).await? {
+    ///     println!("{}", user.user_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_users(&mut self, params: &ListParams) -> Auth0Result<Page<UserResponse>>;
+
+    /// Gets a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `email` - The email of the user to get.
+    /// * `connection` - The connection of the user to get.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let existing = client.get_user_by_email("test@example.com", "Username-Password-Authentication").await?;
+    /// let not_existing = client.get_user_by_email("random@example.com", "Username-Password-Authentication").await?;
+    ///
+    /// assert!(existing.is_some());
+    /// assert!(not_existing.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    async fn get_user_by_email(
+        &mut self,
+        email: &str,
+        connection: &str,
+    ) -> Auth0Result<Option<UserResponse>>;
+
+    /// Searches the users with a Lucene query through the Auth0 users API, using the v3 search engine.
+    ///
+    /// # Arguments
+    /// * `query` - The query the users must match.
+    /// * `params` - The page, sort and fields of the users to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn search_users(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// # use auth0_client::users::search::UserQuery;
+    /// let query = UserQuery::field("app_metadata.plan", "premium")
+    ///     .and(UserQuery::range("created_at", Some("2024-01-01"), None));
+    ///
+    /// let users = client.search_users(&query, &Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn search_users(
+        &mut self,
+        query: &UserQuery,
+        params: &ListParams,
+    ) -> Auth0Result<Page<UserResponse>>;
+
+    /// Gets the users with the given email, across connections, through the Auth0 users-by-email API.
+    ///
+    /// Unlike [`OperateUsers::get_user_by_email`] which searches the users, the email
+    /// is matched exactly, making it the recommended way to look a user up by email.
+    ///
+    /// # Arguments
+    /// * `email` - The email of the users to get, matched case-insensitively.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_users(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let users = client.get_users_by_email("test@example.com").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_users_by_email(&mut self, email: &str) -> Auth0Result<Vec<UserResponse>>;
+
+    /// Creates a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to create a user.
+    ///
+    /// The `connection` field is mandatory, others depends on the connection type.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn create_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let mut payload =
+    ///     auth0_client::users::CreateUserPayload::from_connection("Username-Password-Authentication");
+    /// payload.email = Some("test@example.com".to_owned());
+    /// payload.password = Some("password123456789!".to_owned());
+    ///
+    /// let new_user = client.create_user(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn create_user(&mut self, payload: &CreateUserPayload) -> Auth0Result<UserResponse>;
+
+    /// Updates a user through the Auth0 users API.
+    ///
+    /// The metadata of the payload only overwrite the top-level properties they contain,
+    /// so they can be typed as partial structs. The metadata of the updated user are
+    /// deserialized into the same types.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to update.
+    /// * `payload` - A struct containing the necessary information to update a user.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn update_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let mut payload =
+    ///     auth0_client::users::UpdateUserPayload::from_connection("Username-Password-Authentication");
+    /// payload.password = Some("password123456789!".to_owned());
+    ///
+    /// let resp = client
+    ///     .update_user("auth0|63bfd5cdbd7f2c642dd83768", &payload)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn update_user<UMeta, AMeta>(
+        &mut self,
+        user_id: &str,
+        payload: &UpdateUserPayload<UMeta, AMeta>,
+    ) -> Auth0Result<UserResponse<UMeta, AMeta>>
+    where
+        UMeta: Serialize + DeserializeOwned + Send + Sync,
+        AMeta: Serialize + DeserializeOwned + Send + Sync;
+
+    /// Deletes a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to delete.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn delete_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    ///
+    /// let resp = client
+    ///     .delete_user("auth0|63bfd5cdbd7f2c642dd83768")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn delete_user(&mut self, user_id: &str) -> Auth0Result<()>;
+
+    /// Links a secondary account to a user through the Auth0 users API.
+    ///
+    /// The secondary account can no longer log in on its own, the user logging in with any
+    /// of its identities instead.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the primary account.
+    /// * `payload` - The secondary account to link.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn link(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// # use auth0_client::users::LinkIdentityPayload;
+    /// let payload = LinkIdentityPayload::User {
+    ///     provider: "google-oauth2".to_owned(),
+    ///     user_id: "108091299999329986433".to_owned(),
+    ///     connection_id: None,
+    /// };
+    ///
+    /// let identities = client
+    ///     .link_identity("auth0|63bfd5cdbd7f1c642dd83768", &payload)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn link_identity(
+        &mut self,
+        user_id: &str,
+        payload: &LinkIdentityPayload,
+    ) -> Auth0Result<Vec<Identity>>;
+
+    /// Unlinks an identity from a user through the Auth0 users API, making it a separate user again.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the primary account.
+    /// * `provider` - The provider of the identity to unlink, e.g. `google-oauth2`.
+    /// * `identity_user_id` - The user ID of the identity to unlink, without the provider prefix.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn unlink(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let remaining = client
+    ///     .unlink_identity("auth0|63bfd5cdbd7f1c642dd83768", "google-oauth2", "108091299999329986433")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn unlink_identity(
+        &mut self,
+        user_id: &str,
+        provider: &str,
+        identity_user_id: &str,
+    ) -> Auth0Result<Vec<Identity>>;
+
+    /// Lists the roles assigned to a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to list the roles of.
+    /// * `params` - The page of the roles to list.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn list_user_roles(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// # use auth0_client::management::ListParams;
+    /// let params = ListParams::default().include_totals(true);
+    /// let roles = client
+    ///     .list_user_roles("auth0|63bfd5cdbd7f1c642dd83768", &params)
+    ///     .await?;
+    ///
+    /// println!("{} roles out of {:?}", roles.len(), roles.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn list_user_roles(
+        &mut self,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Role>>;
+
+    /// Lists the permissions assigned to a user, directly or through roles, through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to list the permissions of.
+    /// * `params` - The page of the permissions to list.
+    async fn list_user_permissions(
+        &mut self,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Permission>>;
+
+    /// Assigns roles to a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to assign the roles to.
+    /// * `role_ids` - The IDs of the roles to assign.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn assign_roles(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// client
+    ///     .assign_roles("auth0|63bfd5cdbd7f1c642dd83768", &["rol_0tK3QKvbSvXcXz7w"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn assign_roles(&mut self, user_id: &str, role_ids: &[&str]) -> Auth0Result<()>;
+
+    /// Removes roles from a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to remove the roles from.
+    /// * `role_ids` - The IDs of the roles to remove.
+    async fn remove_roles(&mut self, user_id: &str, role_ids: &[&str]) -> Auth0Result<()>;
+
+    /// Assigns permissions directly to a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to assign the permissions to.
+    /// * `permissions` - The permissions to assign.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn assign_permissions(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// # use auth0_client::management::PermissionRef;
+    /// let permissions = [PermissionRef::new("https://api.example.com", "orders:write")];
+    ///
+    /// client
+    ///     .assign_permissions("auth0|63bfd5cdbd7f1c642dd83768", &permissions)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn assign_permissions(
+        &mut self,
+        user_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()>;
+
+    /// Removes permissions directly assigned to a user through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user ID of the user to remove the permissions from.
+    /// * `permissions` - The permissions to remove.
+    async fn remove_permissions(
+        &mut self,
+        user_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()>;
+
+    /// Check a user's password through the Auth0 users API.
+    ///
+    /// # Arguments
+    /// * `payload` - A struct containing the necessary information to check the user's passord.
+    ///
+    /// The `connection` field is mandatory, others depends on the connection type.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn check_password(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// let mut payload =
+    ///     auth0_client::users::CheckPasswordPayload::new();
+    /// payload.username = "test@example.com".to_owned();
+    /// payload.password = "password123456789!".to_owned();
+    ///
+    /// client.check_password(&payload).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn check_password(&mut self, payload: &CheckPasswordPayload) -> Auth0Result<()>;
+}
+
+/// A struct containing the payload for creating a user.
+#[derive(Serialize)]
+pub struct CreateUserPayload {
+    pub connection: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+/// A struct containing the payload for updating a user.
+///
+/// `UMeta` and `AMeta` are the types of the `user_metadata` and `app_metadata` to update.
+#[derive(Serialize)]
+pub struct UpdateUserPayload<UMeta = Value, AMeta = Value> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_metadata: Option<UMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_metadata: Option<AMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+/// The secondary account to link to a user.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum LinkIdentityPayload {
+    /// The account of an ID token, proving the user authenticated with it.
+    IdToken { link_with: String },
+    /// The account with the given provider and user ID.
+    User {
+        provider: String,
+        /// The user ID of the account, without the provider prefix.
+        user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        connection_id: Option<String>,
+    },
+}
+
+/// The payload for assigning roles to, or removing roles from, a user.
+#[derive(Serialize)]
+struct UserRolesPayload<'a> {
+    roles: &'a [&'a str],
+}
+
+/// The payload for assigning permissions to, or removing permissions from, a user.
+#[derive(Serialize)]
+struct UserPermissionsPayload<'a> {
+    permissions: &'a [PermissionRef],
+}
+
+/// A struct containing the payload for checking a user's password.
+#[derive(Default, Serialize)]
+pub struct CheckPasswordPayload {
+    pub username: String,
+    pub password: String,
+}
+
+/// A struct containing the response from the Auth0 users API.
+///
+/// `UMeta` and `AMeta` are the types of the `user_metadata` and `app_metadata` of the user.
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserResponse<UMeta = Value, AMeta = Value> {
+    pub user_id: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: String,
+    pub nickname: String,
+    pub picture: String,
+    pub identities: Vec<Identity>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub user_metadata: Option<UMeta>,
+    pub app_metadata: Option<AMeta>,
+}
+
+impl UserResponse {
+    /// Deserializes the metadata of the user into their types.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn get_user(mut client: auth0_client::Auth0Client) -> auth0_client::error::Auth0Result<()> {
+    /// # use crate::auth0_client::users::OperateUsers;
+    /// #[derive(serde::Deserialize)]
+    /// struct Preferences {
+    ///     theme: String,
+    /// }
+    ///
+    /// let user = client
+    ///     .get_user("auth0|63bfd5cdbd7f1c642dd83768")
+    ///     .await?
+    ///     .with_metadata::<Preferences, serde_json::Value>()?;
+    ///
+    /// if let Some(preferences) = user.user_metadata {
+    ///     println!("Theme: {}", preferences.theme);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_metadata<UMeta, AMeta>(self) -> Auth0Result<UserResponse<UMeta, AMeta>>
+    where
+        UMeta: DeserializeOwned,
+        AMeta: DeserializeOwned,
+    {
+        Ok(UserResponse {
+            user_id: self.user_id,
+            email: self.email,
+            email_verified: self.email_verified,
+            name: self.name,
+            nickname: self.nickname,
+            picture: self.picture,
+            identities: self.identities,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            user_metadata: self.user_metadata.map(serde_json::from_value).transpose()?,
+            app_metadata: self.app_metadata.map(serde_json::from_value).transpose()?,
+        })
+    }
+}
+
+/// A struct containing an identity of a user.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Identity {
+    pub connection: String,
+    pub user_id: String,
+    pub provider: String,
+    #[serde(rename = "isSocial")]
+    pub is_social: bool,
+}
+
+#[async_trait]
+impl OperateUsers for Auth0Client {
+    async fn get_user(&mut self, user_id: &str) -> Auth0Result<UserResponse> {
+        self.request::<_, _, UserError>(Method::GET, &format!("/users/{user_id}"), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_users(&mut self, params: &ListParams) -> Auth0Result<Page<UserResponse>> {
+        self.request::<_, _, UserError>(Method::GET, &params.apply("/users"), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_user_by_email(
+        &mut self,
+        email: &str,
+        connection: &str,
+    ) -> Auth0Result<Option<UserResponse>> {
+        let res: Vec<UserResponse> = self
+            .request::<_, _, UserError>(
+                Method::GET,
+                &format!(
+                    "/users?connection={connection}&q=email%3A{}&search_engine=v3",
+                    urlencoding::encode(email)
+                ),
+                None::<String>,
+            )
+            .await?
+            .ok_or(Error::InvalidResponseBody)?;
+
+        let user = res
+            .iter()
+            .find(|u| u.identities.iter().any(|i| i.connection == connection))
+            .cloned();
+
+        Ok(user)
+    }
+
+    async fn search_users(
+        &mut self,
+        query: &UserQuery,
+        params: &ListParams,
+    ) -> Auth0Result<Page<UserResponse>> {
+        let path = format!(
+            "/users?q={}&search_engine=v3",
+            urlencoding::encode(&query.to_string())
+        );
+
+        self.request::<_, _, UserError>(Method::GET, &params.apply(&path), None::<String>)
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn get_users_by_email(&mut self, email: &str) -> Auth0Result<Vec<UserResponse>> {
+        self.request::<_, _, UserError>(
+            Method::GET,
+            &format!("/users-by-email?email={}", urlencoding::encode(email)),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn create_user(&mut self, payload: &CreateUserPayload) -> Auth0Result<UserResponse> {
+        self.request::<_, _, UserError>(Method::POST, "/users", Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn update_user<UMeta, AMeta>(
+        &mut self,
+        user_id: &str,
+        payload: &UpdateUserPayload<UMeta, AMeta>,
+    ) -> Auth0Result<UserResponse<UMeta, AMeta>>
+    where
+        UMeta: Serialize + DeserializeOwned + Send + Sync,
+        AMeta: Serialize + DeserializeOwned + Send + Sync,
+    {
+        self.request::<_, _, UserError>(Method::PATCH, &format!("/users/{user_id}"), Some(payload))
+            .await?
+            .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn delete_user(&mut self, user_id: &str) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/users/{user_id}"),
+            None::<String>,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn link_identity(
+        &mut self,
+        user_id: &str,
+        payload: &LinkIdentityPayload,
+    ) -> Auth0Result<Vec<Identity>> {
+        self.request::<_, _, UserError>(
+            Method::POST,
+            &format!("/users/{user_id}/identities"),
+            Some(payload),
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn unlink_identity(
+        &mut self,
+        user_id: &str,
+        provider: &str,
+        identity_user_id: &str,
+    ) -> Auth0Result<Vec<Identity>> {
+        self.request::<_, _, UserError>(
+            Method::DELETE,
+            &format!("/users/{user_id}/identities/{provider}/{identity_user_id}"),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_user_roles(
+        &mut self,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Role>> {
+        self.request::<_, _, UserError>(
+            Method::GET,
+            &params.apply(&format!("/users/{user_id}/roles")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn list_user_permissions(
+        &mut self,
+        user_id: &str,
+        params: &ListParams,
+    ) -> Auth0Result<Page<Permission>> {
+        self.request::<_, _, UserError>(
+            Method::GET,
+            &params.apply(&format!("/users/{user_id}/permissions")),
+            None::<String>,
+        )
+        .await?
+        .ok_or(Error::InvalidResponseBody)
+    }
+
+    async fn assign_roles(&mut self, user_id: &str, role_ids: &[&str]) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::POST,
+            &format!("/users/{user_id}/roles"),
+            Some(UserRolesPayload { roles: role_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_roles(&mut self, user_id: &str, role_ids: &[&str]) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/users/{user_id}/roles"),
+            Some(UserRolesPayload { roles: role_ids }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn assign_permissions(
+        &mut self,
+        user_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::POST,
+            &format!("/users/{user_id}/permissions"),
+            Some(UserPermissionsPayload { permissions }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_permissions(
+        &mut self,
+        user_id: &str,
+        permissions: &[PermissionRef],
+    ) -> Auth0Result<()> {
+        self.request::<_, (), UserError>(
+            Method::DELETE,
+            &format!("/users/{user_id}/permissions"),
+            Some(UserPermissionsPayload { permissions }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_password(&mut self, payload: &CheckPasswordPayload) -> Auth0Result<()> {
+        self.grant_type(GrantType::Password);
+
+        self.authenticate_user(payload.username.clone(), payload.password.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// An error representing the possible errors that can occur when interacting with the Auth0 users API.
+#[derive(Debug, ThisError)]
+pub enum UserError {
+    #[error("Invalid request body: {0}")]
+    InvalidRequestBody(String),
+    #[error("User already exists")]
+    UserAlreadyExists,
+    #[error("Connection not found")]
+    ConnectionNotFound,
+    #[error("Unknown user error: {0}")]
+    Unknown(String),
+}
+
+impl From<Auth0ApiError> for UserError {
+    fn from(api_error: Auth0ApiError) -> Self {
+        match api_error.error_code.as_deref() {
+            Some("invalid_body") => Self::InvalidRequestBody(api_error.message),
+            Some("auth0_idp_error") => Self::UserAlreadyExists,
+            Some("inexistent_connection") => Self::ConnectionNotFound,
+            _ => Self::Unknown(api_error.message),
+        }
+    }
+}
+
+impl UpdateUserPayload {
+    /// Returns an empty payload for user creation with only `connection` field set.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - The connection type for the user we want to create.
+    pub fn from_connection(connection: &str) -> Self {
+        Self {
+            connection: Some(connection.to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+impl<UMeta, AMeta> Default for UpdateUserPayload<UMeta, AMeta> {
+    fn default() -> Self {
+        Self {
+            connection: None,
+            email: None,
+            phone_number: None,
+            user_metadata: None,
+            blocked: None,
+            email_verified: None,
+            phone_verified: None,
+            app_metadata: None,
+            given_name: None,
+            family_name: None,
+            name: None,
+            nickname: None,
+            picture: None,
+            password: None,
+            username: None,
+            verify_email: None,
+            verify_phone_number: None,
+            client_id: None,
+        }
+    }
+}
+
+impl CreateUserPayload {
+    /// Returns an empty payload for user update with only `connection` field set.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - The connection type for the user we want to update.
+    pub fn from_connection(connection: &str) -> Self {
+        Self {
+            connection: connection.to_owned(),
+            email: None,
+            phone_number: None,
+            user_metadata: None,
+            blocked: None,
+            email_verified: None,
+            phone_verified: None,
+            app_metadata: None,
+            given_name: None,
+            family_name: None,
+            name: None,
+            nickname: None,
+            picture: None,
+            user_id: None,
+            password: None,
+            username: None,
+        }
+    }
+}
+
+impl CheckPasswordPayload {
+    /// Returns an empty payload for user password checking.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Mock};
+    use serde_json::json;
+
+    fn new_client() -> Auth0Client {
+        Auth0Client::new(
+            "client_id",
+            "client_secret",
+            &mockito::server_url(),
+            &mockito::server_url(),
+        )
+    }
+
+    mod get_user {
+        use super::*;
+
+        fn get_user_mock() -> Mock {
+            mock("GET", "/users/1234")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test@example.com",
+                        "email_verified": false,
+                        "identities": [
+                          {
+                            "connection": "Username-Password-Authentication",
+                            "user_id": "63bfd5cdbd7f1c642dd83768",
+                            "provider": "auth0",
+                            "isSocial": false
+                          }
+                        ],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c?s=480&r=pg&d=https%3A%2F%2Fcdn.auth0.com%2Favatars%2Fth.png",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd5cdbd7f1c642dd83768"
+                      }).to_string(),
+                )
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = get_user_mock();
+            let mut client = new_client();
+
+            let resp = client.get_user("1234").await.unwrap();
+
+            assert_eq!(resp.email, Some("test@example.com".to_owned()));
+        }
+    }
+
+    mod list_users {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/users")
+                .with_status(200)
+                .with_body(
+                    json!([{
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test@example.com",
+                        "email_verified": false,
+                        "identities": [],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd5cdbd7f1c642dd83768"
+                    }])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client.list_users(&Default::default()).await.unwrap();
+
+            assert_eq!(resp.len(), 1);
+            assert_eq!(resp[0].user_id, "auth0|63bfd5cdbd7f1c642dd83768");
+        }
+    }
+
+    mod get_user_by_email {
+        use super::*;
+
+        fn get_user_by_email_mock() -> Mock {
+            mock("GET", format!("/users?connection=Username-Password-Authentication&q=email%3A{}&search_engine=v3", urlencoding::encode("test@example.com")).as_str())
+                .with_status(200)
+                .with_body(
+                    json!([{
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test@example.com",
+                        "email_verified": false,
+                        "identities": [
+                          {
+                            "connection": "Username-Password-Authentication",
+                            "user_id": "63bfd5cdbd7f1c642dd83768",
+                            "provider": "auth0",
+                            "isSocial": false
+                          }
+                        ],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c?s=480&r=pg&d=https%3A%2F%2Fcdn.auth0.com%2Favatars%2Fth.png",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd5cdbd7f1c642dd83768"
+                      }]).to_string(),
+                )
+                .create()
+        }
+
+        fn get_user_by_email_not_found_mock() -> Mock {
+            mock("GET", format!("/users?connection=Username-Password-Authentication&q=email%3A{}&search_engine=v3", urlencoding::encode("test@example.com")).as_str())
+                .with_status(200)
+                .with_body(json!([]).to_string())
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = get_user_by_email_mock();
+            let mut client = new_client();
+
+            let resp = client
+                .get_user_by_email("test@example.com", "Username-Password-Authentication")
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(resp.email, Some("test@example.com".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn works_with_not_found() {
+            let _m = get_user_by_email_not_found_mock();
+            let mut client = new_client();
+
+            let resp = client
+                .get_user_by_email("test@example.com", "Username-Password-Authentication")
+                .await
+                .unwrap();
+
+            assert!(resp.is_none());
+        }
+    }
+
+    mod search_users {
+        use super::*;
+        use mockito::Matcher;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/users")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded(
+                        "q".to_owned(),
+                        r#"app_metadata.plan:"premium" AND logins_count:["10" TO *]"#.to_owned(),
+                    ),
+                    Matcher::UrlEncoded("search_engine".to_owned(), "v3".to_owned()),
+                    Matcher::UrlEncoded("per_page".to_owned(), "10".to_owned()),
+                ]))
+                .with_status(200)
+                .with_body(json!([]).to_string())
+                .create();
+            let mut client = new_client();
+            let query = UserQuery::field("app_metadata.plan", "premium").and(UserQuery::range(
+                "logins_count",
+                Some("10"),
+                None,
+            ));
+
+            let params = ListParams::default().per_page(10);
+
+            let resp = client.search_users(&query, &params).await.unwrap();
+
+            assert!(resp.is_empty());
+        }
+    }
+
+    mod get_users_by_email {
+        use super::*;
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = mock("GET", "/users-by-email?email=test%2Bauth0%40example.com")
+                .with_status(200)
+                .with_body(
+                    json!([{
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test+auth0@example.com",
+                        "email_verified": true,
+                        "identities": [],
+                        "name": "test+auth0@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "google-oauth2|108091299999329986433"
+                    }])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .get_users_by_email("test+auth0@example.com")
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].user_id, "google-oauth2|108091299999329986433");
+        }
+    }
+
+    mod create_user {
+        use super::*;
+
+        fn create_user_mock() -> Mock {
+            mock("POST", "/users")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test@example.com",
+                        "email_verified": false,
+                        "identities": [
+                          {
+                            "connection": "Username-Password-Authentication",
+                            "user_id": "63bfd5cdbd7f1c642dd83768",
+                            "provider": "auth0",
+                            "isSocial": false
+                          }
+                        ],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c?s=480&r=pg&d=https%3A%2F%2Fcdn.auth0.com%2Favatars%2Fth.png",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd5cdbd7f1c642dd83768"
+                      }).to_string(),
+                )
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = create_user_mock();
+            let mut client = new_client();
+
+            let mut payload =
+                CreateUserPayload::from_connection("Username-Password-Authentication");
+            payload.email = Some("test@example.com".to_owned());
+            payload.password = Some("password123456789!".to_owned());
+
+            let resp = client.create_user(&payload).await.unwrap();
+
+            assert_eq!(resp.email, Some("test@example.com".to_owned()));
+        }
+    }
+
+    mod update_user {
+        use super::*;
+        use mockito::Matcher;
+
+        fn update_user_mock() -> Mock {
+            mock("PATCH", "/users/auth0|63bfd5cdbd7f1c642dd83768")
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email": "test@example.com",
+                        "email_verified": false,
+                        "identities": [
+                          {
+                            "connection": "Username-Password-Authentication",
+                            "user_id": "63bfd1ddbd7f1c635dd83768",
+                            "provider": "auth0",
+                            "isSocial": false
+                          }
+                        ],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c?s=480&r=pg&d=https%3A%2F%2Fcdn.auth0.com%2Favatars%2Fth.png",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd1ddbd7f1c635dd83768"
+                      }).to_string(),
+                )
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = update_user_mock();
+            let mut client = new_client();
+
+            let mut payload =
+                UpdateUserPayload::from_connection("Username-Password-Authentication");
+            payload.password = Some("password123456789!".to_owned());
+
+            let resp = client
+                .update_user("auth0|63bfd5cdbd7f1c642dd83768", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.email, Some("test@example.com".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn update_typed_metadata() {
+            #[derive(Debug, Serialize, Deserialize, PartialEq)]
+            struct Preferences {
+                #[serde(skip_serializing_if = "Option::is_none")]
+                theme: Option<String>,
+            }
+
+            let _m = mock("PATCH", "/users/auth0|63bfd5cdbd7f1c642dd83769")
+                .match_body(Matcher::Json(json!({
+                    "user_metadata": { "theme": "dark" }
+                })))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "created_at": "2023-01-12T09:24:45.761Z",
+                        "email_verified": false,
+                        "identities": [],
+                        "name": "test@example.com",
+                        "nickname": "test",
+                        "picture": "https://s.gravatar.com/avatar/108cfa0160355a6aef1acdaa4493755c",
+                        "updated_at": "2023-01-12T09:24:45.761Z",
+                        "user_id": "auth0|63bfd5cdbd7f1c642dd83769",
+                        "user_metadata": { "theme": "dark", "language": "fr" },
+                        "app_metadata": { "plan": "pro" }
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let payload = UpdateUserPayload::<_, Value> {
+                user_metadata: Some(Preferences {
+                    theme: Some("dark".to_owned()),
+                }),
+                ..Default::default()
+            };
+            let resp = client
+                .update_user("auth0|63bfd5cdbd7f1c642dd83769", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.user_metadata, payload.user_metadata);
+            assert_eq!(resp.app_metadata.unwrap()["plan"], "pro");
+        }
+    }
+
+    mod delete_user {
+        use super::*;
+
+        fn delete_user_mock() -> Mock {
+            mock("DELETE", "/users/auth0|63bfd5cdbd7f1c642dd83768")
+                .with_status(204)
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = delete_user_mock();
+            let mut client = new_client();
+
+            client
+                .delete_user("auth0|63bfd5cdbd7f1c642dd83768")
+                .await
+                .unwrap();
+        }
+    }
+
+    mod identities {
+        use super::*;
+        use mockito::Matcher;
+
+        fn identities() -> serde_json::Value {
+            json!([
+                {
+                    "connection": "Username-Password-Authentication",
+                    "user_id": "63bfd5cdbd7f1c642dd83768",
+                    "provider": "auth0",
+                    "isSocial": false
+                },
+                {
+                    "connection": "google-oauth2",
+                    "user_id": "108091299999329986433",
+                    "provider": "google-oauth2",
+                    "isSocial": true
+                }
+            ])
+        }
+
+        #[tokio::test]
+        async fn link_an_identity() {
+            let _m = mock("POST", "/users/auth0|63bfd5cdbd7f1c642dd83768/identities")
+                .match_body(Matcher::Json(json!({ "link_with": "id_token" })))
+                .with_status(201)
+                .with_body(identities().to_string())
+                .create();
+            let mut client = new_client();
+            let payload = LinkIdentityPayload::IdToken {
+                link_with: "id_token".to_owned(),
+            };
+
+            let resp = client
+                .link_identity("auth0|63bfd5cdbd7f1c642dd83768", &payload)
+                .await
+                .unwrap();
+
+            assert_eq!(resp.len(), 2);
+            assert!(resp[1].is_social);
+        }
+
+        #[tokio::test]
+        async fn unlink_an_identity() {
+            let _m = mock(
+                "DELETE",
+                "/users/auth0|63bfd5cdbd7f1c642dd83768/identities/google-oauth2/108091299999329986433",
+            )
+            .with_status(200)
+            .with_body(json!([identities()[0]]).to_string())
+            .create();
+            let mut client = new_client();
+
+            let resp = client
+                .unlink_identity(
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    "google-oauth2",
+                    "108091299999329986433",
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(resp.len(), 1);
+        }
+    }
+
+    mod roles {
+        use super::*;
+        use mockito::Matcher;
+
+        #[tokio::test]
+        async fn list_user_roles() {
+            let _m = mock("GET", "/users/auth0|63bfd5cdbd7f1c642dd83768/roles")
+                .match_query(Matcher::UrlEncoded(
+                    "include_totals".to_owned(),
+                    "true".to_owned(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({
+                        "start": 0,
+                        "limit": 50,
+                        "total": 1,
+                        "roles": [{
+                            "id": "rol_0tK3QKvbSvXcXz7w",
+                            "name": "admin",
+                            "description": "Administrators"
+                        }]
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_user_roles(
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &ListParams::default().include_totals(true),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(resp.total, Some(1));
+            assert_eq!(resp[0].name, "admin");
+        }
+
+        #[tokio::test]
+        async fn assign_roles() {
+            let m = mock("POST", "/users/auth0|63bfd5cdbd7f1c642dd83768/roles")
+                .match_body(Matcher::Json(json!({ "roles": ["rol_1", "rol_2"] })))
+                .with_status(200)
+                .create();
+            let mut client = new_client();
+
+            client
+                .assign_roles("auth0|63bfd5cdbd7f1c642dd83768", &["rol_1", "rol_2"])
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_roles() {
+            let m = mock("DELETE", "/users/auth0|63bfd5cdbd7f1c642dd83768/roles")
+                .match_body(Matcher::Json(json!({ "roles": ["rol_1"] })))
+                .with_status(204)
+                .create();
+            let mut client = new_client();
+
+            client
+                .remove_roles("auth0|63bfd5cdbd7f1c642dd83768", &["rol_1"])
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod permissions {
+        use super::*;
+        use mockito::Matcher;
+
+        fn body() -> serde_json::Value {
+            json!({
+                "permissions": [{
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "orders:write"
+                }]
+            })
+        }
+
+        #[tokio::test]
+        async fn list_user_permissions() {
+            let _m = mock("GET", "/users/auth0|63bfd5cdbd7f1c642dd83768/permissions")
+                .with_status(200)
+                .with_body(
+                    json!([{
+                        "resource_server_identifier": "https://api.example.com",
+                        "permission_name": "orders:write",
+                        "resource_server_name": "Orders",
+                        "description": "Create orders",
+                        "sources": [{
+                            "source_id": "rol_0tK3QKvbSvXcXz7w",
+                            "source_name": "admin",
+                            "source_type": "ROLE"
+                        }]
+                    }])
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let resp = client
+                .list_user_permissions("auth0|63bfd5cdbd7f1c642dd83768", &Default::default())
+                .await
+                .unwrap();
+
+            assert_eq!(resp[0].permission_name, "orders:write");
+            assert_eq!(resp[0].sources[0].source_type, "ROLE");
+        }
+
+        #[tokio::test]
+        async fn assign_permissions() {
+            let m = mock("POST", "/users/auth0|63bfd5cdbd7f1c642dd83768/permissions")
+                .match_body(Matcher::Json(body()))
+                .with_status(201)
+                .create();
+            let mut client = new_client();
+
+            client
+                .assign_permissions(
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &[PermissionRef::new(
+                        "https://api.example.com",
+                        "orders:write",
+                    )],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+
+        #[tokio::test]
+        async fn remove_permissions() {
+            let m = mock(
+                "DELETE",
+                "/users/auth0|63bfd5cdbd7f1c642dd83768/permissions",
+            )
+            .match_body(Matcher::Json(body()))
+            .with_status(204)
+            .create();
+            let mut client = new_client();
+
+            client
+                .remove_permissions(
+                    "auth0|63bfd5cdbd7f1c642dd83768",
+                    &[PermissionRef::new(
+                        "https://api.example.com",
+                        "orders:write",
+                    )],
+                )
+                .await
+                .unwrap();
+
+            m.assert();
+        }
+    }
+
+    mod check_password {
+        use super::*;
+
+        fn check_password_mock() -> Mock {
+            mock("POST", "/oauth/token")
+                .with_status(200)
+                .with_body(
+                    json!({
+                      "access_token": "ACCESS_TOKEN",
+                      "refresh_token": "REFRESH_TOKEN",
+                      "id_token": "ID_TOKEN",
+                      "token_type": "TOKEN_TYPE",
+                      "expires_in": 3600
+                    })
+                    .to_string(),
+                )
+                .create()
+        }
+
+        #[tokio::test]
+        async fn works_with_sample_response() {
+            let _m = check_password_mock();
+            let mut client = new_client();
+
+            let mut payload = CheckPasswordPayload::new();
+            payload.username = "test@example.com".to_owned();
+            payload.password = "password123456789!".to_owned();
+
+            client.check_password(&payload).await.unwrap();
+        }
+    }
+
+    mod errors {
+        use super::*;
+
+        use crate::error::Error;
+
+        #[tokio::test]
+        async fn error_mapping_in_request() {
+            let _m = mock("POST", "/users")
+                .with_status(400)
+                .with_body(
+                    json!({
+                      "statusCode": 400,
+                      "error": "Bad Request",
+                      "message": "The \"connection\" field is required.",
+                      "errorCode": "invalid_body"
+                    })
+                    .to_string(),
+                )
+                .create();
+            let mut client = new_client();
+
+            let mut payload =
+                CreateUserPayload::from_connection("Username-Password-Authentication");
+            payload.email = Some("test@example.com".to_owned());
+            payload.password = Some("password123456789!".to_owned());
+
+            let resp = client.create_user(&payload).await;
+
+            match resp {
+                Err(Error::User(UserError::InvalidRequestBody(msg))) => {
+                    assert_eq!(msg, "The \"connection\" field is required.")
+                }
+                _ => panic!("Invalid error"),
+            }
+        }
+
+        #[test]
+        fn from_auth0_api_error_to_user_error() {
+            // Invalid body
+            let auth0_error = Auth0ApiError {
+                status_code: 400,
+                error: "Bad Request".to_owned(),
+                message: "\"password\" is required".to_owned(),
+                error_code: Some("invalid_body".to_owned()),
+            };
+
+            match UserError::from(auth0_error) {
+                UserError::InvalidRequestBody(msg) => assert_eq!(msg, "\"password\" is required"),
+                _ => panic!("Invalid variant for error"),
+            }
+
+            // User already exists
+            let auth0_error = Auth0ApiError {
+                status_code: 400,
+                error: "Conflict".to_owned(),
+                message: "The user already exists.".to_owned(),
+                error_code: Some("auth0_idp_error".to_owned()),
+            };
+
+            match UserError::from(auth0_error) {
+                UserError::UserAlreadyExists => (),
+                _ => panic!("Invalid variant for error"),
+            }
+
+            // Invalid connection
+            let auth0_error = Auth0ApiError {
+                status_code: 400,
+                error: "Bad Request".to_owned(),
+                message: "The connection does not exist.".to_owned(),
+                error_code: Some("inexistent_connection".to_owned()),
+            };
+
+            match UserError::from(auth0_error) {
+                UserError::ConnectionNotFound => (),
+                _ => panic!("Invalid variant for error"),
+            }
+
+            // User already exists
+            let auth0_error = Auth0ApiError {
+                status_code: 400,
+                error: "Bad Request".to_owned(),
+                message: "Unknown error.".to_owned(),
+                error_code: None,
+            };
+
+            match UserError::from(auth0_error) {
+                UserError::Unknown(msg) => assert_eq!(msg, "Unknown error."),
+                _ => panic!("Invalid variant for error"),
+            }
+        }
+    }
+}
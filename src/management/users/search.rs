@@ -0,0 +1,159 @@
+//! Types relative to the Lucene queries of the user search.
+
+use std::fmt::Display;
+use std::ops::Not;
+
+/// The characters with a meaning in the Lucene query syntax.
+const SPECIAL_CHARACTERS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':', '\\', '/',
+    ' ',
+];
+
+/// A query of the user search, with its values escaped.
+///
+/// # Example
+///
+/// ```
+/// # use auth0_client::users::search::UserQuery;
+/// let query = UserQuery::field("email", "jane@example.com")
+///     .or(UserQuery::prefix("name", "Jane D"))
+///     .and(UserQuery::range("logins_count", Some("10"), None));
+///
+/// assert_eq!(
+///     query.to_string(),
+///     r#"(email:"jane@example.com" OR name:Jane\ D*) AND logins_count:["10" TO *]"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserQuery {
+    query: String,
+    /// Whether the query combines others and must be parenthesized when combined in turn.
+    compound: bool,
+}
+
+impl UserQuery {
+    /// Matches the users whose field is the exact value, e.g. `email:"jane@example.com"`.
+    ///
+    /// Nested fields use dots, e.g. `app_metadata.plan` or `identities.connection`.
+    pub fn field(field: &str, value: &str) -> Self {
+        Self::term(format!("{field}:{}", quote(value)))
+    }
+
+    /// Matches the users whose field starts with the value, e.g. `name:jane*`.
+    pub fn prefix(field: &str, value: &str) -> Self {
+        Self::term(format!("{field}:{}*", escape(value)))
+    }
+
+    /// Matches the users whose field is within the inclusive range, unbounded on `None` sides.
+    pub fn range(field: &str, from: Option<&str>, to: Option<&str>) -> Self {
+        let bound = |value: Option<&str>| value.map(quote).unwrap_or_else(|| "*".to_owned());
+
+        Self::term(format!("{field}:[{} TO {}]", bound(from), bound(to)))
+    }
+
+    /// Matches the users having the field, e.g. `_exists_:app_metadata.plan`.
+    pub fn exists(field: &str) -> Self {
+        Self::term(format!("_exists_:{field}"))
+    }
+
+    /// Matches the users matching both queries.
+    pub fn and(self, other: UserQuery) -> Self {
+        self.combine("AND", other)
+    }
+
+    /// Matches the users matching either query.
+    pub fn or(self, other: UserQuery) -> Self {
+        self.combine("OR", other)
+    }
+
+    fn term(query: String) -> Self {
+        Self {
+            query,
+            compound: false,
+        }
+    }
+
+    fn combine(self, operator: &str, other: UserQuery) -> Self {
+        Self {
+            query: format!("{} {operator} {}", self.operand(), other.operand()),
+            compound: true,
+        }
+    }
+
+    fn operand(&self) -> String {
+        if self.compound {
+            format!("({})", self.query)
+        } else {
+            self.query.clone()
+        }
+    }
+}
+
+impl Not for UserQuery {
+    type Output = UserQuery;
+
+    /// Matches the users not matching the query.
+    fn not(self) -> Self::Output {
+        Self::term(format!("NOT {}", self.operand()))
+    }
+}
+
+impl Display for UserQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.query)
+    }
+}
+
+/// Quotes a value as a Lucene phrase.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Escapes the special characters of an unquoted Lucene term.
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .fold(String::with_capacity(value.len()), |mut escaped, c| {
+            if SPECIAL_CHARACTERS.contains(&c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+            escaped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod user_query {
+        use super::*;
+
+        #[test]
+        fn escape_values() {
+            assert_eq!(
+                UserQuery::field("name", r#"Jane "JD" D\oe"#).to_string(),
+                r#"name:"Jane \"JD\" D\\oe""#
+            );
+            assert_eq!(
+                UserQuery::prefix("email", "jane+test@").to_string(),
+                r"email:jane\+test@*"
+            );
+        }
+
+        #[test]
+        fn combine_queries() {
+            let query = UserQuery::exists("app_metadata.plan")
+                .and(
+                    UserQuery::field("identities.connection", "google-oauth2")
+                        .or(UserQuery::field("identities.connection", "github")),
+                )
+                .and(!UserQuery::field("blocked", "true"));
+
+            assert_eq!(
+                query.to_string(),
+                r#"(_exists_:app_metadata.plan AND (identities.connection:"google-oauth2" OR identities.connection:"github")) AND NOT blocked:"true""#
+            );
+        }
+    }
+}
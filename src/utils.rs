@@ -1,7 +1,49 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use lazy_static::lazy_static;
 use regex::Regex;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::error::{Auth0Result, Error};
 
 lazy_static! {
     /// Regex to remove duplicate slashes from URLs
     pub static ref URL_REGEX: Regex = Regex::new(r"([^:]/)/+").expect("URL Regex error");
 }
+
+/// Returns `len` random bytes encoded in base64url, e.g. to use as a `jti` or a `state`.
+pub(crate) fn random_string(len: usize) -> Auth0Result<String> {
+    let mut bytes = vec![0u8; len];
+
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| Error::Unknown("Could not generate random bytes".to_owned()))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Fails with [`Error::ReservedParameter`] if one of the extra parameters is reserved.
+pub(crate) fn check_extra_params<'a>(
+    extra_params: impl IntoIterator<Item = &'a str>,
+    reserved: &[&str],
+) -> Auth0Result<()> {
+    match extra_params
+        .into_iter()
+        .find(|name| reserved.contains(name))
+    {
+        Some(name) => Err(Error::ReservedParameter(name.to_owned())),
+        None => Ok(()),
+    }
+}
+
+/// Signs the given claims with the private key matching `tests/data/rsa_jwks.json`.
+#[cfg(test)]
+pub(crate) fn sign_test_jwt<C: serde::Serialize>(claims: &C) -> String {
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+
+    let pem = std::fs::read_to_string("tests/data/rsa_private_key.pem").unwrap();
+    let key = EncodingKey::from_rsa_pem(pem.as_bytes()).unwrap();
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some("test_kid".to_owned());
+
+    encode(&header, claims, &key).unwrap()
+}